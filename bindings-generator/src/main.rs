@@ -1,7 +1,20 @@
+use regex::Regex;
 use std::path::Path;
 
 const SPINE_SRC_PATH: &str = "external/spine-c/spine-c";
 
+/// Rewrites the field offsets of the layout tests from a null pointer dereference, which is
+/// undefined behavior and rejected by current compilers, to `offset_of!`.
+fn offset_of_layout_tests(bindings: &str) -> String {
+    let offset = Regex::new(
+        r"unsafe\s*\{\s*&\(\*\(::std::ptr::null::<(\w+)>\(\)\)\)\.(\w+)\s+as\s+\*const\s+_\s+as\s+usize\s*\}",
+    )
+    .unwrap();
+    offset
+        .replace_all(bindings, "::std::mem::offset_of!($1, $2)")
+        .into_owned()
+}
+
 fn generate() {
     let out_path = std::env::current_dir().unwrap();
 
@@ -15,11 +28,46 @@ fn generate() {
         .generate()
         .expect("Unable to generate bindings");
 
-    bindings
-        .write_to_file(out_path.join("spine-sys/src/spine_bindings.rs"))
-        .expect("Couldn't write bindings!");
+    std::fs::write(
+        out_path.join("spine-sys/src/spine_bindings.rs"),
+        offset_of_layout_tests(&bindings.to_string()),
+    )
+    .expect("Couldn't write bindings!");
 }
 
 fn main() {
     generate();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_tests_use_offset_of() {
+        let generated = r"
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<spEvent>())).data as *const _ as usize },
+        0usize,
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::std::ptr::null::<spIkConstraintTimeline>())).ikConstraintIndex as *const _
+                as usize
+        },
+        40usize,
+    );";
+        assert_eq!(
+            r"
+    assert_eq!(
+        ::std::mem::offset_of!(spEvent, data),
+        0usize,
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(spIkConstraintTimeline, ikConstraintIndex),
+        40usize,
+    );",
+            offset_of_layout_tests(generated)
+        );
+    }
+}
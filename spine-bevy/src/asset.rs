@@ -0,0 +1,266 @@
+use bevy::{
+    asset::{
+        io::Reader, AssetLoader, AsyncReadExt, LoadContext, LoadDirectError, ParseAssetPathError,
+    },
+    image::{
+        ImageAddressMode, ImageFilterMode, ImageLoaderSettings, ImageSampler,
+        ImageSamplerDescriptor,
+    },
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+use spine::{
+    animation::AnimationStateData,
+    atlas::{is_pma_path, Atlas, AtlasPage},
+    enums::{AtlasFilter, AtlasWrap},
+    format::atlas::{AtlasData, PageData},
+    skeleton::SkeletonData,
+    SpineError,
+};
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SpineLoaderError {
+    #[error("failed to read spine asset: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Spine(#[from] SpineError),
+    #[error("skeleton json is not valid utf-8: {0}")]
+    Utf8(#[from] std::string::FromUtf8Error),
+    #[error("invalid atlas path: {0}")]
+    Path(#[from] ParseAssetPathError),
+    #[error("failed to load atlas: {0}")]
+    Atlas(#[from] Box<LoadDirectError>),
+    #[error("binary skeletons are only loaded with `SpineSkeletonLoaderSettings::trust_binary`")]
+    UntrustedBinary,
+}
+
+/// A loaded `.atlas` file, with its pages loaded as `Image`s.
+///
+/// Page `n` was given texture id `n + 1`, so `SpineAtlas::page` resolves the texture ids reported
+/// by slots and batches.
+#[derive(Asset, TypePath)]
+pub struct SpineAtlas {
+    pub(crate) atlas: Atlas,
+    #[dependency]
+    pub(crate) pages: Vec<Handle<Image>>,
+}
+impl SpineAtlas {
+    pub fn atlas(&self) -> &Atlas {
+        &self.atlas
+    }
+
+    pub fn pages(&self) -> &[Handle<Image>] {
+        &self.pages
+    }
+
+    /// The image of the page that was given `texture_id`.
+    pub fn page(&self, texture_id: u32) -> Option<&Handle<Image>> {
+        page(&self.pages, texture_id)
+    }
+}
+
+/// Loads `.atlas` files as `SpineAtlas` assets. Page images are loaded relative to the atlas, with
+/// the filtering and wrapping the atlas asks for.
+#[derive(Default, TypePath)]
+pub struct SpineAtlasLoader;
+
+impl AssetLoader for SpineAtlasLoader {
+    type Asset = SpineAtlas;
+    type Settings = ();
+    type Error = SpineLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<SpineAtlas, SpineLoaderError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).await?;
+        let data = text.parse::<AtlasData>()?;
+
+        let mut pages = Vec::with_capacity(data.pages.len());
+        for page in &data.pages {
+            let sampler = sampler(page);
+            let path = load_context.path().resolve_embed(&page.name)?;
+            pages.push(
+                load_context
+                    .loader()
+                    .with_settings(move |settings: &mut ImageLoaderSettings| {
+                        settings.sampler = ImageSampler::Descriptor(sampler.clone());
+                    })
+                    .load(path),
+            );
+        }
+
+        let mut next_id = 0;
+        let mut atlas = Atlas::from_data(&data, "", |_, _| {
+            next_id += 1;
+            next_id
+        })?;
+        if is_pma_path(load_context.path().path()) {
+            atlas.set_premultiplied_alpha(true);
+        }
+
+        Ok(SpineAtlas { atlas, pages })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["atlas"]
+    }
+}
+
+/// A loaded `.json` or `.skel` skeleton, together with its atlas pages and the mix durations
+/// shared by every `Spine` spawned from it.
+#[derive(Asset, TypePath)]
+pub struct SpineSkeletonData {
+    pub(crate) skeleton_data: SkeletonData,
+    pub(crate) animation_state_data: AnimationStateData,
+    #[dependency]
+    pub(crate) pages: Vec<Handle<Image>>,
+    /// Whether each page stores premultiplied alpha, indexed by texture id - 1.
+    pub(crate) premultiplied_alpha: Vec<bool>,
+}
+impl SpineSkeletonData {
+    pub fn skeleton_data(&self) -> &SkeletonData {
+        &self.skeleton_data
+    }
+
+    pub fn animation_state_data(&self) -> &AnimationStateData {
+        &self.animation_state_data
+    }
+
+    /// The atlas page images, indexed by texture id - 1.
+    pub fn pages(&self) -> &[Handle<Image>] {
+        &self.pages
+    }
+
+    /// The image of the atlas page that was given `texture_id`.
+    pub fn page(&self, texture_id: u32) -> Option<&Handle<Image>> {
+        page(&self.pages, texture_id)
+    }
+
+    /// Whether the atlas page that was given `texture_id` stores premultiplied alpha, see
+    /// `AtlasPage::premultiplied_alpha`.
+    pub fn premultiplied_alpha(&self, texture_id: u32) -> bool {
+        page(&self.premultiplied_alpha, texture_id).is_some_and(|&pma| pma)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpineSkeletonLoaderSettings {
+    /// The atlas holding the skeleton's images, relative to the skeleton file. Defaults to the
+    /// skeleton's file name with an `.atlas` extension.
+    pub atlas: Option<String>,
+    /// The mix duration used between animations without a mix of their own.
+    pub default_mix: f32,
+    /// Loads `.skel` files, which spine-c reads without bounds checks, so corrupt or malicious
+    /// data reads out of bounds, see `SkeletonData::from_binary_bytes`. Only set this for exports
+    /// shipped with the game, not for mods or downloads. Without it, loading a `.skel` file fails
+    /// with `SpineLoaderError::UntrustedBinary`.
+    pub trust_binary: bool,
+}
+
+/// Loads `.json` and `.skel` files as `SpineSkeletonData` assets, loading their atlas on the way.
+#[derive(Default, TypePath)]
+pub struct SpineSkeletonLoader;
+
+impl AssetLoader for SpineSkeletonLoader {
+    type Asset = SpineSkeletonData;
+    type Settings = SpineSkeletonLoaderSettings;
+    type Error = SpineLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        settings: &SpineSkeletonLoaderSettings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<SpineSkeletonData, SpineLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let path = load_context.path().path().to_owned();
+        let atlas_path = match &settings.atlas {
+            Some(atlas) => atlas.clone(),
+            None => Path::new(path.file_name().unwrap_or_default())
+                .with_extension("atlas")
+                .to_string_lossy()
+                .into_owned(),
+        };
+        let atlas_path = load_context.path().resolve_embed(&atlas_path)?;
+        let SpineAtlas { atlas, pages } = load_context
+            .loader()
+            .immediate()
+            .load::<SpineAtlas>(atlas_path)
+            .await
+            .map_err(Box::new)?
+            .take();
+
+        let premultiplied_alpha = atlas
+            .pages()
+            .iter()
+            .map(AtlasPage::premultiplied_alpha)
+            .collect();
+        let skeleton_data = if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            SkeletonData::from_json_str(&String::from_utf8(bytes)?, atlas)?
+        } else if settings.trust_binary {
+            // SAFETY: the settings vouch that the asset is a complete export.
+            unsafe { SkeletonData::from_binary_bytes(&bytes, atlas)? }
+        } else {
+            return Err(SpineLoaderError::UntrustedBinary);
+        };
+        let mut animation_state_data = AnimationStateData::new(&skeleton_data);
+        animation_state_data.set_default_mix(settings.default_mix);
+
+        Ok(SpineSkeletonData {
+            skeleton_data,
+            animation_state_data,
+            pages,
+            premultiplied_alpha,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["json", "skel"]
+    }
+}
+
+fn page<T>(pages: &[T], texture_id: u32) -> Option<&T> {
+    pages.get(usize::try_from(texture_id).ok()?.checked_sub(1)?)
+}
+
+fn sampler(page: &PageData) -> ImageSamplerDescriptor {
+    let address_mode = |wrap| match wrap {
+        AtlasWrap::MirroedRepeat => ImageAddressMode::MirrorRepeat,
+        AtlasWrap::ClampToEdge => ImageAddressMode::ClampToEdge,
+        AtlasWrap::Repeat => ImageAddressMode::Repeat,
+    };
+    let (min_filter, mipmap_filter) = filter(page.min_filter);
+    ImageSamplerDescriptor {
+        address_mode_u: address_mode(page.u_wrap),
+        address_mode_v: address_mode(page.v_wrap),
+        mag_filter: filter(page.mag_filter).0,
+        min_filter,
+        mipmap_filter,
+        ..ImageSamplerDescriptor::default()
+    }
+}
+
+/// The texel and mipmap filters of an atlas filter, named after the OpenGL filters.
+fn filter(filter: AtlasFilter) -> (ImageFilterMode, ImageFilterMode) {
+    use ImageFilterMode::{Linear, Nearest};
+
+    match filter {
+        AtlasFilter::Nearest | AtlasFilter::MipmapNereastNearest => (Nearest, Nearest),
+        AtlasFilter::Unknown | AtlasFilter::Linear | AtlasFilter::MipmapLinearNearest => {
+            (Linear, Nearest)
+        }
+        AtlasFilter::MipmapNearestLinear => (Nearest, Linear),
+        AtlasFilter::Mipmap | AtlasFilter::MipmapLinearLinear => (Linear, Linear),
+    }
+}
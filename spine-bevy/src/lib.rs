@@ -0,0 +1,309 @@
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::must_use_candidate,
+    clippy::needless_pass_by_value,
+    clippy::type_complexity
+)]
+
+//! Loads, animates and draws Spine skeletons in Bevy.
+//!
+//! Add `SpinePlugin`, load a `.json` or `.skel` file (its atlas is loaded along with it) and spawn
+//! a `SpineBundle`. Once the skeleton has loaded, a `Spine` component holding the `Skeleton` and
+//! `AnimationState` is inserted and a `SpineReady` message is written.
+
+pub mod asset;
+pub mod render;
+pub mod spine;
+
+pub use crate::{
+    asset::{SpineAtlas, SpineSkeletonData, SpineSkeletonLoaderSettings},
+    render::SpineMaterial,
+    spine::{
+        Spine, SpineBone, SpineBundle, SpineEvent, SpineReady, SpineSettings, SpineSkeleton,
+        SpineSystems,
+    },
+};
+
+use bevy::{
+    asset::embedded_asset, prelude::*, sprite_render::Material2dPlugin, transform::TransformSystems,
+};
+
+pub struct SpinePlugin;
+
+impl Plugin for SpinePlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "spine.wgsl");
+
+        app.add_plugins(Material2dPlugin::<SpineMaterial>::default())
+            .init_asset::<SpineAtlas>()
+            .init_asset::<SpineSkeletonData>()
+            .init_asset_loader::<asset::SpineAtlasLoader>()
+            .init_asset_loader::<asset::SpineSkeletonLoader>()
+            .add_message::<SpineReady>()
+            .add_message::<SpineEvent>()
+            .add_systems(PreUpdate, spine::spawn_spines.in_set(SpineSystems::Spawn))
+            .add_systems(
+                Update,
+                (
+                    spine::update_spines.in_set(SpineSystems::Update),
+                    spine::sync_bones.in_set(SpineSystems::SyncBones),
+                )
+                    .chain(),
+            )
+            .add_systems(
+                PostUpdate,
+                render::update_meshes
+                    .in_set(SpineSystems::UpdateMeshes)
+                    .before(TransformSystems::Propagate),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::spine::{animation::TrackIndex, enums::EventType};
+    use bevy::{
+        image::{CompressedImageFormats, ImageFilterMode, ImageLoader, ImageSampler},
+        mesh::MeshPlugin,
+        time::TimeUpdateStrategy,
+    };
+    use std::{path::Path, time::Duration};
+
+    const DRAGON: &str = "../spine-example/examples/dragon/export";
+
+    fn app(asset_root: &str) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin {
+                file_path: asset_root.to_owned(),
+                ..AssetPlugin::default()
+            },
+            ImagePlugin::default(),
+            MeshPlugin,
+            SpinePlugin,
+        ))
+        // The image loader is registered by the renderer, which a headless app does not have.
+        .register_asset_loader(ImageLoader::new(CompressedImageFormats::NONE))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )));
+        app
+    }
+
+    /// Updates `app` until `done` returns true, panicking if it takes too long.
+    fn update_until(app: &mut App, mut done: impl FnMut(&mut App) -> bool) {
+        for _ in 0..1000 {
+            app.update();
+            if done(app) {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        panic!("timed out");
+    }
+
+    fn spawn(app: &mut App, path: &str, settings: SpineSkeletonLoaderSettings) -> Entity {
+        let skeleton = app.world().resource::<AssetServer>().load_with_settings(
+            path.to_owned(),
+            move |s: &mut SpineSkeletonLoaderSettings| *s = settings.clone(),
+        );
+        app.world_mut()
+            .spawn(SpineBundle {
+                skeleton: SpineSkeleton(skeleton),
+                settings: SpineSettings { spawn_bones: true },
+                ..SpineBundle::default()
+            })
+            .id()
+    }
+
+    #[test]
+    fn dragon() {
+        let mut app = app(DRAGON);
+        let entity = spawn(
+            &mut app,
+            "dragon-ess.skel",
+            SpineSkeletonLoaderSettings {
+                atlas: Some("dragon.atlas".to_owned()),
+                default_mix: 0.2,
+                trust_binary: true,
+            },
+        );
+        update_until(&mut app, |app| app.world().get::<Spine>(entity).is_some());
+
+        let ready = app.world().resource::<Messages<SpineReady>>();
+        assert_eq!(
+            vec![entity],
+            ready
+                .iter_current_update_messages()
+                .map(|m| m.entity)
+                .collect::<Vec<_>>()
+        );
+
+        app.world_mut()
+            .get_mut::<Spine>(entity)
+            .unwrap()
+            .animation_state_mut()
+            .set_by_name("flying", TrackIndex::zero(), true);
+        for _ in 0..10 {
+            app.update();
+        }
+
+        let world = app.world_mut();
+        let skeleton = world.get::<SpineSkeleton>(entity).unwrap().0.clone();
+        let data = world
+            .resource::<Assets<SpineSkeletonData>>()
+            .get(&skeleton)
+            .unwrap();
+        assert!((data.animation_state_data().default_mix() - 0.2).abs() < f32::EPSILON);
+        let pages = data.pages().to_vec();
+        assert_eq!(2, pages.len());
+        assert!(!data.premultiplied_alpha(1) && !data.premultiplied_alpha(2));
+
+        let mut spine = world.get_mut::<Spine>(entity).unwrap();
+        let bones = spine.bones().to_vec();
+        let expected = spine
+            .skeleton_mut()
+            .bone()
+            .iter()
+            .map(|bone| (bone.name().to_owned(), bone.world_position()))
+            .collect::<Vec<_>>();
+        assert_eq!(expected.len(), bones.len());
+        for ((name, [x, y]), bone) in expected.iter().zip(bones) {
+            let bone = world.entity(bone);
+            assert_eq!(name, &bone.get::<SpineBone>().unwrap().name);
+            assert_eq!(Some(entity), bone.get::<ChildOf>().map(ChildOf::parent));
+            let translation = bone.get::<Transform>().unwrap().translation;
+            assert!((translation.x - x).abs() < 1e-4 && (translation.y - y).abs() < 1e-4);
+        }
+
+        let mut batches = world.query::<(&Mesh2d, &MeshMaterial2d<SpineMaterial>, &ChildOf)>();
+        let batches = batches
+            .iter(world)
+            .map(|(mesh, material, parent)| (mesh.0.clone(), material.0.clone(), parent.parent()))
+            .collect::<Vec<_>>();
+        assert!(!batches.is_empty());
+        let meshes = world.resource::<Assets<Mesh>>();
+        let materials = world.resource::<Assets<SpineMaterial>>();
+        for (mesh, material, parent) in batches {
+            assert_eq!(entity, parent);
+            let mesh = meshes.get(&mesh).unwrap();
+            assert!(mesh.count_vertices() > 0);
+            assert!(mesh.attribute(render::ATTRIBUTE_DARK_COLOR).is_some());
+            assert!(pages.contains(&materials.get(&material).unwrap().image));
+        }
+
+        // Pages load with the sampler the atlas asks for.
+        update_until(&mut app, |app| {
+            let images = app.world().resource::<Assets<Image>>();
+            pages.iter().all(|page| images.get(page).is_some())
+        });
+        let image = app
+            .world()
+            .resource::<Assets<Image>>()
+            .get(&pages[0])
+            .unwrap();
+        let ImageSampler::Descriptor(sampler) = &image.sampler else {
+            panic!("no sampler descriptor");
+        };
+        assert!(matches!(sampler.mag_filter, ImageFilterMode::Linear));
+    }
+
+    /// A skeleton with an `arm` bone rotated by a `walk` animation raising a `step` event, plus
+    /// an empty atlas, in a temporary directory.
+    #[test]
+    fn binary_requires_trust() {
+        let mut app = app(DRAGON);
+        let skeleton: Handle<SpineSkeletonData> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("dragon-ess.skel");
+        update_until(&mut app, |app| {
+            app.world()
+                .resource::<AssetServer>()
+                .load_state(&skeleton)
+                .is_failed()
+        });
+    }
+
+    fn write_rig(dir: &Path, name: &str, extra_bone: &str) {
+        std::fs::create_dir_all(dir).unwrap();
+        let json = r#"{
+            "skeleton": { "hash": "events", "spine": "3.8.55" },
+            "bones": [ { "name": "root" }, { "name": "arm", "parent": "root", "x": 10 } EXTRA ],
+            "events": { "step": { "int": 7 } },
+            "animations": {
+                "walk": {
+                    "bones": { "arm": { "rotate": [ { "angle": 0 }, { "time": 0.5, "angle": 90 } ] } },
+                    "events": [ { "time": 0.25, "name": "step" } ]
+                }
+            }
+        }"#;
+        std::fs::write(
+            dir.join(format!("{name}.json")),
+            json.replace("EXTRA", extra_bone),
+        )
+        .unwrap();
+        std::fs::write(dir.join(format!("{name}.atlas")), "").unwrap();
+    }
+
+    #[test]
+    fn events_and_respawning() {
+        let dir = std::env::temp_dir().join(format!("spine-bevy-events-{}", std::process::id()));
+        write_rig(&dir, "walker", "");
+        write_rig(
+            &dir,
+            "reacher",
+            r#", { "name": "hand", "parent": "arm", "x": 5 }"#,
+        );
+
+        let mut app = app(dir.to_str().unwrap());
+        let entity = spawn(
+            &mut app,
+            "walker.json",
+            SpineSkeletonLoaderSettings::default(),
+        );
+        update_until(&mut app, |app| app.world().get::<Spine>(entity).is_some());
+        app.world_mut()
+            .get_mut::<Spine>(entity)
+            .unwrap()
+            .animation_state_mut()
+            .set_by_name("walk", TrackIndex::zero(), false);
+
+        let mut events = Vec::new();
+        for _ in 0..40 {
+            app.update();
+            let messages = app.world().resource::<Messages<SpineEvent>>();
+            events.extend(messages.iter_current_update_messages().cloned());
+        }
+        assert!(events.iter().all(|event| event.entity == entity));
+        let kinds = events
+            .iter()
+            .map(|event| event.event.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![EventType::Start, EventType::Event, EventType::Complete],
+            kinds
+        );
+        assert_eq!(7, events[1].event.event.as_ref().unwrap().int);
+
+        // The arm bone entity follows the animation.
+        let arm = app.world().get::<Spine>(entity).unwrap().bones()[1];
+        let rotation = app.world().get::<Transform>(arm).unwrap().rotation;
+        assert!((rotation.to_euler(EulerRot::XYZ).2.to_degrees() - 90.0).abs() < 1e-3);
+
+        // Switching skeletons replaces the bone entities.
+        let reacher = app.world().resource::<AssetServer>().load("reacher.json");
+        app.world_mut().get_mut::<SpineSkeleton>(entity).unwrap().0 = reacher;
+        update_until(&mut app, |app| {
+            app.world().get::<Spine>(entity).unwrap().bones().len() == 3
+        });
+        let bones = app.world().get::<Spine>(entity).unwrap().bones().to_vec();
+        assert!(app.world().get_entity(arm).is_err());
+        assert_eq!("hand", app.world().get::<SpineBone>(bones[2]).unwrap().name);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    asset::SpineSkeletonData,
+    spine::{Spine, SpineSkeleton},
+};
+use bevy::{
+    asset::{embedded_path, AssetPath, RenderAssetUsages},
+    color::{LinearRgba, Srgba},
+    log::warn,
+    mesh::{
+        Indices, MeshVertexAttribute, MeshVertexBufferLayoutRef, PrimitiveTopology, VertexFormat,
+    },
+    platform::collections::HashMap,
+    prelude::*,
+    render::render_resource::{
+        AsBindGroup, BlendComponent, BlendFactor, BlendOperation, BlendState,
+        RenderPipelineDescriptor, SpecializedMeshPipelineError,
+    },
+    shader::ShaderRef,
+    sprite_render::{AlphaMode2d, Material2d, Material2dKey},
+};
+use spine::{batch::Batch, color::Color, enums::BlendMode, skeleton::WorldVertex};
+
+/// The dark color of two color tinting. Like the light color it is premultiplied for premultiplied
+/// alpha textures, and its alpha is then 1 instead of 0, as the spine-runtimes two color shaders
+/// expect.
+pub const ATTRIBUTE_DARK_COLOR: MeshVertexAttribute =
+    MeshVertexAttribute::new("SpineDarkColor", 0x5350_494e, VertexFormat::Float32x4);
+
+/// The distance along z between consecutive batches of a skeleton, so they are drawn in order.
+pub const BATCH_Z_STEP: f32 = 0.0001;
+
+/// Draws a `Batch` with its atlas page, blend mode and two color tinting.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+#[bind_group_data(SpineMaterialKey)]
+pub struct SpineMaterial {
+    #[texture(0)]
+    #[sampler(1)]
+    pub image: Handle<Image>,
+    pub blend_mode: BlendMode,
+    pub premultiplied_alpha: bool,
+}
+
+/// The pipeline variant of a `SpineMaterial`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpineMaterialKey {
+    pub blend_mode: BlendMode,
+    pub premultiplied_alpha: bool,
+}
+impl From<&SpineMaterial> for SpineMaterialKey {
+    fn from(material: &SpineMaterial) -> Self {
+        Self {
+            blend_mode: material.blend_mode,
+            premultiplied_alpha: material.premultiplied_alpha,
+        }
+    }
+}
+
+impl Material2d for SpineMaterial {
+    fn vertex_shader() -> ShaderRef {
+        shader()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        shader()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+
+    fn specialize(
+        descriptor: &mut RenderPipelineDescriptor,
+        layout: &MeshVertexBufferLayoutRef,
+        key: Material2dKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        descriptor.vertex.buffers = vec![layout.0.get_layout(&[
+            Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
+            Mesh::ATTRIBUTE_UV_0.at_shader_location(1),
+            Mesh::ATTRIBUTE_COLOR.at_shader_location(2),
+            ATTRIBUTE_DARK_COLOR.at_shader_location(3),
+        ])?];
+
+        let blend = blend_state(key.bind_group_data);
+        if let Some(fragment) = &mut descriptor.fragment {
+            for target in fragment.targets.iter_mut().flatten() {
+                target.blend = Some(blend);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn shader() -> ShaderRef {
+    ShaderRef::Path(AssetPath::from_path_buf(embedded_path!("spine.wgsl")).with_source("embedded"))
+}
+
+/// The blend state of `BlendMode::blend_func`.
+pub fn blend_state(key: SpineMaterialKey) -> BlendState {
+    let factor = |factor| match factor {
+        spine::enums::BlendFactor::One => BlendFactor::One,
+        spine::enums::BlendFactor::SrcAlpha => BlendFactor::SrcAlpha,
+        spine::enums::BlendFactor::OneMinusSrcAlpha => BlendFactor::OneMinusSrcAlpha,
+        spine::enums::BlendFactor::DstColor => BlendFactor::Dst,
+        spine::enums::BlendFactor::OneMinusSrcColor => BlendFactor::OneMinusSrc,
+    };
+    let func = key.blend_mode.blend_func(key.premultiplied_alpha);
+    BlendState {
+        color: BlendComponent {
+            src_factor: factor(func.src_color),
+            dst_factor: factor(func.dst_color),
+            operation: BlendOperation::Add,
+        },
+        alpha: BlendComponent {
+            src_factor: factor(func.src_alpha),
+            dst_factor: factor(func.dst_alpha),
+            operation: BlendOperation::Add,
+        },
+    }
+}
+
+/// A child entity drawing one `Batch` of a `Spine`. Batch entities are reused between frames and
+/// hidden while their skeleton draws fewer batches.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SpineBatch {
+    pub index: usize,
+}
+
+pub(crate) fn update_meshes(
+    mut commands: Commands,
+    mut spines: Query<(Entity, &SpineSkeleton, &mut Spine)>,
+    mut batches: Query<
+        (&Mesh2d, &mut MeshMaterial2d<SpineMaterial>, &mut Visibility),
+        With<SpineBatch>,
+    >,
+    skeletons: Res<Assets<SpineSkeletonData>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<SpineMaterial>>,
+    mut material_cache: Local<HashMap<(AssetId<Image>, SpineMaterialKey), Handle<SpineMaterial>>>,
+) {
+    for (entity, skeleton, mut spine) in &mut spines {
+        let Some(data) = skeletons.get(&skeleton.0) else {
+            continue;
+        };
+
+        let mut drawn = 0;
+        for batch in spine.batch() {
+            let Some(image) = data.page(batch.texture_id) else {
+                warn!("no atlas page with texture id {}", batch.texture_id);
+                continue;
+            };
+            let premultiplied_alpha = data.premultiplied_alpha(batch.texture_id);
+            let key = SpineMaterialKey {
+                blend_mode: batch.blend_mode,
+                premultiplied_alpha,
+            };
+            let material = material_cache
+                .entry((image.id(), key))
+                .or_insert_with(|| {
+                    materials.add(SpineMaterial {
+                        image: image.clone(),
+                        blend_mode: key.blend_mode,
+                        premultiplied_alpha,
+                    })
+                })
+                .clone();
+
+            if let Some(&child) = spine.batches.get(drawn) {
+                if let Ok((mesh, mut mesh_material, mut visibility)) = batches.get_mut(child) {
+                    if let Some(mesh) = meshes.get_mut(&mesh.0) {
+                        fill_mesh(mesh, &batch, premultiplied_alpha);
+                    }
+                    if mesh_material.0 != material {
+                        mesh_material.0 = material;
+                    }
+                    visibility.set_if_neq(Visibility::Inherited);
+                }
+            } else {
+                let mut mesh = Mesh::new(
+                    PrimitiveTopology::TriangleList,
+                    RenderAssetUsages::default(),
+                );
+                fill_mesh(&mut mesh, &batch, premultiplied_alpha);
+                #[allow(clippy::cast_precision_loss)]
+                let z = drawn as f32 * BATCH_Z_STEP;
+                let child = commands
+                    .spawn((
+                        SpineBatch { index: drawn },
+                        Mesh2d(meshes.add(mesh)),
+                        MeshMaterial2d(material),
+                        Transform::from_xyz(0.0, 0.0, z),
+                        Visibility::Inherited,
+                        ChildOf(entity),
+                    ))
+                    .id();
+                spine.batches.push(child);
+            }
+            drawn += 1;
+        }
+
+        for &child in &spine.batches[drawn..] {
+            if let Ok((_, _, mut visibility)) = batches.get_mut(child) {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+    }
+}
+
+/// Replaces the vertices and indices of `mesh` with those of `batch`, in the linear color space
+/// Bevy blends in.
+fn fill_mesh(mesh: &mut Mesh, batch: &Batch, premultiplied_alpha: bool) {
+    let linear = |color: Color| {
+        let linear = LinearRgba::from(Srgba::new(color.r, color.g, color.b, color.a));
+        Color::new(linear.red, linear.green, linear.blue, linear.alpha)
+    };
+
+    let positions = batch
+        .vertices
+        .iter()
+        .map(|vertex| [vertex.position[0], vertex.position[1], 0.0])
+        .collect::<Vec<_>>();
+    let uvs = batch
+        .vertices
+        .iter()
+        .map(|vertex| vertex.uv)
+        .collect::<Vec<_>>();
+    let (light, dark): (Vec<_>, Vec<_>) = batch
+        .vertices
+        .iter()
+        .map(|vertex| {
+            let vertex = WorldVertex {
+                light: linear(vertex.light),
+                dark: linear(vertex.dark),
+                ..*vertex
+            };
+            vertex.tint_colors(premultiplied_alpha)
+        })
+        .unzip();
+
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, light);
+    mesh.insert_attribute(ATTRIBUTE_DARK_COLOR, dark);
+    mesh.insert_indices(Indices::U32(batch.indices.clone()));
+}
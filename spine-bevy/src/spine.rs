@@ -0,0 +1,219 @@
+use crate::asset::SpineSkeletonData;
+use bevy::{platform::cell::SyncCell, prelude::*};
+use spine::{
+    animation::{AnimationEvent, AnimationState},
+    batch::{Batch, SkeletonBatcher},
+    skeleton::{Bone, Skeleton},
+};
+
+/// The skeleton to spawn a `Spine` from. Changing it respawns the `Spine`.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpineSkeleton(pub Handle<SpineSkeletonData>);
+
+#[derive(Component, Debug, Clone, Default)]
+pub struct SpineSettings {
+    /// Spawns a `SpineBone` child entity per bone, with its `Transform` following the bone, so
+    /// other entities can be attached to bones.
+    pub spawn_bones: bool,
+}
+
+/// Everything needed to spawn an animated skeleton. `Spine` is inserted once the skeleton has
+/// loaded, followed by a `SpineReady` message.
+#[derive(Bundle, Default)]
+pub struct SpineBundle {
+    pub skeleton: SpineSkeleton,
+    pub settings: SpineSettings,
+    pub transform: Transform,
+    pub visibility: Visibility,
+}
+
+/// The skeleton and animation state of a spawned `SpineSkeleton`.
+///
+/// Every frame the animation state is advanced and applied, world transforms are updated and the
+/// skeleton is turned into meshes, see `SpineSystems`.
+#[derive(Component)]
+pub struct Spine {
+    inner: SyncCell<SpineInner>,
+    pub(crate) data: AssetId<SpineSkeletonData>,
+    pub(crate) bones: Vec<Entity>,
+    pub(crate) batches: Vec<Entity>,
+}
+
+/// `Skeleton`, `AnimationState` and `SkeletonBatcher` are `Send` but not `Sync`, so they are only
+/// reachable through `&mut Spine`.
+struct SpineInner {
+    skeleton: Skeleton,
+    animation_state: AnimationState,
+    batcher: SkeletonBatcher,
+}
+
+impl Spine {
+    pub fn skeleton_mut(&mut self) -> &mut Skeleton {
+        &mut self.inner.get().skeleton
+    }
+
+    pub fn animation_state_mut(&mut self) -> &mut AnimationState {
+        &mut self.inner.get().animation_state
+    }
+
+    pub fn get_mut(&mut self) -> (&mut Skeleton, &mut AnimationState) {
+        let inner = self.inner.get();
+        (&mut inner.skeleton, &mut inner.animation_state)
+    }
+
+    /// The `SpineBone` entities in skeleton bone order, empty unless `SpineSettings::spawn_bones`
+    /// was set.
+    pub fn bones(&self) -> &[Entity] {
+        &self.bones
+    }
+
+    pub(crate) fn batch(&mut self) -> Vec<Batch> {
+        let inner = self.inner.get();
+        inner.batcher.batch(&inner.skeleton)
+    }
+}
+
+/// A child entity of a `Spine` following one of its bones.
+#[derive(Component, Debug, Clone)]
+pub struct SpineBone {
+    pub index: usize,
+    pub name: String,
+}
+
+/// Written once the `Spine` of `entity` was inserted, so animations can be set.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct SpineReady {
+    pub entity: Entity,
+}
+
+/// An event raised by the animation state of the `Spine` of `entity`.
+#[derive(Message, Debug, Clone)]
+pub struct SpineEvent {
+    pub entity: Entity,
+    pub event: AnimationEvent,
+}
+
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpineSystems {
+    /// Inserts `Spine`s for loaded skeletons, in `PreUpdate`.
+    Spawn,
+    /// Advances and applies animations and writes `SpineEvent`s, in `Update`.
+    Update,
+    /// Moves `SpineBone` entities to their bones, in `Update`.
+    SyncBones,
+    /// Rebuilds the meshes of every `Spine`, in `PostUpdate`.
+    UpdateMeshes,
+}
+
+pub(crate) fn spawn_spines(
+    mut commands: Commands,
+    spines: Query<(Entity, &SpineSkeleton, &SpineSettings, Option<&Spine>)>,
+    skeletons: Res<Assets<SpineSkeletonData>>,
+    mut ready: MessageWriter<SpineReady>,
+) {
+    for (entity, skeleton, settings, spine) in &spines {
+        if spine.is_some_and(|spine| spine.data == skeleton.0.id()) {
+            continue;
+        }
+        let Some(data) = skeletons.get(&skeleton.0) else {
+            continue;
+        };
+        if let Some(spine) = spine {
+            for &child in spine.bones.iter().chain(&spine.batches) {
+                commands.entity(child).despawn();
+            }
+        }
+
+        let mut skeleton_instance = Skeleton::new(&data.skeleton_data);
+        skeleton_instance.update_world_transforms();
+        let mut animation_state = AnimationState::new(&data.animation_state_data);
+        animation_state.set_record_events(true);
+
+        let bones = if settings.spawn_bones {
+            skeleton_instance
+                .bone()
+                .iter()
+                .enumerate()
+                .map(|(index, bone)| {
+                    commands
+                        .spawn((
+                            SpineBone {
+                                index,
+                                name: bone.name().to_owned(),
+                            },
+                            bone_transform(bone, 0.0),
+                            Visibility::Inherited,
+                            ChildOf(entity),
+                        ))
+                        .id()
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        commands.entity(entity).insert(Spine {
+            inner: SyncCell::new(SpineInner {
+                skeleton: skeleton_instance,
+                animation_state,
+                batcher: SkeletonBatcher::new(),
+            }),
+            data: skeleton.0.id(),
+            bones,
+            batches: Vec::new(),
+        });
+        ready.write(SpineReady { entity });
+    }
+}
+
+pub(crate) fn update_spines(
+    time: Res<Time>,
+    mut spines: Query<(Entity, &mut Spine)>,
+    mut events: MessageWriter<SpineEvent>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut spine) in &mut spines {
+        let (skeleton, animation_state) = spine.get_mut();
+        animation_state.update(delta);
+        animation_state.apply(skeleton);
+        skeleton.update(delta);
+        skeleton.update_world_transforms();
+
+        events.write_batch(
+            animation_state
+                .take_events()
+                .into_iter()
+                .map(|event| SpineEvent { entity, event }),
+        );
+    }
+}
+
+pub(crate) fn sync_bones(
+    mut spines: Query<&mut Spine>,
+    mut bones: Query<&mut Transform, With<SpineBone>>,
+) {
+    for mut spine in &mut spines {
+        let spine = spine.bypass_change_detection();
+        if spine.bones.is_empty() {
+            continue;
+        }
+
+        let skeleton = &spine.inner.get().skeleton;
+        for (bone, &entity) in skeleton.bone().iter().zip(&spine.bones) {
+            if let Ok(mut transform) = bones.get_mut(entity) {
+                *transform = bone_transform(bone, transform.translation.z);
+            }
+        }
+    }
+}
+
+/// The transform of `bone` relative to its skeleton, ignoring shear.
+fn bone_transform(bone: &Bone<'_>, z: f32) -> Transform {
+    let [x, y] = bone.world_position();
+    let [scale_x, scale_y] = bone.world_scale();
+    Transform {
+        translation: Vec3::new(x, y, z),
+        rotation: Quat::from_rotation_z(bone.world_rotation().to_radians()),
+        scale: Vec3::new(scale_x, scale_y, 1.0),
+    }
+}
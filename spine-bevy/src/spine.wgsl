@@ -0,0 +1,43 @@
+#import bevy_sprite::mesh2d_functions as mesh_functions
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(1) var texture_sampler: sampler;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) light: vec4<f32>,
+    @location(3) dark: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) light: vec4<f32>,
+    @location(2) dark: vec4<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    let world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+    let world_position = mesh_functions::mesh2d_position_local_to_world(
+        world_from_local,
+        vec4<f32>(vertex.position, 1.0)
+    );
+    out.position = mesh_functions::mesh2d_position_world_to_clip(world_position);
+    out.uv = vertex.uv;
+    out.light = vertex.light;
+    out.dark = vertex.dark;
+    return out;
+}
+
+// Two color tinting as in the spine-runtimes shaders: the light color tints the texture and the
+// dark color replaces its black.
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let texel = textureSample(texture, texture_sampler, in.uv);
+    let rgb = ((texel.a - 1.0) * in.dark.a + 1.0 - texel.rgb) * in.dark.rgb + texel.rgb * in.light.rgb;
+    return vec4<f32>(rgb, texel.a * in.light.a);
+}
@@ -0,0 +1,63 @@
+use crate::{load_skeleton, Assets};
+use spine::format::skeleton::{to_binary, to_json};
+use std::{error::Error, path::PathBuf};
+
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    #[command(flatten)]
+    assets: Assets,
+    /// The file to write, as Spine JSON if it ends in `.json` and as a binary `.skel` otherwise.
+    #[arg(long, short)]
+    output: PathBuf,
+}
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let atlas = args.assets.load_atlas()?;
+    let skeleton_data = load_skeleton(&args.assets.skeleton, atlas)?;
+
+    let is_json = args
+        .output
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    if is_json {
+        std::fs::write(&args.output, to_json(&skeleton_data))?;
+    } else {
+        std::fs::write(&args.output, to_binary(&skeleton_data))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::dragon;
+
+    #[test]
+    fn converts_both_ways() {
+        let output = |name: &str| {
+            std::env::temp_dir().join(format!("spine-cli-{}-{name}", std::process::id()))
+        };
+        let binary = Args {
+            assets: dragon("dragon-ess.json"),
+            output: output("dragon.skel"),
+        };
+        run(&binary).unwrap();
+
+        let mut assets = dragon("dragon-ess.skel");
+        assets.skeleton = binary.output.clone();
+        let json = Args {
+            assets,
+            output: output("dragon.json"),
+        };
+        run(&json).unwrap();
+
+        let atlas = json.assets.load_atlas().unwrap();
+        let original = load_skeleton(&dragon("dragon-ess.json").skeleton, atlas).unwrap();
+        assert_eq!(
+            to_json(&original),
+            std::fs::read_to_string(&json.output).unwrap()
+        );
+        std::fs::remove_file(&binary.output).unwrap();
+        std::fs::remove_file(&json.output).unwrap();
+    }
+}
@@ -0,0 +1,353 @@
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+
+use crate::{load_skeleton, Assets};
+use serde::Serialize;
+use spine::{
+    animation::{AnimationState, AnimationStateData, TrackIndex},
+    atlas::Atlas,
+    raster::{Camera, Image, Rasterizer},
+    skeleton::{Skeleton, Slot},
+};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// One PNG file per frame, in the output directory.
+    Png,
+    /// A single PNG sprite sheet, with a JSON manifest of frame rectangles next to it.
+    Sheet,
+    /// An animated GIF.
+    Gif,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    #[command(flatten)]
+    pub assets: Assets,
+    /// An animation to play. Repeat it to queue more animations, each starting when the previous
+    /// one ends.
+    #[arg(long = "animation", short, required = true)]
+    pub animations: Vec<String>,
+    /// Mix duration in seconds between queued animations.
+    #[arg(long, default_value_t = 0.0)]
+    pub mix: f32,
+    /// The skin to show, instead of the default skin.
+    #[arg(long)]
+    pub skin: Option<String>,
+    #[arg(long, default_value_t = 30)]
+    pub fps: u32,
+    /// Seconds to render, instead of the total duration of the queued animations.
+    #[arg(long)]
+    pub duration: Option<f32>,
+    #[arg(long, default_value_t = 256)]
+    pub width: u32,
+    #[arg(long, default_value_t = 256)]
+    pub height: u32,
+    /// Treats the atlas pages as having premultiplied alpha, even if the atlas does not say so.
+    #[arg(long)]
+    pub pma: bool,
+    #[arg(long, value_enum, default_value_t = Format::Png)]
+    pub format: Format,
+    /// The directory for `png` frames, or the file for a `sheet` or `gif`.
+    #[arg(long, short)]
+    pub output: PathBuf,
+    /// Frames per row of a `sheet`. Defaults to a roughly square sheet.
+    #[arg(long)]
+    pub columns: Option<u32>,
+}
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let frames = render(args)?;
+    match args.format {
+        Format::Png => write_pngs(&frames, &args.output),
+        Format::Sheet => write_sheet(&frames, args, &args.output),
+        Format::Gif => write_gif(&frames, args.fps, &args.output),
+    }
+}
+
+/// A rendered frame and the time it shows, in seconds from the start.
+pub struct Frame {
+    pub time: f32,
+    pub image: Image,
+}
+
+/// Renders every frame of `args`.
+///
+/// # Errors
+/// Returns an error if the assets fail to load, or an animation or the skin does not exist.
+pub fn render(args: &Args) -> Result<Vec<Frame>, Box<dyn Error>> {
+    if args.fps == 0 {
+        return Err("fps must be greater than 0".into());
+    }
+
+    let mut rasterizer = Rasterizer::new();
+    let atlas = load_atlas(&mut rasterizer, args)?;
+    let skeleton_data = load_skeleton(&args.assets.skeleton, atlas)?;
+
+    let animations = skeleton_data.animations();
+    let mut duration = 0.0;
+    for name in &args.animations {
+        match animations.iter().find(|animation| animation.name() == name) {
+            Some(animation) => duration += animation.duration(),
+            None => return Err(format!("no animation named {name:?}").into()),
+        }
+    }
+    let duration = args.duration.unwrap_or(duration);
+    let count = ((duration * args.fps as f32).round() as usize).max(1);
+
+    let mut state_data = AnimationStateData::new(&skeleton_data);
+    state_data.set_default_mix(args.mix);
+
+    // Plays the queue from the start, calling `frame` with the posed skeleton of every frame.
+    let play = |frame: &mut dyn FnMut(&Skeleton)| -> Result<(), Box<dyn Error>> {
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        if let Some(skin) = &args.skin {
+            if !skeleton.set_skin_by_name(skin) {
+                return Err(format!("no skin named {skin:?}").into());
+            }
+            skeleton.reset_slots();
+        }
+
+        let mut state = AnimationState::new(&state_data);
+        let (first, queued) = args.animations.split_first().unwrap();
+        state.set_by_name(first, TrackIndex::zero(), false);
+        for name in queued {
+            state.add_by_name(name, TrackIndex::zero(), false, 0.0);
+        }
+
+        for n in 0..count {
+            state.update(if n == 0 { 0.0 } else { 1.0 / args.fps as f32 });
+            state.apply(&mut skeleton);
+            skeleton.update_world_transforms();
+            frame(&skeleton);
+        }
+        Ok(())
+    };
+
+    // Every frame shares one camera fitting the whole animation, so the skeleton does not jump.
+    let mut min = [f32::MAX; 2];
+    let mut max = [f32::MIN; 2];
+    play(&mut |skeleton| {
+        for vertex in skeleton.draw_slots().iter().flat_map(Slot::world_vertices) {
+            for axis in 0..2 {
+                min[axis] = min[axis].min(vertex.position[axis]);
+                max[axis] = max[axis].max(vertex.position[axis]);
+            }
+        }
+    })?;
+    let camera = if min[0] > max[0] {
+        Camera::new([0.0, 0.0], 1.0)
+    } else {
+        Camera::fit(min, max, args.width, args.height)
+    };
+
+    let mut frames = Vec::with_capacity(count);
+    play(&mut |skeleton| {
+        frames.push(Frame {
+            time: frames.len() as f32 / args.fps as f32,
+            image: rasterizer.render(skeleton, camera, args.width, args.height),
+        });
+    })?;
+    Ok(frames)
+}
+
+/// Loads the atlas of `args` like `Rasterizer::load_atlas`, with every page premultiplied for
+/// `--pma`.
+fn load_atlas(rasterizer: &mut Rasterizer, args: &Args) -> Result<Atlas, Box<dyn Error>> {
+    let mut paths = Vec::new();
+    let mut atlas = Atlas::from_file(&args.assets.atlas, |_, path| {
+        paths.push(path.to_owned());
+        u32::try_from(paths.len()).unwrap()
+    })?;
+    if args.pma {
+        atlas.set_premultiplied_alpha(true);
+    }
+    for (page, path) in atlas.pages().iter().zip(paths) {
+        let image = Image::from_png(path)?;
+        rasterizer.insert_texture(page.texture_id(), image, page.premultiplied_alpha());
+    }
+    Ok(atlas)
+}
+
+fn write_pngs(frames: &[Frame], directory: &Path) -> Result<(), Box<dyn Error>> {
+    std::fs::create_dir_all(directory)?;
+    for (n, frame) in frames.iter().enumerate() {
+        frame
+            .image
+            .write_png(directory.join(format!("frame_{n:04}.png")))?;
+    }
+    Ok(())
+}
+
+/// The manifest written next to a sprite sheet.
+#[derive(Debug, Serialize)]
+pub struct Manifest {
+    /// The file name of the sheet image.
+    pub image: String,
+    pub fps: u32,
+    pub frames: Vec<ManifestFrame>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ManifestFrame {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Seconds from the start of the animation.
+    pub time: f32,
+}
+
+fn write_sheet(frames: &[Frame], args: &Args, path: &Path) -> Result<(), Box<dyn Error>> {
+    let columns = args
+        .columns
+        .unwrap_or_else(|| (frames.len() as f32).sqrt().ceil() as u32)
+        .max(1);
+    let rows = (frames.len() as u32).div_ceil(columns);
+    let mut sheet = Image::new(columns * args.width, rows * args.height);
+
+    let mut manifest = Manifest {
+        image: path
+            .file_name()
+            .ok_or("the sheet output must be a file")?
+            .to_string_lossy()
+            .into_owned(),
+        fps: args.fps,
+        frames: Vec::with_capacity(frames.len()),
+    };
+    for (n, frame) in frames.iter().enumerate() {
+        let x = n as u32 % columns * args.width;
+        let y = n as u32 / columns * args.height;
+        let row_bytes = args.width as usize * 4;
+        for row in 0..args.height as usize {
+            let from = row * row_bytes;
+            let to = ((y as usize + row) * sheet.width as usize + x as usize) * 4;
+            sheet.pixels[to..to + row_bytes]
+                .copy_from_slice(&frame.image.pixels[from..from + row_bytes]);
+        }
+        manifest.frames.push(ManifestFrame {
+            x,
+            y,
+            width: args.width,
+            height: args.height,
+            time: frame.time,
+        });
+    }
+
+    sheet.write_png(path)?;
+    let file = File::create(path.with_extension("json"))?;
+    serde_json::to_writer_pretty(BufWriter::new(file), &manifest)?;
+    Ok(())
+}
+
+fn write_gif(frames: &[Frame], fps: u32, path: &Path) -> Result<(), Box<dyn Error>> {
+    let (width, height) = match frames.first() {
+        Some(frame) => (frame.image.width, frame.image.height),
+        None => return Ok(()),
+    };
+    let width = u16::try_from(width).map_err(|_| "gif frames are at most 65535 pixels wide")?;
+    let height = u16::try_from(height).map_err(|_| "gif frames are at most 65535 pixels high")?;
+
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = gif::Encoder::new(file, width, height, &[])?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    // GIF delays are in hundredths of a second.
+    let delay = (100.0 / fps as f32).round() as u16;
+    for frame in frames {
+        let mut pixels = frame.image.pixels.clone();
+        let mut gif_frame = gif::Frame::from_rgba_speed(width, height, &mut pixels, 10);
+        gif_frame.delay = delay;
+        gif_frame.dispose = gif::DisposalMethod::Background;
+        encoder.write_frame(&gif_frame)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::dragon;
+
+    fn args(format: Format, output: &str) -> Args {
+        Args {
+            assets: dragon("dragon-ess.skel"),
+            animations: vec!["flying".to_owned()],
+            mix: 0.0,
+            skin: None,
+            fps: 10,
+            duration: None,
+            width: 48,
+            height: 32,
+            pma: false,
+            format,
+            output: std::env::temp_dir().join(format!("spine-cli-{}-{output}", std::process::id())),
+            columns: None,
+        }
+    }
+
+    #[test]
+    fn frames_follow_the_queue() {
+        let mut args = args(Format::Png, "queue");
+        args.animations.push("flying".to_owned());
+        args.mix = 0.2;
+        let frames = render(&args).unwrap();
+
+        assert_eq!(20, frames.len());
+        assert!((frames[19].time - 1.9).abs() < 1e-5);
+        assert_ne!(frames[0].image, frames[5].image);
+        assert!(frames[5].image.pixels.chunks(4).any(|pixel| pixel[3] > 0));
+
+        args.animations.push("walk".to_owned());
+        assert!(render(&args).is_err());
+        args.animations.pop();
+        args.skin = Some("missing".to_owned());
+        assert!(render(&args).is_err());
+    }
+
+    #[test]
+    fn writes_every_format() {
+        let args = args(Format::Png, "frames");
+        run(&args).unwrap();
+        assert!(args.output.join("frame_0009.png").exists());
+        assert!(!args.output.join("frame_0010.png").exists());
+        std::fs::remove_dir_all(&args.output).unwrap();
+
+        let mut args = self::args(Format::Sheet, "sheet.png");
+        args.columns = Some(4);
+        run(&args).unwrap();
+        let sheet = Image::from_png(&args.output).unwrap();
+        assert_eq!((4 * 48, 3 * 32), (sheet.width, sheet.height));
+        let manifest: serde_json::Value =
+            serde_json::from_reader(File::open(args.output.with_extension("json")).unwrap())
+                .unwrap();
+        assert_eq!(10, manifest["frames"].as_array().unwrap().len());
+        assert_eq!(96, manifest["frames"][6]["x"]);
+        assert_eq!(32, manifest["frames"][6]["y"]);
+        std::fs::remove_file(&args.output).unwrap();
+        std::fs::remove_file(args.output.with_extension("json")).unwrap();
+
+        let args = self::args(Format::Gif, "dragon.gif");
+        run(&args).unwrap();
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(File::open(&args.output).unwrap())
+            .unwrap();
+        let mut count = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(10, frame.delay);
+            count += 1;
+        }
+        assert_eq!(10, count);
+        std::fs::remove_file(&args.output).unwrap();
+    }
+}
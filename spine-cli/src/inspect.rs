@@ -0,0 +1,460 @@
+use crate::{load_skeleton, Assets};
+use serde::Serialize;
+use spine::{
+    atlas::Atlas,
+    skeleton::{BoneData, SkeletonData, Skin},
+};
+use std::{error::Error, fmt};
+
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    #[command(flatten)]
+    assets: Assets,
+    /// Prints the report as JSON instead of text.
+    #[arg(long)]
+    json: bool,
+}
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let report = Report::load(&args.assets)?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{report}");
+    }
+    Ok(())
+}
+
+/// Everything `inspect` prints, in a form that serializes to its `--json` output.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub version: Option<String>,
+    pub hash: Option<String>,
+    pub bones: Vec<BoneReport>,
+    pub slots: Vec<SlotReport>,
+    pub skins: Vec<SkinReport>,
+    pub events: Vec<EventReport>,
+    pub constraints: Vec<ConstraintReport>,
+    pub animations: Vec<AnimationReport>,
+    pub pages: Vec<PageReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BoneReport {
+    pub name: String,
+    pub parent: Option<String>,
+    pub length: f32,
+    pub position: [f32; 2],
+    pub rotation: f32,
+    pub scale: [f32; 2],
+}
+
+#[derive(Debug, Serialize)]
+pub struct SlotReport {
+    pub name: String,
+    pub bone: String,
+    pub attachment: Option<String>,
+    pub blend_mode: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SkinReport {
+    pub name: String,
+    /// The attachments of this skin, grouped by the slot they are stored under.
+    pub slots: Vec<SkinSlotReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SkinSlotReport {
+    pub slot: String,
+    pub attachments: Vec<AttachmentReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AttachmentReport {
+    pub name: String,
+    pub kind: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EventReport {
+    pub name: String,
+    pub int: i32,
+    pub float: f32,
+    pub string: Option<String>,
+    pub audio_path: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConstraintReport {
+    pub kind: &'static str,
+    pub name: String,
+    pub order: i32,
+    pub bones: Vec<String>,
+    pub target: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AnimationReport {
+    pub name: String,
+    pub duration: f32,
+    /// The bones keyed by the animation.
+    pub bones: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PageReport {
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    pub regions: Vec<RegionReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RegionReport {
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+fn names(bones: &[BoneData<'_>]) -> Vec<String> {
+    bones.iter().map(|bone| bone.name().to_owned()).collect()
+}
+
+impl Report {
+    /// Loads the atlas and skeleton of `assets` and collects their contents.
+    ///
+    /// # Errors
+    /// Returns an error if either file fails to load.
+    pub fn load(assets: &Assets) -> Result<Self, Box<dyn Error>> {
+        let atlas = assets.load_atlas()?;
+        let pages = Self::pages(&atlas);
+        let skeleton_data = load_skeleton(&assets.skeleton, atlas)?;
+        Ok(Self::new(&skeleton_data, pages))
+    }
+
+    fn pages(atlas: &Atlas) -> Vec<PageReport> {
+        let regions = atlas.regions();
+        atlas
+            .pages()
+            .iter()
+            .map(|page| {
+                let (width, height) = page.dimensions();
+                PageReport {
+                    name: page.name().to_owned(),
+                    width,
+                    height,
+                    regions: regions
+                        .iter()
+                        .filter(|region| region.page().name() == page.name())
+                        .map(|region| {
+                            let (x, y) = region.position();
+                            let (width, height) = region.dimensions();
+                            RegionReport {
+                                name: region.name().to_owned(),
+                                x,
+                                y,
+                                width,
+                                height,
+                            }
+                        })
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    fn skin(skin: &Skin<'_>) -> SkinReport {
+        let mut slots: Vec<SkinSlotReport> = Vec::new();
+        for entry in skin.attachments() {
+            let attachment = AttachmentReport {
+                name: entry.name.to_owned(),
+                kind: format!("{:?}", entry.attachment.kind()),
+            };
+            match slots.last_mut() {
+                Some(slot) if slot.slot == entry.slot.name() => slot.attachments.push(attachment),
+                _ => slots.push(SkinSlotReport {
+                    slot: entry.slot.name().to_owned(),
+                    attachments: vec![attachment],
+                }),
+            }
+        }
+
+        SkinReport {
+            name: skin.name().to_owned(),
+            slots,
+        }
+    }
+
+    fn new(skeleton_data: &SkeletonData, pages: Vec<PageReport>) -> Self {
+        let mut constraints = Vec::new();
+        for ik in skeleton_data.ik_constraints() {
+            constraints.push(ConstraintReport {
+                kind: "ik",
+                name: ik.name().to_owned(),
+                order: ik.order(),
+                bones: names(&ik.bones()),
+                target: ik.target().name().to_owned(),
+            });
+        }
+        for transform in skeleton_data.transform_constraints() {
+            constraints.push(ConstraintReport {
+                kind: "transform",
+                name: transform.name().to_owned(),
+                order: transform.order(),
+                bones: names(&transform.bones()),
+                target: transform.target().name().to_owned(),
+            });
+        }
+        for path in skeleton_data.path_constraints() {
+            constraints.push(ConstraintReport {
+                kind: "path",
+                name: path.name().to_owned(),
+                order: path.order(),
+                bones: names(&path.bones()),
+                target: path.target().name().to_owned(),
+            });
+        }
+        constraints.sort_by_key(|constraint| constraint.order);
+
+        Self {
+            version: skeleton_data.version().map(str::to_owned),
+            hash: skeleton_data.hash().map(str::to_owned),
+            bones: skeleton_data
+                .bones()
+                .iter()
+                .map(|bone| BoneReport {
+                    name: bone.name().to_owned(),
+                    parent: bone.parent().map(|parent| parent.name().to_owned()),
+                    length: bone.length(),
+                    position: bone.position(),
+                    rotation: bone.rotation(),
+                    scale: bone.scale(),
+                })
+                .collect(),
+            slots: skeleton_data
+                .slots()
+                .iter()
+                .map(|slot| SlotReport {
+                    name: slot.name().to_owned(),
+                    bone: slot.bone().name().to_owned(),
+                    attachment: slot.attachment_name().map(str::to_owned),
+                    blend_mode: format!("{:?}", slot.blend_mode()),
+                })
+                .collect(),
+            skins: skeleton_data.skins().iter().map(Self::skin).collect(),
+            events: skeleton_data
+                .events()
+                .iter()
+                .map(|event| EventReport {
+                    name: event.name().to_owned(),
+                    int: event.int(),
+                    float: event.float(),
+                    string: event.string().map(str::to_owned),
+                    audio_path: event.audio_path().map(str::to_owned),
+                })
+                .collect(),
+            constraints,
+            animations: skeleton_data
+                .animations()
+                .iter()
+                .map(|animation| AnimationReport {
+                    name: animation.name().to_owned(),
+                    duration: animation.duration(),
+                    bones: names(&animation.bones()),
+                })
+                .collect(),
+            pages,
+        }
+    }
+
+    /// Writes `bone` and its children, indented by depth.
+    fn bone_tree(&self, f: &mut fmt::Formatter, bone: &BoneReport, depth: usize) -> fmt::Result {
+        writeln!(f, "  {:width$}{}", "", bone.name, width = depth * 2)?;
+        for child in self
+            .bones
+            .iter()
+            .filter(|child| child.parent.as_deref() == Some(bone.name.as_str()))
+        {
+            self.bone_tree(f, child, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the report for reading in a terminal.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "spine {} (hash {})",
+            self.version.as_deref().unwrap_or("unknown"),
+            self.hash.as_deref().unwrap_or("none")
+        )?;
+
+        writeln!(f, "\nbones ({}):", self.bones.len())?;
+        for root in self.bones.iter().filter(|bone| bone.parent.is_none()) {
+            self.bone_tree(f, root, 0)?;
+        }
+
+        writeln!(f, "\nslots ({}):", self.slots.len())?;
+        for slot in &self.slots {
+            writeln!(
+                f,
+                "  {} (bone {}, attachment {}, {})",
+                slot.name,
+                slot.bone,
+                slot.attachment.as_deref().unwrap_or("none"),
+                slot.blend_mode
+            )?;
+        }
+
+        writeln!(f, "\nskins ({}):", self.skins.len())?;
+        for skin in &self.skins {
+            writeln!(f, "  {}", skin.name)?;
+            for slot in &skin.slots {
+                let attachments = slot
+                    .attachments
+                    .iter()
+                    .map(|attachment| format!("{} ({})", attachment.name, attachment.kind))
+                    .collect::<Vec<_>>();
+                writeln!(f, "    {}: {}", slot.slot, attachments.join(", "))?;
+            }
+        }
+
+        writeln!(f, "\nevents ({}):", self.events.len())?;
+        for event in &self.events {
+            writeln!(
+                f,
+                "  {} (int {}, float {}, string {:?}, audio {:?})",
+                event.name, event.int, event.float, event.string, event.audio_path
+            )?;
+        }
+
+        writeln!(f, "\nconstraints ({}):", self.constraints.len())?;
+        for constraint in &self.constraints {
+            writeln!(
+                f,
+                "  {} {} (order {}): {} -> {}",
+                constraint.kind,
+                constraint.name,
+                constraint.order,
+                constraint.bones.join(", "),
+                constraint.target
+            )?;
+        }
+
+        writeln!(f, "\nanimations ({}):", self.animations.len())?;
+        for animation in &self.animations {
+            writeln!(
+                f,
+                "  {} ({:.3}s): {}",
+                animation.name,
+                animation.duration,
+                animation.bones.join(", ")
+            )?;
+        }
+
+        writeln!(f, "\natlas pages ({}):", self.pages.len())?;
+        for page in &self.pages {
+            writeln!(
+                f,
+                "  {} ({}x{}, {} regions)",
+                page.name,
+                page.width,
+                page.height,
+                page.regions.len()
+            )?;
+            for region in &page.regions {
+                writeln!(
+                    f,
+                    "    {} ({}x{} at {},{})",
+                    region.name, region.width, region.height, region.x, region.y
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::dragon;
+
+    #[test]
+    fn json_and_binary_agree() {
+        let json = Report::load(&dragon("dragon-ess.json")).unwrap();
+        let binary = Report::load(&dragon("dragon-ess.skel")).unwrap();
+
+        // The two exports were made separately, so only the structure is the same.
+        let structure = |report: &Report| {
+            (
+                report.version.clone(),
+                report
+                    .bones
+                    .iter()
+                    .map(|bone| (bone.name.clone(), bone.parent.clone()))
+                    .collect::<Vec<_>>(),
+                report
+                    .slots
+                    .iter()
+                    .map(|slot| slot.name.clone())
+                    .collect::<Vec<_>>(),
+                report
+                    .animations
+                    .iter()
+                    .map(|animation| animation.bones.clone())
+                    .collect::<Vec<_>>(),
+                serde_json::to_value(&report.skins).unwrap(),
+            )
+        };
+        assert_eq!(structure(&json), structure(&binary));
+    }
+
+    #[test]
+    fn dragon_report() {
+        let report = Report::load(&dragon("dragon-ess.json")).unwrap();
+
+        assert_eq!(Some("3.8.55"), report.version.as_deref());
+        assert_eq!(33, report.bones.len());
+        assert_eq!(31, report.slots.len());
+        assert_eq!(1, report.skins.len());
+        assert_eq!(2, report.pages.len());
+        assert!(report.pages[0]
+            .regions
+            .iter()
+            .any(|region| region.name == "chin"));
+
+        let flying = &report.animations[0];
+        assert_eq!("flying", flying.name);
+        assert!(flying.bones.contains(&"center".to_owned()));
+
+        let text = report.to_string();
+        assert!(text.starts_with("spine 3.8.55"));
+        // Children are indented below their parent.
+        assert!(text.contains("\n  root\n    center\n"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&report).unwrap()).unwrap();
+        assert_eq!(
+            "chin",
+            json["slots"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|slot| slot["name"] == "chin")
+                .unwrap()["bone"]
+        );
+    }
+
+    #[test]
+    fn missing_skeleton() {
+        let mut assets = dragon("dragon-ess.json");
+        assets.skeleton.set_file_name("missing.json");
+        assert!(Report::load(&assets).is_err());
+    }
+}
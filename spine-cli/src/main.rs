@@ -0,0 +1,100 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+mod convert;
+mod export;
+mod inspect;
+mod validate;
+
+use clap::{Parser, Subcommand};
+use spine::{atlas::Atlas, skeleton::SkeletonData, SpineError};
+use std::path::{Path, PathBuf};
+
+/// Tools for working with Spine assets.
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Prints the contents of a skeleton and its atlas.
+    Inspect(inspect::Args),
+    /// Renders animations to PNG frames, a sprite sheet or an animated GIF, without a GPU.
+    Export(export::Args),
+    /// Cross-checks a skeleton against its atlas and reports every problem found.
+    Validate(validate::Args),
+    /// Rewrites a skeleton as Spine JSON or as a binary `.skel`.
+    Convert(convert::Args),
+}
+
+/// The atlas and skeleton file every subcommand loads.
+#[derive(Debug, clap::Args)]
+pub struct Assets {
+    /// The `.atlas` file the skeleton's attachments are packed into.
+    #[arg(long)]
+    pub atlas: PathBuf,
+    /// The skeleton, either a `.json` export or a binary `.skel` export.
+    pub skeleton: PathBuf,
+}
+impl Assets {
+    /// Loads the atlas, without creating any textures.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadAtlas` if the atlas fails to load.
+    pub fn load_atlas(&self) -> Result<Atlas, SpineError> {
+        Atlas::from_file(&self.atlas, |_, _| 0)
+    }
+}
+
+/// Loads a skeleton, picking the JSON or binary loader from the file extension.
+///
+/// Binary skeletons are trusted to be complete exports, as the person running the command chose
+/// them, see `SkeletonData::from_binary_file`.
+///
+/// # Errors
+/// Returns a `SpineError::FailLoadSkeleton` if the skeleton fails to load.
+pub fn load_skeleton(path: &Path, atlas: Atlas) -> Result<SkeletonData, SpineError> {
+    let is_json = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    if is_json {
+        SkeletonData::from_json_file(path, atlas)
+    } else {
+        // SAFETY: the command line names the exports to read, like the files a game ships with.
+        unsafe { SkeletonData::from_binary_file(path, atlas) }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Inspect(args) => inspect::run(&args),
+        Command::Export(args) => export::run(&args),
+        Command::Validate(args) => validate::run(&args),
+        Command::Convert(args) => convert::run(&args),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::Assets;
+    use std::path::PathBuf;
+
+    /// The dragon example the other crates test against.
+    pub fn dragon(skeleton: &str) -> Assets {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../spine-example/examples/dragon/export");
+        Assets {
+            atlas: path.join("dragon.atlas"),
+            skeleton: path.join(skeleton),
+        }
+    }
+}
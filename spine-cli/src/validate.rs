@@ -0,0 +1,115 @@
+use crate::Assets;
+use serde::Serialize;
+use spine::validate::{Diagnostic, Severity, Validator};
+use std::error::Error;
+
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    #[command(flatten)]
+    assets: Assets,
+    /// The largest width or height an atlas page may have.
+    #[arg(long, default_value_t = 4096)]
+    max_page_size: i32,
+    /// The Spine editor version the skeleton must be exported from.
+    #[arg(long, default_value = "3.8")]
+    expected_version: String,
+    /// Prints the diagnostics as JSON instead of text.
+    #[arg(long)]
+    json: bool,
+}
+
+/// A diagnostic as printed by `--json`.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub severity: &'static str,
+    pub code: &'static str,
+    pub message: String,
+}
+impl From<&Diagnostic> for Report {
+    fn from(diagnostic: &Diagnostic) -> Self {
+        Self {
+            severity: match diagnostic.severity() {
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+            },
+            code: diagnostic.code(),
+            message: diagnostic.to_string(),
+        }
+    }
+}
+
+/// Validates the assets of `args`, returning the diagnostics found.
+///
+/// # Errors
+/// Returns an error if the atlas or skeleton cannot be loaded at all.
+pub fn diagnostics(args: &Args) -> Result<Vec<Diagnostic>, Box<dyn Error>> {
+    let atlas = args.assets.load_atlas()?;
+    let mut validator = Validator::new();
+    validator.set_max_page_size(args.max_page_size);
+    validator.set_expected_version(&args.expected_version);
+    Ok(validator.validate(&atlas, &args.assets.skeleton)?)
+}
+
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let diagnostics = diagnostics(args)?;
+
+    if args.json {
+        let reports = diagnostics.iter().map(Report::from).collect::<Vec<_>>();
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+    } else {
+        for diagnostic in &diagnostics {
+            println!(
+                "{}: {} [{}]",
+                Report::from(diagnostic).severity,
+                diagnostic,
+                diagnostic.code()
+            );
+        }
+    }
+
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity() == Severity::Error)
+        .count();
+    if errors > 0 {
+        return Err(format!("{errors} error(s) found").into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::dragon;
+
+    #[test]
+    fn errors_fail_the_command() {
+        let mut args = Args {
+            assets: dragon("dragon-ess.skel"),
+            max_page_size: 4096,
+            expected_version: "3.8".to_owned(),
+            json: false,
+        };
+        assert!(diagnostics(&args).unwrap().is_empty());
+        assert!(run(&args).is_ok());
+
+        args.max_page_size = 512;
+        assert_eq!(
+            vec!["oversized-page"; 2],
+            diagnostics(&args)
+                .unwrap()
+                .iter()
+                .map(Diagnostic::code)
+                .collect::<Vec<_>>()
+        );
+        assert!(run(&args).is_ok());
+
+        args.expected_version = "4.1".to_owned();
+        let report = Report::from(&diagnostics(&args).unwrap()[0]);
+        assert_eq!(
+            ("error", "version-mismatch"),
+            (report.severity, report.code)
+        );
+        assert!(run(&args).is_err());
+    }
+}
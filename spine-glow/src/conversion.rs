@@ -0,0 +1,56 @@
+use spine::enums::{AtlasFilter, AtlasWrap, BlendFactor};
+
+/// Converts Spine enums to the OpenGL enums glow takes.
+pub trait GlowConversion {
+    type Output;
+
+    fn glow(&self) -> Self::Output;
+}
+
+impl GlowConversion for AtlasFilter {
+    type Output = u32;
+
+    /// Filters without a mipmap level, `Unknown` included, map to `LINEAR` like spine-runtimes
+    /// does, and `Mipmap` maps to `LINEAR_MIPMAP_LINEAR`.
+    fn glow(&self) -> Self::Output {
+        match *self {
+            Self::Nearest => glow::NEAREST,
+            Self::Unknown | Self::Linear => glow::LINEAR,
+            Self::Mipmap | Self::MipmapLinearLinear => glow::LINEAR_MIPMAP_LINEAR,
+            Self::MipmapNereastNearest => glow::NEAREST_MIPMAP_NEAREST,
+            Self::MipmapLinearNearest => glow::LINEAR_MIPMAP_NEAREST,
+            Self::MipmapNearestLinear => glow::NEAREST_MIPMAP_LINEAR,
+        }
+    }
+}
+
+impl GlowConversion for AtlasWrap {
+    type Output = u32;
+
+    fn glow(&self) -> Self::Output {
+        match *self {
+            Self::MirroedRepeat => glow::MIRRORED_REPEAT,
+            Self::ClampToEdge => glow::CLAMP_TO_EDGE,
+            Self::Repeat => glow::REPEAT,
+        }
+    }
+}
+
+impl GlowConversion for BlendFactor {
+    type Output = u32;
+
+    fn glow(&self) -> Self::Output {
+        match *self {
+            Self::One => glow::ONE,
+            Self::SrcAlpha => glow::SRC_ALPHA,
+            Self::OneMinusSrcAlpha => glow::ONE_MINUS_SRC_ALPHA,
+            Self::DstColor => glow::DST_COLOR,
+            Self::OneMinusSrcColor => glow::ONE_MINUS_SRC_COLOR,
+        }
+    }
+}
+
+/// Whether a minifying filter samples mipmaps, which must then be generated.
+pub(crate) fn uses_mipmaps(filter: u32) -> bool {
+    !matches!(filter, glow::NEAREST | glow::LINEAR)
+}
@@ -0,0 +1,18 @@
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+//! Draws Spine skeletons with glow, on OpenGL 3.3+, OpenGL ES 3.0+ and WebGL2.
+//!
+//! `GlowSkeletonRenderer` owns its shader program and buffers. Every call to
+//! `GlowSkeletonRenderer::draw` batches the skeletons it is given by texture and blend mode, with
+//! meshes, clipping and two color tinting, grows its buffers as needed and draws each batch with
+//! a single draw call. On the web, build with the `web-sys` feature and pass
+//! `"#version 300 es"` as the shader version.
+
+pub mod conversion;
+pub mod renderer;
+
+pub use crate::{
+    conversion::GlowConversion,
+    renderer::{orthographic, Draw, GlowSkeletonRenderer},
+};
@@ -0,0 +1,773 @@
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss
+)]
+
+use crate::conversion::{uses_mipmaps, GlowConversion};
+use glow::HasContext;
+use spine::{
+    atlas::{Atlas, AtlasPage},
+    batch::SkeletonBatcher,
+    enums::BlendMode,
+    raster::Image,
+    skeleton::{Skeleton, WorldVertex},
+    SpineError,
+};
+use std::{collections::HashMap, ops::Range, path::Path};
+
+const VERTEX_SHADER: &str = r"
+layout (location = 0) in vec2 a_position;
+layout (location = 1) in vec2 a_texCoords;
+layout (location = 2) in vec4 a_light;
+layout (location = 3) in vec4 a_dark;
+
+uniform mat4 u_viewProjection;
+
+out vec2 v_texCoords;
+out vec4 v_light;
+out vec4 v_dark;
+
+void main() {
+    v_texCoords = a_texCoords;
+    v_light = a_light;
+    v_dark = a_dark;
+    gl_Position = u_viewProjection * vec4(a_position, 0.0, 1.0);
+}
+";
+
+// Two color tinting as in the spine-runtimes shaders: the light color tints the texture and the
+// dark color replaces its black.
+const FRAGMENT_SHADER: &str = r"
+precision mediump float;
+
+uniform sampler2D u_texture;
+
+in vec2 v_texCoords;
+in vec4 v_light;
+in vec4 v_dark;
+
+out vec4 fragColor;
+
+void main() {
+    vec4 texColor = texture(u_texture, v_texCoords);
+    fragColor.a = texColor.a * v_light.a;
+    fragColor.rgb = ((texColor.a - 1.0) * v_dark.a + 1.0 - texColor.rgb) * v_dark.rgb
+        + texColor.rgb * v_light.rgb;
+}
+";
+
+/// A vertex as uploaded to the vertex buffer, in world space.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+struct Vertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+    light: [f32; 4],
+    dark: [f32; 4],
+}
+impl Vertex {
+    /// The vertex with its `WorldVertex::tint_colors`.
+    fn new(vertex: &WorldVertex, premultiplied_alpha: bool) -> Self {
+        let (light, dark) = vertex.tint_colors(premultiplied_alpha);
+        Self {
+            position: vertex.position,
+            uv: vertex.uv,
+            light,
+            dark,
+        }
+    }
+}
+
+/// The bytes of `slice`, for uploading `Vertex` and index data.
+fn as_u8_slice<T: Copy>(slice: &[T]) -> &[u8] {
+    // SAFETY: only used with `u32` and the `repr(C)` `Vertex`, which consist of 4 byte values
+    // without padding.
+    unsafe { std::slice::from_raw_parts(slice.as_ptr().cast(), std::mem::size_of_val(slice)) }
+}
+
+/// A range of indices of the last frame drawn with one texture and blend mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Draw {
+    pub texture_id: u32,
+    pub blend_mode: BlendMode,
+    pub indices: Range<u32>,
+}
+
+struct Texture<C: HasContext> {
+    texture: C::Texture,
+    premultiplied_alpha: bool,
+}
+
+/// A buffer that is reallocated to the next power of two when the data drawn outgrows it.
+struct DynamicBuffer<C: HasContext> {
+    buffer: C::Buffer,
+    target: u32,
+    capacity: usize,
+}
+impl<C: HasContext> DynamicBuffer<C> {
+    unsafe fn new(gl: &C, target: u32) -> Result<Self, String> {
+        Ok(Self {
+            buffer: gl.create_buffer()?,
+            target,
+            capacity: 0,
+        })
+    }
+
+    unsafe fn write(&mut self, gl: &C, data: &[u8]) {
+        gl.bind_buffer(self.target, Some(self.buffer));
+        if data.len() > self.capacity {
+            self.capacity = data.len().next_power_of_two();
+            gl.buffer_data_size(self.target, self.capacity as i32, glow::DYNAMIC_DRAW);
+        }
+        if !data.is_empty() {
+            gl.buffer_sub_data_u8_slice(self.target, 0, data);
+        }
+    }
+}
+
+/// Draws posed skeletons with glow.
+///
+/// Every call to `GlowSkeletonRenderer::draw` batches the skeletons it is given into one vertex
+/// and one index buffer. Consecutive slots sharing a texture and blend mode are drawn with a
+/// single draw call, also across skeletons, so skeletons sharing an atlas page draw in few calls
+/// when they are drawn together.
+///
+/// Textures are looked up by the texture id assigned while loading the `Atlas`, see
+/// `GlowSkeletonRenderer::load_atlas` and `GlowSkeletonRenderer::insert_texture`. Whether a
+/// texture stores premultiplied alpha picks the blend functions and vertex colors of the slots
+/// using it.
+///
+/// The renderer does not own the context. Every method must be called with the context it was
+/// created with, current on the calling thread, and `GlowSkeletonRenderer::destroy` releases the
+/// GL objects.
+pub struct GlowSkeletonRenderer<C: HasContext> {
+    program: C::Program,
+    vertex_array: C::VertexArray,
+    vertex_buffer: DynamicBuffer<C>,
+    index_buffer: DynamicBuffer<C>,
+    view_projection: Option<C::UniformLocation>,
+    texture_unit: Option<C::UniformLocation>,
+    textures: HashMap<u32, Texture<C>>,
+    vertices: Vec<Vertex>,
+    indices: Vec<u32>,
+    draws: Vec<Draw>,
+    batcher: SkeletonBatcher,
+}
+
+impl<C: HasContext> GlowSkeletonRenderer<C> {
+    /// Compiles the shaders, prefixed with `shader_version` such as `"#version 330"` or
+    /// `"#version 300 es"`, and creates the buffers.
+    ///
+    /// # Safety
+    /// `gl` must be current on the calling thread.
+    ///
+    /// # Errors
+    /// Returns the info log if a shader fails to compile or link, or the error of a GL object
+    /// that cannot be created.
+    pub unsafe fn new(gl: &C, shader_version: &str) -> Result<Self, String> {
+        let program = gl.create_program()?;
+        let mut shaders = Vec::new();
+        for (kind, source) in [
+            (glow::VERTEX_SHADER, VERTEX_SHADER),
+            (glow::FRAGMENT_SHADER, FRAGMENT_SHADER),
+        ] {
+            let shader = gl.create_shader(kind)?;
+            gl.shader_source(shader, &format!("{shader_version}\n{source}"));
+            gl.compile_shader(shader);
+            if !gl.get_shader_compile_status(shader) {
+                let log = gl.get_shader_info_log(shader);
+                gl.delete_shader(shader);
+                gl.delete_program(program);
+                return Err(log);
+            }
+            gl.attach_shader(program, shader);
+            shaders.push(shader);
+        }
+        gl.link_program(program);
+        for shader in shaders {
+            gl.detach_shader(program, shader);
+            gl.delete_shader(shader);
+        }
+        if !gl.get_program_link_status(program) {
+            let log = gl.get_program_info_log(program);
+            gl.delete_program(program);
+            return Err(log);
+        }
+
+        let vertex_array = gl.create_vertex_array()?;
+        gl.bind_vertex_array(Some(vertex_array));
+        let vertex_buffer = DynamicBuffer::new(gl, glow::ARRAY_BUFFER)?;
+        let index_buffer = DynamicBuffer::new(gl, glow::ELEMENT_ARRAY_BUFFER)?;
+        gl.bind_buffer(glow::ARRAY_BUFFER, Some(vertex_buffer.buffer));
+        gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, Some(index_buffer.buffer));
+        let stride = std::mem::size_of::<Vertex>() as i32;
+        for (location, (size, offset)) in [(2, 0), (2, 8), (4, 16), (4, 32)].into_iter().enumerate()
+        {
+            gl.vertex_attrib_pointer_f32(location as u32, size, glow::FLOAT, false, stride, offset);
+            gl.enable_vertex_attrib_array(location as u32);
+        }
+        gl.bind_vertex_array(None);
+
+        Ok(Self {
+            program,
+            vertex_array,
+            vertex_buffer,
+            index_buffer,
+            view_projection: gl.get_uniform_location(program, "u_viewProjection"),
+            texture_unit: gl.get_uniform_location(program, "u_texture"),
+            textures: HashMap::new(),
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            draws: Vec::new(),
+            batcher: SkeletonBatcher::new(),
+        })
+    }
+
+    /// Registers `texture` for atlas pages that were given `id` as their texture id, for textures
+    /// created elsewhere, such as from an image element on the web. The renderer takes ownership
+    /// of the texture.
+    pub fn insert_texture(&mut self, id: u32, texture: C::Texture, premultiplied_alpha: bool) {
+        self.textures.insert(
+            id,
+            Texture {
+                texture,
+                premultiplied_alpha,
+            },
+        );
+    }
+
+    /// Creates a texture holding `image`, sampled with the filters and wrapping of `page`.
+    /// Mipmaps are generated if the minifying filter samples them.
+    ///
+    /// # Safety
+    /// `gl` must be current on the calling thread.
+    ///
+    /// # Errors
+    /// Returns the GL error if the texture cannot be created.
+    pub unsafe fn create_texture(
+        gl: &C,
+        image: &Image,
+        page: &AtlasPage<'_>,
+    ) -> Result<C::Texture, String> {
+        let texture = gl.create_texture()?;
+        gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+        let (u_wrap, v_wrap) = page.wrap();
+        let min_filter = page.min_filter().glow();
+        for (parameter, value) in [
+            (glow::TEXTURE_WRAP_S, u_wrap.glow()),
+            (glow::TEXTURE_WRAP_T, v_wrap.glow()),
+            (glow::TEXTURE_MIN_FILTER, min_filter),
+            (glow::TEXTURE_MAG_FILTER, page.mag_filter().glow()),
+        ] {
+            gl.tex_parameter_i32(glow::TEXTURE_2D, parameter, value as i32);
+        }
+        gl.tex_image_2d(
+            glow::TEXTURE_2D,
+            0,
+            glow::RGBA8 as i32,
+            image.width as i32,
+            image.height as i32,
+            0,
+            glow::RGBA,
+            glow::UNSIGNED_BYTE,
+            Some(&image.pixels),
+        );
+        if uses_mipmaps(min_filter) {
+            gl.generate_mipmap(glow::TEXTURE_2D);
+        }
+        gl.bind_texture(glow::TEXTURE_2D, None);
+        Ok(texture)
+    }
+
+    /// Loads an atlas, decoding every page as a PNG file and uploading it under a new texture id
+    /// with the premultiplied alpha of the atlas.
+    ///
+    /// # Safety
+    /// `gl` must be current on the calling thread.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadAtlas` if the atlas cannot be loaded, or a
+    /// `SpineError::FailImage` if one of its pages cannot be decoded or uploaded.
+    pub unsafe fn load_atlas<P>(&mut self, gl: &C, path: P) -> Result<Atlas, SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut next_id = self.textures.keys().max().map_or(1, |id| id + 1);
+        let mut textures = Vec::new();
+        let mut error = None;
+
+        let atlas = Atlas::from_file(path, |page, page_path| {
+            let texture = Image::from_png(page_path).and_then(|image| {
+                Self::create_texture(gl, &image, page).map_err(SpineError::FailImage)
+            });
+            match texture {
+                Ok(texture) => {
+                    let id = next_id;
+                    next_id += 1;
+                    textures.push((id, texture));
+                    id
+                }
+                Err(e) => {
+                    error.get_or_insert(e);
+                    0
+                }
+            }
+        });
+
+        let atlas = match (atlas, error) {
+            (Ok(atlas), None) => atlas,
+            (Err(e), _) | (_, Some(e)) => {
+                for (_, texture) in textures {
+                    gl.delete_texture(texture);
+                }
+                return Err(e);
+            }
+        };
+        for (page, (id, texture)) in atlas.pages().iter().zip(textures) {
+            self.insert_texture(id, texture, page.premultiplied_alpha());
+        }
+        Ok(atlas)
+    }
+
+    /// Draws `skeletons` in order into the bound framebuffer, transformed by the column-major
+    /// `view_projection` matrix, see `orthographic`. World transforms must be up to date.
+    ///
+    /// Blending is enabled and the program, vertex array and texture unit 0 are left bound.
+    ///
+    /// # Safety
+    /// `gl` must be current on the calling thread.
+    ///
+    /// # Panics
+    /// Panics if the skeletons have more than `u32::MAX` vertices or indices.
+    pub unsafe fn draw(&mut self, gl: &C, skeletons: &[&Skeleton], view_projection: &[f32; 16]) {
+        self.vertices.clear();
+        self.indices.clear();
+        self.draws.clear();
+
+        for skeleton in skeletons {
+            for batch in self.batcher.batch(skeleton) {
+                let Some(texture) = self.textures.get(&batch.texture_id) else {
+                    continue;
+                };
+
+                let offset = u32::try_from(self.vertices.len()).unwrap();
+                let start = u32::try_from(self.indices.len()).unwrap();
+                self.vertices.extend(
+                    batch
+                        .vertices
+                        .iter()
+                        .map(|vertex| Vertex::new(vertex, texture.premultiplied_alpha)),
+                );
+                self.indices
+                    .extend(batch.indices.iter().map(|&index| offset + index));
+                let end = u32::try_from(self.indices.len()).unwrap();
+
+                match self.draws.last_mut() {
+                    Some(draw)
+                        if draw.texture_id == batch.texture_id
+                            && draw.blend_mode == batch.blend_mode =>
+                    {
+                        draw.indices.end = end;
+                    }
+                    _ => self.draws.push(Draw {
+                        texture_id: batch.texture_id,
+                        blend_mode: batch.blend_mode,
+                        indices: start..end,
+                    }),
+                }
+            }
+        }
+        if self.draws.is_empty() {
+            return;
+        }
+
+        gl.use_program(Some(self.program));
+        gl.uniform_matrix_4_f32_slice(self.view_projection.clone(), false, view_projection);
+        gl.uniform_1_i32(self.texture_unit.clone(), 0);
+        gl.active_texture(glow::TEXTURE0);
+        gl.bind_vertex_array(Some(self.vertex_array));
+        self.vertex_buffer.write(gl, as_u8_slice(&self.vertices));
+        self.index_buffer.write(gl, as_u8_slice(&self.indices));
+        gl.enable(glow::BLEND);
+
+        for draw in &self.draws {
+            let texture = &self.textures[&draw.texture_id];
+            let func = draw.blend_mode.blend_func(texture.premultiplied_alpha);
+            gl.blend_func_separate(
+                func.src_color.glow(),
+                func.dst_color.glow(),
+                func.src_alpha.glow(),
+                func.dst_alpha.glow(),
+            );
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture.texture));
+            gl.draw_elements(
+                glow::TRIANGLES,
+                (draw.indices.end - draw.indices.start) as i32,
+                glow::UNSIGNED_INT,
+                (draw.indices.start as usize * std::mem::size_of::<u32>()) as i32,
+            );
+        }
+    }
+
+    /// The draw calls of the last `GlowSkeletonRenderer::draw`.
+    pub fn draws(&self) -> &[Draw] {
+        &self.draws
+    }
+
+    /// Deletes the program, buffers and textures.
+    ///
+    /// # Safety
+    /// `gl` must be current on the calling thread.
+    pub unsafe fn destroy(self, gl: &C) {
+        gl.delete_program(self.program);
+        gl.delete_vertex_array(self.vertex_array);
+        gl.delete_buffer(self.vertex_buffer.buffer);
+        gl.delete_buffer(self.index_buffer.buffer);
+        for texture in self.textures.into_values() {
+            gl.delete_texture(texture.texture);
+        }
+    }
+}
+
+/// A column-major orthographic projection showing world space from `left` to `right` and from
+/// `bottom` to `top`.
+pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32) -> [f32; 16] {
+    let (width, height) = (right - left, top - bottom);
+    [
+        2.0 / width,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        2.0 / height,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        -1.0,
+        0.0,
+        -(right + left) / width,
+        -(top + bottom) / height,
+        0.0,
+        1.0,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khronos_egl as egl;
+    use spine::{
+        animation::{AnimationState, AnimationStateData, TrackIndex},
+        color::Color,
+        raster::{Camera, Rasterizer},
+        skeleton::{is_y_down, SkeletonData},
+    };
+
+    const DRAGON: &str = "../spine-example/examples/dragon/export";
+
+    /// `EGL_PLATFORM_SURFACELESS_MESA`, which needs neither a window system nor a GPU.
+    const PLATFORM_SURFACELESS_MESA: egl::Enum = 0x31DD;
+
+    /// A surfaceless OpenGL ES 3.0 context, the API WebGL2 is based on, made current on this
+    /// thread.
+    struct Headless {
+        egl: egl::DynamicInstance<egl::EGL1_5>,
+        display: egl::Display,
+        context: egl::Context,
+        gl: glow::Context,
+    }
+    impl Drop for Headless {
+        fn drop(&mut self) {
+            self.egl.make_current(self.display, None, None, None).ok();
+            self.egl.destroy_context(self.display, self.context).ok();
+        }
+    }
+
+    /// The context, or `None` where EGL, its surfaceless platform or a GLES 3 driver is missing.
+    fn context() -> Option<Headless> {
+        let context = || -> Result<Headless, String> {
+            let egl = unsafe { egl::DynamicInstance::<egl::EGL1_5>::load_required() }
+                .map_err(|e| e.to_string())?;
+            let display = unsafe {
+                egl.get_platform_display(
+                    PLATFORM_SURFACELESS_MESA,
+                    egl::DEFAULT_DISPLAY,
+                    &[egl::ATTRIB_NONE],
+                )
+            }
+            .map_err(|e| e.to_string())?;
+            egl.initialize(display).map_err(|e| e.to_string())?;
+            let config = egl
+                .choose_first_config(
+                    display,
+                    &[
+                        egl::RENDERABLE_TYPE,
+                        egl::OPENGL_ES3_BIT,
+                        egl::SURFACE_TYPE,
+                        0,
+                        egl::NONE,
+                    ],
+                )
+                .map_err(|e| e.to_string())?
+                .ok_or("no GLES 3 config")?;
+            egl.bind_api(egl::OPENGL_ES_API)
+                .map_err(|e| e.to_string())?;
+            let context = egl
+                .create_context(
+                    display,
+                    config,
+                    None,
+                    &[egl::CONTEXT_CLIENT_VERSION, 3, egl::NONE],
+                )
+                .map_err(|e| e.to_string())?;
+            egl.make_current(display, None, None, Some(context))
+                .map_err(|e| e.to_string())?;
+            let gl = glow::Context::from_loader_function(|name| {
+                egl.get_proc_address(name)
+                    .map_or(std::ptr::null(), |f| f as *const _)
+            });
+            Ok(Headless {
+                egl,
+                display,
+                context,
+                gl,
+            })
+        };
+        context().map_err(|e| eprintln!("skipping: {e}")).ok()
+    }
+
+    /// Draws `skeletons` into a transparent `width` x `height` framebuffer and reads it back with
+    /// straight alpha, top row first like `raster::Image`.
+    unsafe fn render(
+        gl: &glow::Context,
+        renderer: &mut GlowSkeletonRenderer<glow::Context>,
+        skeletons: &[&Skeleton],
+        camera: Camera,
+        width: u32,
+        height: u32,
+    ) -> Image {
+        let target = gl.create_texture().unwrap();
+        gl.bind_texture(glow::TEXTURE_2D, Some(target));
+        gl.tex_storage_2d(
+            glow::TEXTURE_2D,
+            1,
+            glow::RGBA8,
+            width as i32,
+            height as i32,
+        );
+        let framebuffer = gl.create_framebuffer().unwrap();
+        gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+        gl.framebuffer_texture_2d(
+            glow::FRAMEBUFFER,
+            glow::COLOR_ATTACHMENT0,
+            glow::TEXTURE_2D,
+            Some(target),
+            0,
+        );
+        gl.viewport(0, 0, width as i32, height as i32);
+        gl.clear_color(0.0, 0.0, 0.0, 0.0);
+        gl.clear(glow::COLOR_BUFFER_BIT);
+
+        let (half_width, half_height) = (
+            width as f32 / 2.0 / camera.zoom,
+            height as f32 / 2.0 / camera.zoom,
+        );
+        let (bottom, top) = (
+            camera.center[1] - half_height,
+            camera.center[1] + half_height,
+        );
+        let (bottom, top) = if is_y_down() {
+            (top, bottom)
+        } else {
+            (bottom, top)
+        };
+        renderer.draw(
+            gl,
+            skeletons,
+            &orthographic(
+                camera.center[0] - half_width,
+                camera.center[0] + half_width,
+                bottom,
+                top,
+            ),
+        );
+
+        let mut pixels = vec![0; width as usize * height as usize * 4];
+        gl.read_pixels(
+            0,
+            0,
+            width as i32,
+            height as i32,
+            glow::RGBA,
+            glow::UNSIGNED_BYTE,
+            &mut pixels,
+        );
+        gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+        gl.delete_framebuffer(framebuffer);
+        gl.delete_texture(target);
+
+        // The framebuffer holds premultiplied colors, as blending over transparent black leaves
+        // them whatever the blend mode, and its rows start at the bottom.
+        let pixels = pixels
+            .chunks_exact(width as usize * 4)
+            .rev()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        let pixels = pixels
+            .chunks_exact(4)
+            .flat_map(|pixel| {
+                let a = f32::from(pixel[3]) / 255.0;
+                let color = if a > 0.0 {
+                    let channel = |c: u8| f32::from(c) / 255.0 / a;
+                    Color::new(channel(pixel[0]), channel(pixel[1]), channel(pixel[2]), a)
+                } else {
+                    Color::TRANSPARENT
+                };
+                color.to_rgba8().to_be_bytes()
+            })
+            .collect();
+        Image::from_rgba8(width, height, pixels)
+    }
+
+    /// Poses the dragon `time` seconds into its "flying" animation.
+    fn dragon(atlas: Atlas, time: f32) -> (SkeletonData, Skeleton) {
+        let skeleton_data = unsafe {
+            SkeletonData::from_binary_file(Path::new(DRAGON).join("dragon-ess.skel"), atlas)
+        }
+        .unwrap();
+        let animation_data = AnimationStateData::new(&skeleton_data);
+        let mut animation = AnimationState::new(&animation_data);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+
+        animation.set_by_name("flying", TrackIndex::zero(), true);
+        animation.update(time);
+        animation.apply(&mut skeleton);
+        skeleton.update_world_transforms();
+
+        (skeleton_data, skeleton)
+    }
+
+    #[test]
+    fn orthographic_maps_corners() {
+        let m = orthographic(-100.0, 300.0, 50.0, 250.0);
+        let transform = |x: f32, y: f32| [m[0] * x + m[12], m[5] * y + m[13]];
+        for (expected, actual) in [
+            ([-1.0, -1.0], transform(-100.0, 50.0)),
+            ([1.0, 1.0], transform(300.0, 250.0)),
+            ([0.0, 0.0], transform(100.0, 150.0)),
+        ] {
+            assert!(
+                expected
+                    .iter()
+                    .zip(&actual)
+                    .all(|(e, a)| (e - a).abs() < 1e-6),
+                "{actual:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_rasterizer() {
+        // Drivers differ from the rasterizer in filtering precision and in coverage along edges,
+        // so compare the average difference of the premultiplied channels.
+        let premultiplied = |image: &Image| {
+            image
+                .pixels
+                .chunks_exact(4)
+                .flat_map(|p| {
+                    let a = f32::from(p[3]) / 255.0;
+                    [p[0], p[1], p[2]]
+                        .map(|c| f32::from(c) * a)
+                        .into_iter()
+                        .chain([f32::from(p[3])])
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let Some(context) = context() else {
+            return;
+        };
+        let gl = &context.gl;
+        for (atlas, premultiplied_alpha) in [("dragon.atlas", false), ("dragon-pma.atlas", true)] {
+            let atlas = Path::new(DRAGON).join(atlas);
+            unsafe {
+                let mut renderer = GlowSkeletonRenderer::new(gl, "#version 300 es").unwrap();
+                let loaded = renderer.load_atlas(gl, &atlas).unwrap();
+                assert!(loaded
+                    .pages()
+                    .iter()
+                    .all(|page| page.premultiplied_alpha() == premultiplied_alpha));
+                let (_data, skeleton) = dragon(loaded, 0.3);
+                let camera = Camera::fit_skeleton(&skeleton, 128, 128);
+                let image = render(gl, &mut renderer, &[&skeleton], camera, 128, 128);
+                renderer.destroy(gl);
+
+                let mut rasterizer = Rasterizer::new();
+                let (_data, skeleton) = dragon(rasterizer.load_atlas(&atlas).unwrap(), 0.3);
+                let expected = rasterizer.render(&skeleton, camera, 128, 128);
+
+                let (a, b) = (premultiplied(&image), premultiplied(&expected));
+                let difference =
+                    a.iter().zip(&b).map(|(a, b)| (a - b).abs()).sum::<f32>() / a.len() as f32;
+                assert!(difference < 1.0, "average difference {difference}");
+                assert!(image.pixels.chunks_exact(4).any(|p| p[3] == 255));
+            }
+        }
+        assert_eq!(glow::NO_ERROR, unsafe { gl.get_error() });
+    }
+
+    #[test]
+    fn draws_merge_and_buffers_grow() {
+        let Some(context) = context() else {
+            return;
+        };
+        let gl = &context.gl;
+        unsafe {
+            let mut renderer = GlowSkeletonRenderer::new(gl, "#version 300 es").unwrap();
+            let atlas = renderer
+                .load_atlas(gl, Path::new(DRAGON).join("dragon.atlas"))
+                .unwrap();
+            let skeleton_data = SkeletonData::from_json_str(
+                r#"{
+                    "skeleton": { "hash": "renderer", "spine": "3.8.55" },
+                    "bones": [ { "name": "root" } ],
+                    "slots": [ { "name": "image", "bone": "root", "attachment": "chin" } ],
+                    "skins": [ { "name": "default", "attachments": {
+                        "image": { "chin": { "width": 214, "height": 146 } }
+                    } } ]
+                }"#,
+                atlas,
+            )
+            .unwrap();
+
+            // Far more vertices than the example's fixed buffer of 2048 held.
+            let mut skeletons = (0..1000)
+                .map(|_| Skeleton::new(&skeleton_data))
+                .collect::<Vec<_>>();
+            for (i, skeleton) in skeletons.iter_mut().enumerate() {
+                skeleton.set_position([(i % 3) as f32 * 300.0, 0.0]);
+                skeleton.update_world_transforms();
+            }
+            let skeletons = skeletons.iter().collect::<Vec<_>>();
+            let camera = Camera::new([300.0, 0.0], 0.25);
+            let image = render(gl, &mut renderer, &skeletons, camera, 256, 64);
+
+            assert_eq!(
+                [Draw {
+                    texture_id: 1,
+                    blend_mode: BlendMode::Normal,
+                    indices: 0..6000,
+                }],
+                renderer.draws()
+            );
+            for x in [32, 128, 224] {
+                assert!((0..64).any(|y| image.pixel(x, y)[3] > 0), "column {x}");
+            }
+            assert_eq!(glow::NO_ERROR, gl.get_error());
+            renderer.destroy(gl);
+        }
+    }
+}
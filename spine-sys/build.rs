@@ -7,17 +7,12 @@ fn main() {
     let include = Path::new(SPINE_SRC_PATH).join("include");
 
     let mut builder = cc::Build::new();
-    builder
-        .include(include)
-        .static_flag(true)
-        .cargo_metadata(true);
+    builder.include(include).cargo_metadata(true);
 
-    for file in std::fs::read_dir(src).unwrap() {
-        if let Ok(entry) = file {
-            if let Some(ext) = entry.path().extension() {
-                if ext == "c" {
-                    builder.file(entry.path());
-                }
+    for entry in std::fs::read_dir(src).unwrap().flatten() {
+        if let Some(ext) = entry.path().extension() {
+            if ext == "c" {
+                builder.file(entry.path());
             }
         }
     }
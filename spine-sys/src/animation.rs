@@ -1,11 +1,13 @@
 #![allow(clippy::needless_pass_by_value)]
 
 use crate::{
+    enums::EventType,
     ffi,
-    skeleton::{Skeleton, SkeletonData},
+    skeleton::{BoneData, Skeleton, SkeletonData},
     SpineMutPtr,
 };
 use std::{
+    convert::TryInto,
     ffi::{CStr, CString},
     marker::PhantomData,
 };
@@ -24,6 +26,7 @@ impl Default for TrackIndex {
 
 pub struct Animation<'a> {
     pub(crate) inner: *mut ffi::spAnimation,
+    pub(crate) data: *const ffi::spSkeletonData,
     pub(crate) _lifetime: PhantomData<&'a ()>,
 }
 impl<'a> Animation<'a> {
@@ -36,49 +39,246 @@ impl<'a> Animation<'a> {
     pub fn duration(&self) -> f32 {
         unsafe { self.inner.as_ref().unwrap().duration }
     }
+
+    /// The bones keyed by this animation's rotate, translate, scale or shear timelines, in
+    /// skeleton order.
+    pub fn bones(&self) -> Vec<BoneData<'a>> {
+        let animation = unsafe { self.inner.as_ref().unwrap() };
+        let data = unsafe { self.data.as_ref().unwrap() };
+
+        let mut indices = (0..animation.timelinesCount.try_into().unwrap())
+            .filter_map(|n| unsafe {
+                let timeline = *animation.timelines.add(n);
+                if (*timeline).type_ <= ffi::spTimelineType_SP_TIMELINE_SHEAR {
+                    Some((*timeline.cast::<ffi::spBaseTimeline>()).boneIndex)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices
+            .into_iter()
+            .map(|index| BoneData {
+                inner: unsafe { &**data.bones.add(index.try_into().unwrap()) },
+            })
+            .collect()
+    }
     //    pub fn apply(&self, skeleton: &mut Skeleton, last_time: f32, time: f32, loop_: i32) {}
 }
 
+/// An event that animations can key, with the default values of its fields.
+pub struct EventData<'a> {
+    pub(crate) inner: &'a ffi::spEventData,
+}
+impl EventData<'_> {
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr(self.inner.name) }.to_str().unwrap()
+    }
+
+    pub fn int(&self) -> i32 {
+        self.inner.intValue
+    }
+
+    pub fn float(&self) -> f32 {
+        self.inner.floatValue
+    }
+
+    pub fn string(&self) -> Option<&str> {
+        if self.inner.stringValue.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(self.inner.stringValue) }
+                    .to_str()
+                    .unwrap(),
+            )
+        }
+    }
+
+    pub fn audio_path(&self) -> Option<&str> {
+        if self.inner.audioPath.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(self.inner.audioPath) }
+                    .to_str()
+                    .unwrap(),
+            )
+        }
+    }
+
+    pub fn volume(&self) -> f32 {
+        self.inner.volume
+    }
+
+    pub fn balance(&self) -> f32 {
+        self.inner.balance
+    }
+}
+impl std::fmt::Debug for EventData<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "EventData {{ name: {} }}", self.name())
+    }
+}
+
+/// A keyed event fired by an animation, with the values keyed for it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Event {
+    pub name: String,
+    /// The animation time the event was keyed at.
+    pub time: f32,
+    pub int: i32,
+    pub float: f32,
+    pub string: Option<String>,
+    pub volume: f32,
+    pub balance: f32,
+}
+
+/// Something that happened to a track entry while an `AnimationState` was updated or applied, as
+/// recorded by `AnimationState::set_record_events`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationEvent {
+    pub kind: EventType,
+    pub track: i32,
+    /// The name of the entry's animation, `<empty>` for empty animations.
+    pub animation: String,
+    /// The keyed event, for `EventType::Event` only.
+    pub event: Option<Event>,
+}
+
+/// An animation playing or queued on a track of an `AnimationState`. Borrowed from the state, as
+/// spine-c frees entries once they are done.
+pub struct TrackEntry<'a> {
+    inner: *mut ffi::spTrackEntry,
+    _lifetime: PhantomData<&'a ()>,
+}
+impl TrackEntry<'_> {
+    fn as_ref(&self) -> &ffi::spTrackEntry {
+        unsafe { self.inner.as_ref().unwrap() }
+    }
+
+    pub fn animation_name(&self) -> &str {
+        unsafe { CStr::from_ptr((*self.as_ref().animation).name) }
+            .to_str()
+            .unwrap()
+    }
+
+    pub fn looping(&self) -> bool {
+        self.as_ref().loop_ != 0
+    }
+
+    /// Seconds after the previous entry started that this one starts. spine-c resolves a queued
+    /// delay of 0 or less against the previous animation's duration and the mix duration.
+    pub fn delay(&self) -> f32 {
+        self.as_ref().delay
+    }
+
+    pub fn mix_duration(&self) -> f32 {
+        self.as_ref().mixDuration
+    }
+}
+
+/// Applies queued animations to a `Skeleton` over time.
+///
+/// An animation state keeps its `AnimationStateData` alive, and is `Send` but not `Sync`.
 pub struct AnimationState {
     pub(crate) inner: SpineMutPtr<ffi::spAnimationState>,
     pub(crate) parent: SpineMutPtr<ffi::spAnimationStateData>,
+    /// Keeps the animations of the track entries alive, which spine-c still reads when disposing
+    /// the state.
+    pub(crate) skeleton_data: SpineMutPtr<ffi::spSkeletonData>,
+    /// Where the listener records events while recording, boxed so its address survives moves of
+    /// the state. Declared after `inner` so it outlives the spine-c state.
+    #[allow(clippy::box_collection)]
+    pub(crate) events: Box<Vec<AnimationEvent>>,
 }
+
+// SAFETY: an animation state exclusively owns its tracks and only reads its shared
+// `AnimationStateData`, which is `Sync`.
+unsafe impl Send for AnimationState {}
+
 impl AnimationState {
     pub fn new(data: &AnimationStateData) -> Self {
+        // The first animation state lazily creates a global empty animation in spine-c.
+        let _guard = crate::lock_global_state();
         Self {
             inner: SpineMutPtr::new(
                 unsafe { ffi::spAnimationState_create(data.inner.as_mut_ptr()) },
                 Some(ffi::spAnimationState_dispose),
             ),
             parent: data.inner.clone(),
+            skeleton_data: data.parent.clone(),
+            events: Box::default(),
+        }
+    }
+
+    /// Whether events fired by the tracks are recorded for `take_events`. Recording is off by
+    /// default; turning it off discards events that were not taken yet.
+    ///
+    /// # spine-c
+    /// Maps to setting `spAnimationState->listener`
+    pub fn set_record_events(&mut self, record: bool) {
+        let state = self.inner.as_mut();
+        if record {
+            state.rendererObject = std::ptr::from_mut(&mut *self.events).cast();
+            state.listener = Some(record_event);
+        } else {
+            state.rendererObject = std::ptr::null_mut();
+            state.listener = None;
+            self.events.clear();
         }
     }
 
+    pub fn record_events(&self) -> bool {
+        self.inner.as_ref().listener.is_some()
+    }
+
+    /// Returns the events recorded since the last call, oldest first.
+    pub fn take_events(&mut self) -> Vec<AnimationEvent> {
+        std::mem::take(&mut *self.events)
+    }
+
     pub fn update(&mut self, delta: f32) {
         unsafe {
             ffi::spAnimationState_update(self.inner.as_mut_ptr(), delta);
         }
     }
 
-    pub fn clear_track(&self, track: TrackIndex) {
+    pub fn clear_track(&mut self, track: TrackIndex) {
         unsafe {
             ffi::spAnimationState_clearTrack(self.inner.as_mut_ptr(), track.0);
         }
     }
 
-    pub fn clear(&self) {
+    pub fn clear(&mut self) {
         unsafe {
             ffi::spAnimationState_clearTracks(self.inner.as_mut_ptr());
         }
     }
 
+    /// Sets the current animation of a track.
+    ///
+    /// # Panics
+    /// If `animation` does not belong to the `SkeletonData` of this animation state.
     pub fn set(&mut self, animation: &Animation, track_index: TrackIndex, do_loop: bool) {
+        let skeleton_data = unsafe { &*self.parent.as_ref().skeletonData };
+        assert!(
+            (0..skeleton_data.animationsCount.try_into().unwrap())
+                .any(|n| unsafe { *skeleton_data.animations.add(n) } == animation.inner),
+            "animation does not belong to this animation state's skeleton data"
+        );
+
         unsafe {
             let _track_entry = ffi::spAnimationState_setAnimation(
                 self.inner.as_mut_ptr(),
                 track_index.0,
                 animation.inner,
-                do_loop as std::os::raw::c_int,
+                std::os::raw::c_int::from(do_loop),
             );
         }
     }
@@ -90,25 +290,112 @@ impl AnimationState {
                 self.inner.as_mut_ptr(),
                 track_index.0,
                 name.as_ptr(),
-                do_loop as std::os::raw::c_int,
+                std::os::raw::c_int::from(do_loop),
             );
             if track_entry.is_null() {
-                panic!("Failed to set animation?");
+                spine_log!(error, "no animation named {:?}", animation_name);
             }
+            assert!(!track_entry.is_null(), "Failed to set animation?");
+        }
+    }
+
+    /// Queues an animation to play on a track after the current and any previously queued
+    /// animations. A `delay` of 0 or less starts it when the previous animation completes, minus the
+    /// mix duration. Returns `None` if there is no animation named `animation_name`.
+    ///
+    /// # spine-c
+    /// Maps to calling `spAnimationState_addAnimation` with the animation found by name, as
+    /// `spAnimationState_addAnimationByName` queues a null animation for unknown names.
+    pub fn add_by_name(
+        &mut self,
+        animation_name: &str,
+        track_index: TrackIndex,
+        do_loop: bool,
+        delay: f32,
+    ) -> Option<TrackEntry<'_>> {
+        let name = CString::new(animation_name).unwrap();
+        let animation = unsafe {
+            ffi::spSkeletonData_findAnimation(self.skeleton_data.as_mut_ptr(), name.as_ptr())
+        };
+        if animation.is_null() {
+            spine_log!(debug, "no animation named {:?}", animation_name);
+            return None;
         }
+
+        let inner = unsafe {
+            ffi::spAnimationState_addAnimation(
+                self.inner.as_mut_ptr(),
+                track_index.0,
+                animation,
+                std::os::raw::c_int::from(do_loop),
+                delay,
+            )
+        };
+        Some(TrackEntry {
+            inner,
+            _lifetime: PhantomData,
+        })
     }
 
-    pub fn apply(&self, skeleton: &mut Skeleton) -> bool {
+    /// Poses `skeleton` with the current state of every track. Returns true if any animation was
+    /// applied.
+    ///
+    /// # Panics
+    /// If `skeleton` was not created from the `SkeletonData` of this animation state.
+    pub fn apply(&mut self, skeleton: &mut Skeleton) -> bool {
+        assert!(
+            self.parent.as_ref().skeletonData == skeleton.data.as_mut_ptr(),
+            "skeleton was created from another skeleton data"
+        );
+
         unsafe {
             ffi::spAnimationState_apply(self.inner.as_mut_ptr(), skeleton.inner.as_mut_ptr()) != 0
         }
     }
 }
 
+/// The `spAnimationState` listener installed by `AnimationState::set_record_events`.
+unsafe extern "C" fn record_event(
+    state: *mut ffi::spAnimationState,
+    kind: ffi::spEventType,
+    entry: *mut ffi::spTrackEntry,
+    event: *mut ffi::spEvent,
+) {
+    let string = |s: *const std::os::raw::c_char| CStr::from_ptr(s).to_string_lossy().into_owned();
+
+    let events = &mut *(*state).rendererObject.cast::<Vec<AnimationEvent>>();
+    let event = event.as_ref().map(|event| Event {
+        name: string((*event.data).name),
+        time: event.time,
+        int: event.intValue,
+        float: event.floatValue,
+        string: (!event.stringValue.is_null()).then(|| string(event.stringValue)),
+        volume: event.volume,
+        balance: event.balance,
+    });
+    events.push(AnimationEvent {
+        kind: kind.into(),
+        track: (*entry).trackIndex,
+        animation: string((*(*entry).animation).name),
+        event,
+    });
+}
+
+/// Mix durations between animations of a `SkeletonData`, shared by every `AnimationState`
+/// created from it.
+///
+/// Like `SkeletonData`, this is `Send + Sync` and can be shared across threads. Animation states
+/// read the mix durations while animating on any thread, so they can only be changed while no
+/// `AnimationState` of this data exists.
 pub struct AnimationStateData {
     pub(crate) inner: SpineMutPtr<ffi::spAnimationStateData>,
     pub(crate) parent: SpineMutPtr<ffi::spSkeletonData>,
 }
+
+// SAFETY: mix durations are only changed through `&mut self` while no animation state shares
+// them, see `AnimationStateData::as_mut`, and spine-c only reads them while animating.
+unsafe impl Send for AnimationStateData {}
+unsafe impl Sync for AnimationStateData {}
 impl AnimationStateData {
     pub fn new(data: &SkeletonData) -> Self {
         Self {
@@ -120,27 +407,340 @@ impl AnimationStateData {
         }
     }
 
-    fn get_mix(&mut self, from: &Animation, to: &Animation) -> f32 {
+    /// The mix duration used between animations without a mix of their own.
+    pub fn default_mix(&self) -> f32 {
+        self.inner.as_ref().defaultMix
+    }
+
+    /// The spine-c data, for changing it while no animation state can read it.
+    ///
+    /// # Panics
+    /// If an `AnimationState` of this data exists.
+    fn as_mut(&mut self) -> &mut ffi::spAnimationStateData {
+        self.inner
+            .get_mut()
+            .expect("mix durations cannot change while animation states use them")
+    }
+
+    /// # Panics
+    /// If an `AnimationState` of this data exists.
+    pub fn set_default_mix(&mut self, mix: f32) {
+        self.as_mut().defaultMix = mix;
+    }
+
+    pub fn get_mix(&self, from: &Animation, to: &Animation) -> f32 {
         unsafe { ffi::spAnimationStateData_getMix(self.inner.as_mut_ptr(), from.inner, to.inner) }
     }
 
-    fn set_mix(&mut self, from: &Animation, to: &Animation, mix: f32) {
+    /// # Panics
+    /// If an `AnimationState` of this data exists.
+    pub fn set_mix(&mut self, from: &Animation, to: &Animation, mix: f32) {
         unsafe {
-            ffi::spAnimationStateData_setMix(self.inner.as_mut_ptr(), from.inner, to.inner, mix)
+            ffi::spAnimationStateData_setMix(self.as_mut(), from.inner, to.inner, mix);
         }
     }
 
-    fn set_mix_by_name(&mut self, from: &str, to: &str, mix: f32) {
+    /// Sets the mix duration from the animation named `from` to the one named `to`.
+    ///
+    /// # Panics
+    /// If an `AnimationState` of this data exists.
+    ///
+    /// # spine-c
+    /// Maps to calling `spAnimationStateData_setMixByName`
+    pub fn set_mix_by_name(&mut self, from: &str, to: &str, mix: f32) {
+        let data = std::ptr::from_mut(self.as_mut());
+        for name in [from, to] {
+            let c_name = CString::new(name).unwrap();
+            let animation = unsafe {
+                ffi::spSkeletonData_findAnimation(self.parent.as_mut_ptr(), c_name.as_ptr())
+            };
+            if animation.is_null() {
+                spine_log!(warn, "no animation named {:?}", name);
+            }
+        }
+
         let from = CString::new(from).unwrap();
         let to = CString::new(to).unwrap();
 
         unsafe {
-            ffi::spAnimationStateData_setMixByName(
-                self.inner.as_mut_ptr(),
-                from.as_ptr(),
-                to.as_ptr(),
-                mix,
-            )
+            ffi::spAnimationStateData_setMixByName(data, from.as_ptr(), to.as_ptr(), mix);
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::{
+        atlas::Atlas,
+        skeleton::Slot,
+        tests::{skeleton_data_from_json, TEST_CASES},
+    };
+    use std::sync::Arc;
+
+    const THREADS: usize = 8;
+    const DRAGONS_PER_THREAD: usize = 32;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>() {}
+
+    /// Animates `count` dragons with staggered start times and returns the world vertices of each.
+    fn animate(
+        skeleton_data: &SkeletonData,
+        state_data: &AnimationStateData,
+        first: usize,
+        count: usize,
+    ) -> Vec<Vec<f32>> {
+        (first..first + count)
+            .map(|n| {
+                let mut skeleton = Skeleton::new(skeleton_data);
+                let mut state = AnimationState::new(state_data);
+                state.set_by_name("flying", TrackIndex::zero(), true);
+
+                #[allow(clippy::cast_precision_loss)]
+                let delta = 1.0 / 60.0 + n as f32 * 0.0001;
+                for _ in 0..30 {
+                    skeleton.update(delta);
+                    state.update(delta);
+                    state.apply(&mut skeleton);
+                    skeleton.update_world_transforms();
+                }
+
+                skeleton
+                    .draw_slots()
+                    .iter()
+                    .flat_map(Slot::world_vertices)
+                    .flat_map(|vertex| vertex.position)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn thread_safety_markers() {
+        assert_send_sync::<Atlas>();
+        assert_send_sync::<SkeletonData>();
+        assert_send_sync::<AnimationStateData>();
+        assert_send::<Skeleton>();
+        assert_send::<AnimationState>();
+    }
+
+    #[test]
+    fn shared_data_across_threads() {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        let skeleton_data =
+            Arc::new(unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas) }.unwrap());
+        let state_data = Arc::new(AnimationStateData::new(&skeleton_data));
+
+        let threaded = (0..THREADS)
+            .map(|thread| {
+                let skeleton_data = Arc::clone(&skeleton_data);
+                let state_data = Arc::clone(&state_data);
+                std::thread::spawn(move || {
+                    animate(
+                        &skeleton_data,
+                        &state_data,
+                        thread * DRAGONS_PER_THREAD,
+                        DRAGONS_PER_THREAD,
+                    )
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+
+        let expected = animate(&skeleton_data, &state_data, 0, THREADS * DRAGONS_PER_THREAD);
+        assert_eq!(THREADS * DRAGONS_PER_THREAD, threaded.len());
+        assert!(threaded.iter().all(|vertices| !vertices.is_empty()));
+        assert!(expected == threaded);
+    }
+
+    #[test]
+    fn skeleton_outlives_data() {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        let skeleton_data =
+            unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas) }.unwrap();
+        let state_data = AnimationStateData::new(&skeleton_data);
+
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        let mut state = AnimationState::new(&state_data);
+        drop(state_data);
+        drop(skeleton_data);
+
+        let handle = std::thread::spawn(move || {
+            state.set_by_name("flying", TrackIndex::zero(), true);
+            state.update(0.5);
+            state.apply(&mut skeleton);
+            skeleton.update_world_transforms();
+            skeleton.slots().len()
+        });
+        assert!(handle.join().unwrap() > 0);
+    }
+
+    #[test]
+    fn queued_animations() {
+        let skeleton_data = skeleton_data_from_json(
+            r#"{
+                "skeleton": { "hash": "queue", "spine": "3.8.55" },
+                "bones": [ { "name": "root" }, { "name": "mover", "parent": "root" } ],
+                "animations": {
+                    "left": { "bones": { "mover": { "translate": [ { "x": -100 }, { "time": 1, "x": -100 } ] } } },
+                    "right": { "bones": { "mover": { "translate": [ { "x": 100 }, { "time": 1, "x": 100 } ] } } }
+                }
+            }"#,
+        );
+        let mut state_data = AnimationStateData::new(&skeleton_data);
+        state_data.set_default_mix(0.5);
+        assert_eq!(0.5, state_data.default_mix());
+        state_data.set_mix_by_name("right", "left", 0.0);
+
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        let mut state = AnimationState::new(&state_data);
+        state.set_by_name("left", TrackIndex::zero(), false);
+        let right = state
+            .add_by_name("right", TrackIndex::zero(), false, 0.0)
+            .unwrap();
+        assert_eq!("right", right.animation_name());
+        assert!(!right.looping());
+        // Queued to start half a second before "left" ends, to mix over the default mix duration.
+        assert_eq!(0.5, right.delay());
+        assert_eq!(0.5, right.mix_duration());
+        let left = state
+            .add_by_name("left", TrackIndex::zero(), false, 0.0)
+            .unwrap();
+        assert_eq!(0.0, left.mix_duration());
+        assert!(state
+            .add_by_name("missing", TrackIndex::zero(), false, 0.0)
+            .is_none());
+
+        // spine-c moves to a queued entry on the update after its start time, so step in frames.
+        let positions = (0..25)
+            .map(|_| {
+                state.update(0.1);
+                state.apply(&mut skeleton);
+                skeleton.find_bone("mover").unwrap().position()[0]
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(-100.0, positions[4]);
+        // "right" starts half a second early to mix in over the default mix duration.
+        assert!((positions[7] - 20.0).abs() < 0.01);
+        assert_eq!(100.0, positions[9]);
+        // The mix from "right" back to "left" is instant.
+        assert_eq!(100.0, positions[14]);
+        assert_eq!(-100.0, positions[15]);
+    }
+
+    #[test]
+    fn mixes_only_change_without_states() {
+        let skeleton_data = skeleton_data_from_json(
+            r#"{ "skeleton": { "hash": "mixes", "spine": "3.8.55" }, "bones": [ { "name": "root" } ] }"#,
+        );
+        let mut state_data = AnimationStateData::new(&skeleton_data);
+        let state = AnimationState::new(&state_data);
+        let changed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            state_data.set_default_mix(0.25);
+        }));
+        assert!(changed.is_err());
+        assert_eq!(0.0, state_data.default_mix());
+
+        drop(state);
+        state_data.set_default_mix(0.25);
+        assert_eq!(0.25, state_data.default_mix());
+    }
+
+    #[test]
+    fn state_outlives_data() {
+        let skeleton_data = skeleton_data_from_json(
+            r#"{
+                "skeleton": { "hash": "outlive", "spine": "3.8.55" },
+                "bones": [ { "name": "root" } ],
+                "animations": {
+                    "walk": { "bones": { "root": { "rotate": [ { "angle": 0 }, { "time": 1, "angle": 90 } ] } } }
+                }
+            }"#,
+        );
+        let state_data = AnimationStateData::new(&skeleton_data);
+        let mut state = AnimationState::new(&state_data);
+        state.set_record_events(true);
+        state.set_by_name("walk", TrackIndex::zero(), false);
+        drop(state_data);
+        drop(skeleton_data);
+
+        state.update(0.5);
+        state.clear();
+        let kinds = state.take_events().into_iter().map(|event| event.kind);
+        assert!(kinds.eq([EventType::Start, EventType::End, EventType::Dispose]));
+        state.set_by_name("walk", TrackIndex::zero(), false);
+    }
+
+    #[test]
+    fn recorded_events() {
+        let skeleton_data = skeleton_data_from_json(
+            r#"{
+                "skeleton": { "hash": "events", "spine": "3.8.55" },
+                "bones": [ { "name": "root" } ],
+                "events": { "step": { "int": 1, "string": "left" } },
+                "animations": {
+                    "walk": {
+                        "bones": { "root": { "rotate": [ { "angle": 0 }, { "time": 1, "angle": 90 } ] } },
+                        "events": [ { "time": 0.5, "name": "step", "float": 2.5 } ]
+                    }
+                }
+            }"#,
+        );
+        let state_data = AnimationStateData::new(&skeleton_data);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        let mut state = AnimationState::new(&state_data);
+
+        state.set_by_name("walk", TrackIndex::zero(), false);
+        state.update(0.1);
+        state.apply(&mut skeleton);
+        assert!(!state.record_events());
+        assert!(state.take_events().is_empty());
+
+        state.set_record_events(true);
+        for _ in 0..11 {
+            state.update(0.1);
+            state.apply(&mut skeleton);
+        }
+        state.clear();
+
+        let events = state.take_events();
+        let kinds = events.iter().map(|event| event.kind).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                EventType::Event,
+                EventType::Complete,
+                EventType::End,
+                EventType::Dispose,
+            ],
+            kinds
+        );
+        assert!(events
+            .iter()
+            .all(|event| event.animation == "walk" && event.track == 0));
+        assert_eq!(
+            Some(Event {
+                name: "step".to_owned(),
+                time: 0.5,
+                int: 1,
+                float: 2.5,
+                string: Some("left".to_owned()),
+                volume: 0.0,
+                balance: 0.0,
+            }),
+            events[0].event
+        );
+        assert!(state.take_events().is_empty());
+
+        state.set_record_events(false);
+        state.set_by_name("walk", TrackIndex::zero(), false);
+        state.update(1.0);
+        state.apply(&mut skeleton);
+        assert!(state.take_events().is_empty());
+    }
+}
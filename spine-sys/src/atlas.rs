@@ -1,60 +1,244 @@
 use crate::{
     enums::{AtlasFilter, AtlasFormat, AtlasWrap},
     ffi,
+    format::atlas::AtlasData,
     spine_ptr::SpineMutPtr,
     SpineError,
 };
 use std::{
+    convert::TryFrom,
     ffi::{CStr, CString},
+    marker::PhantomData,
     path::Path,
 };
 
 pub struct Atlas {
     pub(crate) inner: SpineMutPtr<ffi::spAtlas>,
+    /// Whether each page stores premultiplied alpha, in file order.
+    pub(crate) premultiplied_alpha: Vec<bool>,
 }
 
+// SAFETY: spine-c never modifies an atlas after loading it.
+unsafe impl Send for Atlas {}
+unsafe impl Sync for Atlas {}
+
 impl Atlas {
     /// Loads a `Atlas` instance from the provided file path.
     ///
+    /// A page has premultiplied alpha if it has a `pma: true` line, which spine-c 3.8 cannot parse
+    /// itself, or if the file name ends in `-pma` as in the Spine examples, see `is_pma_path`.
+    ///
     /// # Errors
     /// Returns a `SpineError::FailLoadAtlas` instance, with a text message detailing why loading failed.
-    #[allow(clippy::mut_mut)]
     pub fn from_file<P, F>(path: P, mut create_texture: F) -> Result<Self, SpineError>
     where
         P: AsRef<Path>,
-        F: FnMut(&AtlasPage, &Path) -> u32,
+        F: FnMut(&AtlasPage<'_>, &Path) -> u32,
     {
         let path_str_c = CString::new(path.as_ref().to_str().ok_or_else(|| {
             SpineError::FailLoadAtlas("Failed to convert path to string".to_owned())
         })?)
         .map_err(|e| {
-            SpineError::FailLoadAtlas(format!("Failed to convert path to string: {:?}", e))
+            SpineError::FailLoadAtlas(format!("Failed to convert path to string: {e:?}"))
+        })?;
+
+        let start = std::time::Instant::now();
+        let pma_path = is_pma_path(path.as_ref());
+        // Files the Rust parser rejects are left for spine-c to load or report.
+        let pma_data = AtlasData::from_file(&path)
+            .ok()
+            .filter(|data| data.pages.iter().any(|page| page.pma));
+        let atlas = if let Some(data) = pma_data {
+            let dir = path.as_ref().parent().unwrap_or_else(|| Path::new(""));
+            Self::load_data(&data, dir, pma_path, &mut create_texture)?
+        } else {
+            Self::create(
+                &mut create_texture,
+                &|_| pma_path,
+                |renderer_object| unsafe {
+                    ffi::spAtlas_createFromFile(path_str_c.as_ptr(), renderer_object)
+                },
+            )
+            .ok_or_else(|| SpineError::FailLoadAtlas("spAtlas_createFromFile failed".to_owned()))?
+        };
+        spine_log!(
+            info,
+            "loaded atlas {} in {:?}: {} pages, {} regions",
+            path.as_ref().display(),
+            start.elapsed(),
+            atlas.pages_count(),
+            atlas.regions_count()
+        );
+        Ok(atlas)
+    }
+
+    /// Loads a `Atlas` instance from parsed atlas data, resolving page images relative to `dir`.
+    ///
+    /// A page has premultiplied alpha if its `PageData::pma` is set.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadAtlas` instance, with a text message detailing why loading failed.
+    pub fn from_data<P, F>(
+        data: &AtlasData,
+        dir: P,
+        mut create_texture: F,
+    ) -> Result<Self, SpineError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&AtlasPage<'_>, &Path) -> u32,
+    {
+        Self::load_data(data, dir.as_ref(), false, &mut create_texture)
+    }
+
+    /// Loads `data` like `from_data`, with every page premultiplied if `premultiplied_alpha` is set.
+    fn load_data(
+        data: &AtlasData,
+        dir: &Path,
+        premultiplied_alpha: bool,
+        create_texture: &mut dyn FnMut(&AtlasPage<'_>, &Path) -> u32,
+    ) -> Result<Self, SpineError> {
+        // spine-c 3.8 reads page keys by position, so it cannot skip a `pma` line.
+        let mut text = String::new();
+        data.write(&mut text, false)
+            .map_err(|e| SpineError::FailLoadAtlas(format!("Failed to write atlas: {e}")))?;
+        let length = std::os::raw::c_int::try_from(text.len())
+            .map_err(|_| SpineError::FailLoadAtlas("Atlas is too large".to_owned()))?;
+        let dir_c = CString::new(dir.to_str().ok_or_else(|| {
+            SpineError::FailLoadAtlas("Failed to convert path to string".to_owned())
+        })?)
+        .map_err(|e| {
+            SpineError::FailLoadAtlas(format!("Failed to convert path to string: {e:?}"))
         })?;
 
-        // TODO:
-        let mut closure_ref: &mut dyn FnMut(&AtlasPage, &Path) -> u32 = &mut create_texture;
-        let trait_obj_ref: &mut &mut dyn FnMut(&AtlasPage, &Path) -> u32 = &mut closure_ref;
+        let pma = |page: usize| premultiplied_alpha || data.pages.get(page).is_some_and(|p| p.pma);
+        Self::create(create_texture, &pma, |renderer_object| unsafe {
+            ffi::spAtlas_create(
+                text.as_ptr().cast(),
+                length,
+                dir_c.as_ptr(),
+                renderer_object,
+            )
+        })
+        .ok_or_else(|| SpineError::FailLoadAtlas("spAtlas_create failed".to_owned()))
+    }
 
-        let closure_pointer_pointer = trait_obj_ref as *mut _ as *mut std::os::raw::c_void;
+    /// Overrides whether the images of every page store premultiplied alpha, for atlases exported
+    /// without a `pma` line or `-pma` file name.
+    pub fn set_premultiplied_alpha(&mut self, premultiplied_alpha: bool) {
+        self.premultiplied_alpha.fill(premultiplied_alpha);
+    }
 
-        let inner =
-            unsafe { ffi::spAtlas_createFromFile(path_str_c.as_ptr(), closure_pointer_pointer) };
+    /// Calls `create` with a renderer object that `_spAtlasPage_createTexture` turns back into
+    /// `create_texture`, telling it whether the page at each index has `premultiplied_alpha`.
+    fn create(
+        create_texture: &mut dyn FnMut(&AtlasPage<'_>, &Path) -> u32,
+        premultiplied_alpha: &dyn Fn(usize) -> bool,
+        create: impl FnOnce(*mut std::os::raw::c_void) -> *mut ffi::spAtlas,
+    ) -> Option<Self> {
+        let mut loader = TextureLoader {
+            create_texture,
+            premultiplied_alpha,
+            page: 0,
+        };
+        let inner = create(std::ptr::from_mut(&mut loader).cast());
         if inner.is_null() {
-            Err(SpineError::FailLoadAtlas(
-                "spAtlas_createFromFile failed".to_owned(),
-            ))
+            None
         } else {
-            Ok(Self {
+            let mut atlas = Self {
                 inner: SpineMutPtr::new(inner, Some(ffi::spAtlas_dispose)),
-            })
+                premultiplied_alpha: Vec::new(),
+            };
+            atlas.premultiplied_alpha = (0..atlas.page_ptrs().len())
+                .map(premultiplied_alpha)
+                .collect();
+            Some(atlas)
+        }
+    }
+
+    /// The texture pages of this atlas, in file order.
+    pub fn pages(&self) -> Vec<AtlasPage<'_>> {
+        self.page_ptrs()
+            .into_iter()
+            .zip(&self.premultiplied_alpha)
+            .map(|(page, &premultiplied_alpha)| AtlasPage::new(page, premultiplied_alpha))
+            .collect()
+    }
+
+    fn page_ptrs(&self) -> Vec<*mut ffi::spAtlasPage> {
+        let mut pages = Vec::new();
+        let mut page = self.inner.as_ref().pages;
+        while !page.is_null() {
+            pages.push(page);
+            page = unsafe { (*page).next };
         }
+        pages
     }
+
+    /// The regions packed into this atlas, in file order.
+    pub fn regions(&self) -> Vec<AtlasRegion<'_>> {
+        let mut regions = Vec::new();
+        let mut region = self.inner.as_ref().regions;
+        while !region.is_null() {
+            regions.push(AtlasRegion {
+                inner: region,
+                atlas: self,
+            });
+            region = unsafe { (*region).next };
+        }
+        regions
+    }
+
+    /// Finds a region by name, such as an image packed into the atlas to draw on its own.
+    pub fn find_region(&self, name: &str) -> Option<AtlasRegion<'_>> {
+        let c_name = CString::new(name).unwrap();
+        let inner = unsafe { ffi::spAtlas_findRegion(self.inner.as_ptr(), c_name.as_ptr()) };
+
+        if inner.is_null() {
+            spine_log!(debug, "no atlas region named {:?}", name);
+            None
+        } else {
+            Some(AtlasRegion { inner, atlas: self })
+        }
+    }
+
+    fn pages_count(&self) -> usize {
+        self.premultiplied_alpha.len()
+    }
+
+    fn regions_count(&self) -> usize {
+        self.regions().len()
+    }
+}
+
+/// The renderer object of an atlas while spine-c loads it, which `_spAtlasPage_createTexture`
+/// turns back into the texture callback and the premultiplied alpha of the next page.
+pub(crate) struct TextureLoader<'a> {
+    pub(crate) create_texture: &'a mut dyn FnMut(&AtlasPage<'_>, &Path) -> u32,
+    pub(crate) premultiplied_alpha: &'a dyn Fn(usize) -> bool,
+    pub(crate) page: usize,
+}
+
+/// Whether `path` names an atlas with premultiplied alpha by the `-pma` file name suffix of the
+/// Spine examples, such as `dragon-pma.atlas`.
+pub fn is_pma_path(path: &Path) -> bool {
+    path.file_stem()
+        .is_some_and(|stem| stem.to_string_lossy().ends_with("-pma"))
 }
 
-pub struct AtlasPage {
+pub struct AtlasPage<'a> {
     pub(crate) inner: SpineMutPtr<ffi::spAtlasPage>,
+    pub(crate) premultiplied_alpha: bool,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
 }
-impl AtlasPage {
+impl AtlasPage<'_> {
+    pub(crate) fn new(inner: *mut ffi::spAtlasPage, premultiplied_alpha: bool) -> Self {
+        Self {
+            inner: SpineMutPtr::new(inner, None),
+            premultiplied_alpha,
+            _lifetime: PhantomData,
+        }
+    }
+
     pub fn name(&self) -> &str {
         unsafe { CStr::from_ptr(self.inner.as_ref().name) }
             .to_str()
@@ -62,7 +246,7 @@ impl AtlasPage {
             .unwrap()
     }
 
-    pub fn set_texture_id(&mut self, id: u32) {
+    pub(crate) fn set_texture_id(&mut self, id: u32) {
         self.inner.as_mut().rendererObject = id as *mut std::os::raw::c_void;
     }
 
@@ -92,6 +276,104 @@ impl AtlasPage {
         let r = self.inner.as_ref();
         (r.width, r.height)
     }
+
+    /// Whether the page image stores premultiplied alpha, so renderers must blend it with
+    /// `BlendMode::blend_func(true)`.
+    pub fn premultiplied_alpha(&self) -> bool {
+        self.premultiplied_alpha
+    }
+}
+
+/// An image packed into a page of an `Atlas`.
+pub struct AtlasRegion<'a> {
+    pub(crate) inner: *mut ffi::spAtlasRegion,
+    pub(crate) atlas: &'a Atlas,
+}
+impl<'a> AtlasRegion<'a> {
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr(self.as_ref().name) }
+            .to_str()
+            .unwrap()
+    }
+
+    /// The page this region is packed into.
+    pub fn page(&self) -> AtlasPage<'a> {
+        let page = self.as_ref().page;
+        let index = self.atlas.page_ptrs().iter().position(|&p| p == page);
+        AtlasPage::new(
+            page,
+            index.is_some_and(|i| self.atlas.premultiplied_alpha[i]),
+        )
+    }
+
+    /// The index of this region in a numbered sequence of images, or -1.
+    pub fn index(&self) -> i32 {
+        self.as_ref().index
+    }
+
+    /// The top left corner of this region on its page, in pixels.
+    pub fn position(&self) -> (i32, i32) {
+        let r = self.as_ref();
+        (r.x, r.y)
+    }
+
+    /// The size of this region on its page in pixels, after whitespace stripping.
+    pub fn dimensions(&self) -> (i32, i32) {
+        let r = self.as_ref();
+        (r.width, r.height)
+    }
+
+    /// The texture coordinates of the corners of this region, as `[u, v, u2, v2]`.
+    ///
+    /// For a rotated region, `(u, v)` is still the top left corner on the page.
+    pub fn uvs(&self) -> [f32; 4] {
+        let r = self.as_ref();
+        [r.u, r.v, r.u2, r.v2]
+    }
+
+    /// Whether this region was packed rotated by 90 degrees.
+    pub fn rotate(&self) -> bool {
+        self.as_ref().rotate != 0
+    }
+
+    /// How far this region was rotated counter clockwise when packed.
+    pub fn degrees(&self) -> i32 {
+        self.as_ref().degrees
+    }
+
+    /// The position of this region in its original image, from the bottom left, in pixels.
+    pub fn offset(&self) -> (i32, i32) {
+        let r = self.as_ref();
+        (r.offsetX, r.offsetY)
+    }
+
+    /// The size of the image before whitespace was stripped, in pixels.
+    pub fn original_dimensions(&self) -> (i32, i32) {
+        let r = self.as_ref();
+        (r.originalWidth, r.originalHeight)
+    }
+
+    /// The nine patch split lines: left, right, top and bottom.
+    pub fn splits(&self) -> Option<[i32; 4]> {
+        Self::quad(self.as_ref().splits)
+    }
+
+    /// The nine patch content padding: left, right, top and bottom.
+    pub fn pads(&self) -> Option<[i32; 4]> {
+        Self::quad(self.as_ref().pads)
+    }
+
+    fn quad(values: *const std::os::raw::c_int) -> Option<[i32; 4]> {
+        if values.is_null() {
+            None
+        } else {
+            Some(unsafe { *values.cast::<[i32; 4]>() })
+        }
+    }
+
+    pub(crate) fn as_ref(&self) -> &ffi::spAtlasRegion {
+        unsafe { self.inner.as_ref().unwrap() }
+    }
 }
 
 #[cfg(test)]
@@ -113,4 +395,96 @@ mod tests {
 
         assert_eq!(2, load);
     }
+
+    #[test]
+    fn pages_and_regions() {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+
+        let pages = atlas.pages();
+        assert_eq!(
+            vec!["dragon.png", "dragon2.png"],
+            pages.iter().map(AtlasPage::name).collect::<Vec<_>>()
+        );
+
+        let regions = atlas.regions();
+        assert_eq!(atlas.regions_count(), regions.len());
+        let chin = regions
+            .iter()
+            .find(|region| region.name() == "chin")
+            .unwrap();
+        assert_eq!("dragon.png", chin.page().name());
+    }
+
+    #[test]
+    fn premultiplied_alpha() {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        assert!(atlas.pages().iter().all(|page| !page.premultiplied_alpha()));
+        let pma_path = test_case.atlas().with_file_name("dragon-pma.atlas");
+        assert!(is_pma_path(&pma_path));
+        let mut atlas = Atlas::from_file(&pma_path, |page, _| {
+            assert!(page.premultiplied_alpha());
+            0
+        })
+        .unwrap();
+        assert!(atlas.pages().iter().all(AtlasPage::premultiplied_alpha));
+        atlas.set_premultiplied_alpha(false);
+        assert!(!atlas
+            .find_region("chin")
+            .unwrap()
+            .page()
+            .premultiplied_alpha());
+
+        // spine-c cannot read the `pma` line of newer exports, so it is parsed in Rust.
+        let mut data = AtlasData::from_file(test_case.atlas()).unwrap();
+        data.pages[1].pma = true;
+        let path = std::env::temp_dir().join(format!("spine-pma-{}.atlas", std::process::id()));
+        data.write_file(&path).unwrap();
+        let mut loaded = Vec::new();
+        let atlas = Atlas::from_file(&path, |page, path| {
+            loaded.push((path.to_owned(), page.premultiplied_alpha()));
+            0
+        });
+        std::fs::remove_file(&path).unwrap();
+        let atlas = atlas.unwrap();
+        assert_eq!(
+            vec![false, true],
+            atlas
+                .pages()
+                .iter()
+                .map(AtlasPage::premultiplied_alpha)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(atlas.regions_count(), data.regions.len());
+        assert_eq!((std::env::temp_dir().join("dragon2.png"), true), loaded[1]);
+        assert!(!loaded[0].1);
+
+        let region = &data.regions[data.regions.len() - 1];
+        let page = atlas.find_region(&region.name).unwrap().page();
+        assert_eq!(data.pages[region.page].pma, page.premultiplied_alpha());
+    }
+
+    #[test]
+    fn find_region() {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        assert!(atlas.find_region("missing").is_none());
+
+        let chin = atlas.find_region("chin").unwrap();
+        assert_eq!((856, 138), chin.position());
+        assert_eq!((214, 146), chin.dimensions());
+        assert!(chin.rotate());
+        assert_eq!(90, chin.degrees());
+        assert_eq!((0, 0), chin.offset());
+        assert_eq!((214, 146), chin.original_dimensions());
+        assert_eq!(None, chin.splits());
+
+        // Rotated regions span their height horizontally on the page.
+        let [u, v, u2, v2] = chin.uvs();
+        assert!((u - 856.0 / 1024.0).abs() < 1e-6);
+        assert!((v - 138.0 / 1024.0).abs() < 1e-6);
+        assert!((u2 - (856.0 + 146.0) / 1024.0).abs() < 1e-6);
+        assert!((v2 - (138.0 + 214.0) / 1024.0).abs() < 1e-6);
+    }
 }
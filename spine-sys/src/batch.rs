@@ -0,0 +1,292 @@
+use crate::{
+    color::Color,
+    enums::{AttachmentType, BlendMode},
+    ffi,
+    skeleton::{Skeleton, WorldVertex},
+    SpineMutPtr,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// The triangles of consecutive slots sharing a texture and blend mode, drawable with a single
+/// draw call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Batch {
+    /// The texture id of the atlas page, as provided during `Atlas` loading.
+    pub texture_id: u32,
+    pub blend_mode: BlendMode,
+    pub vertices: Vec<WorldVertex>,
+    /// Triangle indices into `vertices`.
+    pub indices: Vec<u32>,
+}
+
+impl WorldVertex {
+    /// The light and dark colors of two color tinting, as the spine-runtimes shaders expect. With
+    /// premultiplied alpha both colors are premultiplied by the light alpha and the dark alpha is
+    /// 1, otherwise the dark alpha is 0.
+    pub fn tint_colors(&self, premultiplied_alpha: bool) -> ([f32; 4], [f32; 4]) {
+        let (light, dark) = if premultiplied_alpha {
+            let (a, dark) = (self.light.a, self.dark);
+            (
+                self.light.premultiplied(),
+                Color::new(dark.r * a, dark.g * a, dark.b * a, 1.0),
+            )
+        } else {
+            (
+                self.light,
+                Color {
+                    a: 0.0,
+                    ..self.dark
+                },
+            )
+        };
+        (light.into(), dark.into())
+    }
+}
+
+/// Groups the region and mesh attachments of a posed skeleton into `Batch`es in draw order,
+/// applying clipping attachments on the way.
+///
+/// # spine-c
+/// Wraps `spSkeletonClipping`
+pub struct SkeletonBatcher {
+    clipper: SpineMutPtr<ffi::spSkeletonClipping>,
+}
+
+// SAFETY: the clipper is exclusively owned scratch state.
+unsafe impl Send for SkeletonBatcher {}
+
+impl Default for SkeletonBatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SkeletonBatcher {
+    pub fn new() -> Self {
+        Self {
+            clipper: SpineMutPtr::new(
+                unsafe { ffi::spSkeletonClipping_create() },
+                Some(ffi::spSkeletonClipping_dispose),
+            ),
+        }
+    }
+
+    /// Batches the visible attachments of `skeleton`. World transforms must be up to date.
+    pub fn batch(&mut self, skeleton: &Skeleton) -> Vec<Batch> {
+        let clipper = self.clipper.as_mut_ptr();
+        let mut batches: Vec<Batch> = Vec::new();
+
+        for slot in skeleton.draw_slots() {
+            let r = slot.as_ref();
+            if r.bone.is_null() || unsafe { (*r.bone).active } == 0 {
+                unsafe { ffi::spSkeletonClipping_clipEnd(clipper, slot.inner) };
+                continue;
+            }
+
+            match slot.active_attachment().map(|attachment| attachment.kind()) {
+                Some(AttachmentType::Clipping) => unsafe {
+                    ffi::spSkeletonClipping_clipStart(clipper, slot.inner, r.attachment.cast());
+                },
+                Some(
+                    AttachmentType::Region | AttachmentType::Mesh | AttachmentType::LinkedMesh,
+                ) => match slot.texture_id() {
+                    Some(texture_id) => {
+                        let (vertices, triangles) =
+                            clip(clipper, slot.world_vertices(), slot.triangles());
+                        let blend_mode = slot.blend_mode();
+
+                        let batch = match batches.last_mut() {
+                            Some(batch)
+                                if batch.texture_id == texture_id
+                                    && batch.blend_mode == blend_mode =>
+                            {
+                                batch
+                            }
+                            _ => {
+                                batches.push(Batch {
+                                    texture_id,
+                                    blend_mode,
+                                    vertices: Vec::new(),
+                                    indices: Vec::new(),
+                                });
+                                batches.last_mut().unwrap()
+                            }
+                        };
+                        let offset = u32::try_from(batch.vertices.len()).unwrap();
+                        batch
+                            .indices
+                            .extend(triangles.iter().map(|&index| offset + u32::from(index)));
+                        batch.vertices.extend(vertices);
+                    }
+                    None => spine_log!(debug, "no texture for slot {:?}", slot.name()),
+                },
+                _ => {}
+            }
+
+            unsafe { ffi::spSkeletonClipping_clipEnd(clipper, slot.inner) };
+        }
+        unsafe { ffi::spSkeletonClipping_clipEnd2(clipper) };
+
+        batches.retain(|batch| !batch.indices.is_empty());
+        batches
+    }
+}
+
+/// Clips triangles against the active clipping attachment, if any.
+fn clip(
+    clipper: *mut ffi::spSkeletonClipping,
+    vertices: Vec<WorldVertex>,
+    mut triangles: Vec<u16>,
+) -> (Vec<WorldVertex>, Vec<u16>) {
+    if vertices.is_empty() || unsafe { ffi::spSkeletonClipping_isClipping(clipper) } == 0 {
+        return (vertices, triangles);
+    }
+
+    let mut positions = vertices.iter().flat_map(|v| v.position).collect::<Vec<_>>();
+    let mut uvs = vertices.iter().flat_map(|v| v.uv).collect::<Vec<_>>();
+    unsafe {
+        ffi::spSkeletonClipping_clipTriangles(
+            clipper,
+            positions.as_mut_ptr(),
+            positions.len().try_into().unwrap(),
+            triangles.as_mut_ptr(),
+            triangles.len().try_into().unwrap(),
+            uvs.as_mut_ptr(),
+            2,
+        );
+
+        let c = &*clipper;
+        let floats = |array: *mut ffi::spFloatArray| {
+            let array = &*array;
+            std::slice::from_raw_parts(array.items, array.size.try_into().unwrap())
+        };
+        let clipped_triangles = &*c.clippedTriangles;
+        let triangles = std::slice::from_raw_parts(
+            clipped_triangles.items,
+            clipped_triangles.size.try_into().unwrap(),
+        )
+        .to_vec();
+
+        let vertices = floats(c.clippedVertices)
+            .chunks_exact(2)
+            .zip(floats(c.clippedUVs).chunks_exact(2))
+            .map(|(position, uv)| WorldVertex {
+                position: [position[0], position[1]],
+                uv: [uv[0], uv[1]],
+                ..vertices[0]
+            })
+            .collect();
+
+        (vertices, triangles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        atlas::Atlas,
+        skeleton::{SkeletonData, Slot},
+        tests::{skeleton_data_from_json, TEST_CASES},
+    };
+
+    #[test]
+    fn tint_colors() {
+        let vertex = WorldVertex {
+            position: [0.0, 0.0],
+            uv: [0.0, 0.0],
+            light: Color::new(1.0, 0.5, 0.25, 0.5),
+            dark: Color::new(0.5, 0.25, 1.0, 0.75),
+        };
+        assert_eq!(
+            ([1.0, 0.5, 0.25, 0.5], [0.5, 0.25, 1.0, 0.0]),
+            vertex.tint_colors(false)
+        );
+        assert_eq!(
+            ([0.5, 0.25, 0.125, 0.5], [0.25, 0.125, 0.5, 1.0]),
+            vertex.tint_colors(true)
+        );
+    }
+
+    #[test]
+    fn batches_follow_draw_order() {
+        let test_case = &TEST_CASES[0];
+        let mut next_id = 0;
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| {
+            next_id += 1;
+            next_id
+        })
+        .unwrap();
+        let skeleton_data =
+            unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas) }.unwrap();
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        let batches = SkeletonBatcher::new().batch(&skeleton);
+        assert!(!batches.is_empty());
+        assert!(batches
+            .windows(2)
+            .all(|pair| (pair[0].texture_id, pair[0].blend_mode)
+                != (pair[1].texture_id, pair[1].blend_mode)));
+
+        let slots = skeleton
+            .draw_slots()
+            .into_iter()
+            .filter(|slot| slot.texture_id().is_some())
+            .collect::<Vec<_>>();
+        let vertices = slots.iter().flat_map(Slot::world_vertices);
+        assert!(vertices.eq(batches
+            .iter()
+            .flat_map(|batch| batch.vertices.iter().copied())));
+        let triangles: usize = slots.iter().map(|slot| slot.triangles().len()).sum();
+        assert_eq!(
+            triangles,
+            batches
+                .iter()
+                .map(|batch| batch.indices.len())
+                .sum::<usize>()
+        );
+        for batch in &batches {
+            let count = u32::try_from(batch.vertices.len()).unwrap();
+            assert!(batch.indices.iter().all(|&index| index < count));
+        }
+    }
+
+    #[test]
+    fn clipping_splits_triangles() {
+        let json = r#"{
+            "skeleton": { "hash": "batch", "spine": "3.8.55" },
+            "bones": [ { "name": "root" } ],
+            "slots": [
+                { "name": "clip", "bone": "root", "attachment": "clip" },
+                { "name": "image", "bone": "root", "attachment": "chin" }
+            ],
+            "skins": [ { "name": "default", "attachments": {
+                "clip": { "clip": {
+                    "type": "clipping", "end": "image", "vertexCount": 3,
+                    "vertices": [ 0, 0, 1000, 0, 0, 1000 ]
+                } },
+                "image": { "chin": { "width": 214, "height": 146 } }
+            } } ]
+        }"#;
+        let unclipped = skeleton_data_from_json(&json.replace(r#", "attachment": "clip""#, ""));
+        let mut skeleton = Skeleton::new(&unclipped);
+        skeleton.update_world_transforms();
+        let batches = SkeletonBatcher::new().batch(&skeleton);
+        assert_eq!(1, batches.len());
+        assert_eq!(
+            (4, 6),
+            (batches[0].vertices.len(), batches[0].indices.len())
+        );
+
+        let clipped = skeleton_data_from_json(json);
+        let mut skeleton = Skeleton::new(&clipped);
+        skeleton.update_world_transforms();
+        let batches = SkeletonBatcher::new().batch(&skeleton);
+        assert_eq!(1, batches.len());
+        assert!(!batches[0].indices.is_empty());
+        assert!(batches[0]
+            .vertices
+            .iter()
+            .all(|vertex| vertex.position[0] > -0.001 && vertex.position[1] > -0.001));
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{
+    enums::AttachmentType,
+    ffi,
+    skeleton::{slot_ptr, BoundingBoxAttachment, Skeleton, Slot, SlotIndex},
+    SpineMutPtr,
+};
+use std::{convert::TryInto, marker::PhantomData};
+
+/// A world space polygon computed from a `BoundingBoxAttachment`.
+pub struct Polygon<'a> {
+    pub(crate) inner: *mut ffi::spPolygon,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
+}
+impl Polygon<'_> {
+    /// Returns the world space vertices of this polygon as `[x0, y0, x1, y1, ...]`.
+    pub fn vertices(&self) -> &[f32] {
+        let r = self.as_ref();
+        unsafe { std::slice::from_raw_parts(r.vertices, r.count.try_into().unwrap()) }
+    }
+
+    /// # spine-c
+    /// Maps to calling `spPolygon_containsPoint`
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        unsafe { ffi::spPolygon_containsPoint(self.inner, x, y) != 0 }
+    }
+
+    /// # spine-c
+    /// Maps to calling `spPolygon_intersectsSegment`
+    pub fn intersects_segment(&self, x1: f32, y1: f32, x2: f32, y2: f32) -> bool {
+        unsafe { ffi::spPolygon_intersectsSegment(self.inner, x1, y1, x2, y2) != 0 }
+    }
+
+    pub(crate) fn as_ref(&self) -> &ffi::spPolygon {
+        unsafe { self.inner.as_ref().unwrap() }
+    }
+}
+
+/// A bounding box found by `SkeletonBounds`, along with its world polygon.
+pub struct BoundingBox<'a> {
+    pub slot: SlotIndex,
+    pub attachment: BoundingBoxAttachment<'a>,
+    pub polygon: Polygon<'a>,
+}
+impl BoundingBox<'_> {
+    /// Returns the slot this bounding box is attached to in `skeleton`, which must be the skeleton
+    /// last passed to `SkeletonBounds::update`, or another skeleton of the same `SkeletonData`.
+    ///
+    /// # Panics
+    /// If `skeleton` does not have the slot.
+    pub fn slot<'s>(&self, skeleton: &'s Skeleton) -> Slot<'s> {
+        Slot {
+            inner: unsafe { slot_ptr(skeleton.inner.as_mut_ptr(), self.slot) },
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+/// Collects the world space polygons of every active `BoundingBoxAttachment` of a skeleton, for hit
+/// detection.
+///
+/// The polygons are a snapshot of the `Skeleton` last passed to `SkeletonBounds::update`; the bounds
+/// keep that skeleton's data alive, but do not borrow the skeleton itself.
+pub struct SkeletonBounds {
+    pub(crate) inner: SpineMutPtr<ffi::spSkeletonBounds>,
+    slots: Vec<SlotIndex>,
+    data: Option<SpineMutPtr<ffi::spSkeletonData>>,
+}
+impl SkeletonBounds {
+    pub fn new() -> Self {
+        Self {
+            inner: SpineMutPtr::new(
+                unsafe { ffi::spSkeletonBounds_create() },
+                Some(ffi::spSkeletonBounds_dispose),
+            ),
+            slots: Vec::new(),
+            data: None,
+        }
+    }
+
+    /// Recomputes the bounding polygons from the current world transforms of `skeleton`. If
+    /// `update_aabb` is true, the axis aligned bounding box enclosing all polygons is also computed.
+    ///
+    /// # spine-c
+    /// Maps to calling `spSkeletonBounds_update`
+    pub fn update(&mut self, skeleton: &Skeleton, update_aabb: bool) {
+        unsafe {
+            ffi::spSkeletonBounds_update(
+                self.inner.as_mut_ptr(),
+                skeleton.inner.as_mut_ptr(),
+                std::os::raw::c_int::from(update_aabb),
+            );
+        }
+
+        // spine-c does not record the owning slots, so collect them in the same order it does.
+        self.slots = skeleton
+            .slots()
+            .into_iter()
+            .filter(|slot| {
+                let r = slot.as_ref();
+                unsafe {
+                    (*r.bone).active != 0
+                        && !r.attachment.is_null()
+                        && AttachmentType::from((*r.attachment).type_)
+                            == AttachmentType::BoundingBox
+                }
+            })
+            .map(|slot| slot.index())
+            .collect();
+        self.data = Some(skeleton.data.clone());
+    }
+
+    /// Returns the axis aligned bounding box as `[min_x, min_y, max_x, max_y]`.
+    pub fn aabb(&self) -> [f32; 4] {
+        let r = self.inner.as_ref();
+        [r.minX, r.minY, r.maxX, r.maxY]
+    }
+
+    pub fn aabb_contains_point(&self, x: f32, y: f32) -> bool {
+        unsafe { ffi::spSkeletonBounds_aabbContainsPoint(self.inner.as_mut_ptr(), x, y) != 0 }
+    }
+
+    pub fn aabb_intersects_segment(&self, x1: f32, y1: f32, x2: f32, y2: f32) -> bool {
+        unsafe {
+            ffi::spSkeletonBounds_aabbIntersectsSegment(self.inner.as_mut_ptr(), x1, y1, x2, y2)
+                != 0
+        }
+    }
+
+    pub fn aabb_intersects_skeleton(&self, other: &SkeletonBounds) -> bool {
+        unsafe {
+            ffi::spSkeletonBounds_aabbIntersectsSkeleton(
+                self.inner.as_mut_ptr(),
+                other.inner.as_mut_ptr(),
+            ) != 0
+        }
+    }
+
+    /// Returns every bounding box collected by the last `SkeletonBounds::update`.
+    pub fn bounding_boxes(&self) -> Vec<BoundingBox<'_>> {
+        (0..self.slots.len()).map(|n| self.entry(n)).collect()
+    }
+
+    /// Returns the first bounding box that contains the point. When doing many checks, it is usually
+    /// more efficient to only call this if `SkeletonBounds::aabb_contains_point` returns true.
+    ///
+    /// # spine-c
+    /// Maps to calling `spSkeletonBounds_containsPoint`
+    pub fn contains_point(&self, x: f32, y: f32) -> Option<BoundingBox<'_>> {
+        self.find(unsafe { ffi::spSkeletonBounds_containsPoint(self.inner.as_mut_ptr(), x, y) })
+    }
+
+    /// Returns the first bounding box that intersects the line segment. When doing many checks, it
+    /// is usually more efficient to only call this if `SkeletonBounds::aabb_intersects_segment`
+    /// returns true.
+    ///
+    /// # spine-c
+    /// Maps to calling `spSkeletonBounds_intersectsSegment`
+    pub fn intersects_segment(
+        &self,
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+    ) -> Option<BoundingBox<'_>> {
+        self.find(unsafe {
+            ffi::spSkeletonBounds_intersectsSegment(self.inner.as_mut_ptr(), x1, y1, x2, y2)
+        })
+    }
+
+    fn find(&self, attachment: *mut ffi::spBoundingBoxAttachment) -> Option<BoundingBox<'_>> {
+        let r = self.inner.as_ref();
+        (0..self.slots.len())
+            .find(|&n| unsafe { *r.boundingBoxes.add(n) } == attachment)
+            .map(|n| self.entry(n))
+    }
+
+    fn entry(&self, n: usize) -> BoundingBox<'_> {
+        let r = self.inner.as_ref();
+        unsafe {
+            BoundingBox {
+                slot: self.slots[n],
+                attachment: BoundingBoxAttachment {
+                    inner: *r.boundingBoxes.add(n),
+                    _lifetime: PhantomData,
+                },
+                polygon: Polygon {
+                    inner: *r.polygons.add(n),
+                    _lifetime: PhantomData,
+                },
+            }
+        }
+    }
+}
+impl Default for SkeletonBounds {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::{
+        atlas::Atlas,
+        skeleton::SkeletonData,
+        tests::{skeleton_data_from_json, TEST_CASES},
+    };
+
+    const HITBOX_JSON: &str = r#"{
+        "skeleton": { "hash": "hitbox", "spine": "3.8.55" },
+        "bones": [ { "name": "root" }, { "name": "arm", "parent": "root", "x": 100 } ],
+        "slots": [ { "name": "body", "bone": "root", "attachment": "body" },
+                   { "name": "hand", "bone": "arm", "attachment": "hand" } ],
+        "skins": [ { "name": "default", "attachments": {
+            "body": { "body": { "type": "boundingbox", "vertexCount": 4,
+                                "vertices": [ -10, -10, 10, -10, 10, 10, -10, 10 ] } },
+            "hand": { "hand": { "type": "boundingbox", "vertexCount": 3,
+                                "vertices": [ 0, 0, 20, 0, 0, 20 ] } }
+        } } ]
+    }"#;
+
+    fn hitbox_skeleton() -> (SkeletonData, Skeleton) {
+        let skeleton_data = skeleton_data_from_json(HITBOX_JSON);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        (skeleton_data, skeleton)
+    }
+
+    #[test]
+    fn hit_test() {
+        let (_data, skeleton) = hitbox_skeleton();
+
+        let mut bounds = SkeletonBounds::new();
+        bounds.update(&skeleton, true);
+
+        assert_eq!(2, bounds.bounding_boxes().len());
+        assert_eq!([-10.0, -10.0, 120.0, 20.0], bounds.aabb());
+        assert!(bounds.aabb_contains_point(0.0, 0.0));
+        assert!(!bounds.aabb_contains_point(200.0, 0.0));
+
+        let hit = bounds.contains_point(0.0, 0.0).unwrap();
+        assert_eq!("body", hit.slot(&skeleton).name());
+        assert_eq!("body", hit.attachment.name());
+        assert_eq!(8, hit.polygon.vertices().len());
+
+        let hit = bounds.contains_point(105.0, 5.0).unwrap();
+        assert_eq!("hand", hit.slot(&skeleton).name());
+        assert!(!hit.polygon.contains_point(119.0, 19.0));
+
+        assert!(bounds.contains_point(50.0, 0.0).is_none());
+
+        let hit = bounds.intersects_segment(90.0, 10.0, 110.0, 10.0).unwrap();
+        assert_eq!("hand", hit.attachment.name());
+        assert!(hit.polygon.intersects_segment(90.0, 10.0, 110.0, 10.0));
+        assert!(bounds.intersects_segment(50.0, -50.0, 50.0, 50.0).is_none());
+    }
+
+    #[test]
+    fn aabb_intersects_skeleton() {
+        let (_data, skeleton) = hitbox_skeleton();
+        let (_other_data, mut other) = hitbox_skeleton();
+
+        let mut bounds = SkeletonBounds::new();
+        bounds.update(&skeleton, true);
+
+        let mut other_bounds = SkeletonBounds::new();
+        other.inner.as_mut().x = 100.0;
+        other.update_world_transforms();
+        other_bounds.update(&other, true);
+        assert!(bounds.aabb_intersects_skeleton(&other_bounds));
+
+        other.inner.as_mut().x = 1000.0;
+        other.update_world_transforms();
+        other_bounds.update(&other, true);
+        assert!(!bounds.aabb_intersects_skeleton(&other_bounds));
+    }
+
+    #[test]
+    fn no_bounding_boxes() {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        let skeleton_data =
+            unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas) }.unwrap();
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        let mut bounds = SkeletonBounds::default();
+        bounds.update(&skeleton, true);
+
+        assert!(bounds.bounding_boxes().is_empty());
+        assert!(bounds.contains_point(0.0, 0.0).is_none());
+    }
+}
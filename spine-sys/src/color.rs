@@ -0,0 +1,216 @@
+use crate::ffi;
+
+/// A linear RGBA color with `f32` channels in the range 0-1.
+///
+/// This is the type of every color surfaced from spine-c. Renderers can convert it with
+/// `Into<[f32; 4]>` or pack it with `Color::to_rgba8`/`Color::to_abgr8`.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+impl Color {
+    pub const WHITE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+    pub const BLACK: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Unpacks a color stored as `0xRRGGBBAA`, the order used by Spine's JSON export.
+    pub fn from_rgba8(rgba: u32) -> Self {
+        let [r, g, b, a] = rgba.to_be_bytes();
+        Self::from_bytes(r, g, b, a)
+    }
+
+    /// Unpacks a color stored as `0xAABBGGRR`, which is `[r, g, b, a]` in memory on little endian
+    /// machines.
+    pub fn from_abgr8(abgr: u32) -> Self {
+        let [a, b, g, r] = abgr.to_be_bytes();
+        Self::from_bytes(r, g, b, a)
+    }
+
+    /// Packs this color as `0xRRGGBBAA`. Channels are clamped to 0-1.
+    pub fn to_rgba8(self) -> u32 {
+        let [r, g, b, a] = self.to_bytes();
+        u32::from_be_bytes([r, g, b, a])
+    }
+
+    /// Packs this color as `0xAABBGGRR`. Channels are clamped to 0-1.
+    pub fn to_abgr8(self) -> u32 {
+        let [r, g, b, a] = self.to_bytes();
+        u32::from_be_bytes([a, b, g, r])
+    }
+
+    /// Returns this color with the red, green and blue channels multiplied by alpha, for rendering
+    /// with premultiplied alpha blending.
+    #[must_use]
+    pub fn premultiplied(self) -> Self {
+        Self::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    /// Packs the premultiplied color as `0xRRGGBBAA`.
+    pub fn to_premultiplied_rgba8(self) -> u32 {
+        self.premultiplied().to_rgba8()
+    }
+
+    /// Packs the premultiplied color as `0xAABBGGRR`.
+    pub fn to_premultiplied_abgr8(self) -> u32 {
+        self.premultiplied().to_abgr8()
+    }
+
+    fn from_bytes(r: u8, g: u8, b: u8, a: u8) -> Self {
+        let channel = |c: u8| f32::from(c) / 255.0;
+        Self::new(channel(r), channel(g), channel(b), channel(a))
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn to_bytes(self) -> [u8; 4] {
+        let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [
+            channel(self.r),
+            channel(self.g),
+            channel(self.b),
+            channel(self.a),
+        ]
+    }
+}
+impl Default for Color {
+    fn default() -> Self {
+        Self::WHITE
+    }
+}
+impl std::ops::Mul for Color {
+    type Output = Self;
+
+    /// Tints one color by another, channel by channel.
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.r * rhs.r,
+            self.g * rhs.g,
+            self.b * rhs.b,
+            self.a * rhs.a,
+        )
+    }
+}
+impl From<ffi::spColor> for Color {
+    fn from(color: ffi::spColor) -> Self {
+        Self::new(color.r, color.g, color.b, color.a)
+    }
+}
+impl From<Color> for ffi::spColor {
+    fn from(color: Color) -> Self {
+        Self {
+            r: color.r,
+            g: color.g,
+            b: color.b,
+            a: color.a,
+        }
+    }
+}
+impl From<[f32; 4]> for Color {
+    fn from(color: [f32; 4]) -> Self {
+        Self::new(color[0], color[1], color[2], color[3])
+    }
+}
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        [color.r, color.g, color.b, color.a]
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector4<f32>> for Color {
+    fn from(color: mint::Vector4<f32>) -> Self {
+        Self::new(color.x, color.y, color.z, color.w)
+    }
+}
+#[cfg(feature = "mint")]
+impl From<Color> for mint::Vector4<f32> {
+    fn from(color: Color) -> Self {
+        Self {
+            x: color.r,
+            y: color.g,
+            z: color.b,
+            w: color.a,
+        }
+    }
+}
+
+#[cfg(feature = "rgb")]
+impl From<rgb::RGBA<f32>> for Color {
+    fn from(color: rgb::RGBA<f32>) -> Self {
+        Self::new(color.r, color.g, color.b, color.a)
+    }
+}
+#[cfg(feature = "rgb")]
+impl From<Color> for rgb::RGBA<f32> {
+    fn from(color: Color) -> Self {
+        Self::new(color.r, color.g, color.b, color.a)
+    }
+}
+#[cfg(feature = "rgb")]
+impl From<rgb::RGBA8> for Color {
+    fn from(color: rgb::RGBA8) -> Self {
+        Self::from_bytes(color.r, color.g, color.b, color.a)
+    }
+}
+#[cfg(feature = "rgb")]
+impl From<Color> for rgb::RGBA8 {
+    fn from(color: Color) -> Self {
+        let [r, g, b, a] = color.to_bytes();
+        Self::new(r, g, b, a)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packing() {
+        let color = Color::from_rgba8(0xffe3_00ff);
+        assert_eq!(1.0, color.r);
+        assert_eq!(f32::from(0xe3_u8) / 255.0, color.g);
+        assert_eq!(0.0, color.b);
+        assert_eq!(1.0, color.a);
+
+        assert_eq!(0xffe3_00ff, color.to_rgba8());
+        assert_eq!(0xff00_e3ff, color.to_abgr8());
+        assert_eq!(color, Color::from_abgr8(color.to_abgr8()));
+        assert_eq!(
+            [color.r, color.g, color.b, color.a],
+            <[f32; 4]>::from(color)
+        );
+
+        let half = Color::new(1.0, 0.5, 0.0, 0.5);
+        assert_eq!(Color::new(0.5, 0.25, 0.0, 0.5), half.premultiplied());
+        assert_eq!(0x8040_0080, half.to_premultiplied_rgba8());
+        assert_eq!(0x8000_4080, half.to_premultiplied_abgr8());
+        assert_eq!(0xff00_0000, Color::new(2.0, -1.0, 0.0, 0.0).to_rgba8());
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_interop() {
+        let color = Color::new(0.1, 0.2, 0.3, 0.4);
+        let vector = mint::Vector4::from(color);
+        assert_eq!([0.1, 0.2, 0.3, 0.4], <[f32; 4]>::from(vector));
+        assert_eq!(color, Color::from(vector));
+    }
+
+    #[cfg(feature = "rgb")]
+    #[test]
+    fn rgb_interop() {
+        let color = Color::from_rgba8(0x1020_30ff);
+        assert_eq!(rgb::RGBA8::new(0x10, 0x20, 0x30, 0xff), color.into());
+        assert_eq!(color, Color::from(rgb::RGBA8::new(0x10, 0x20, 0x30, 0xff)));
+        assert_eq!(color, Color::from(rgb::RGBA::<f32>::from(color)));
+    }
+}
@@ -0,0 +1,1073 @@
+use crate::{
+    enums::{AttachmentType, PositionMode, RotateMode, SpacingMode},
+    ffi,
+    skeleton::{
+        bone_ptr, slot_ptr, Bone, BoneData, BoneIndex, BoneMut, Skeleton, SkeletonData, Slot,
+        SlotData, SlotIndex,
+    },
+};
+use std::{
+    convert::TryInto,
+    ffi::{CStr, CString},
+    marker::PhantomData,
+    os::raw::c_int,
+};
+
+/// Converts a spine-c array of bone pointers into `Bone` handles.
+unsafe fn bones<'a>(bones: *mut *mut ffi::spBone, count: c_int) -> Vec<Bone<'a>> {
+    (0..count.try_into().unwrap())
+        .map(|n| Bone {
+            inner: *bones.add(n),
+            _lifetime: PhantomData,
+        })
+        .collect()
+}
+
+/// Replaces the bones of a constraint in place with bones of the same skeleton. spine-c owns the
+/// array, so the count cannot change.
+unsafe fn set_bones(bones: *mut *mut ffi::spBone, count: c_int, new: &[BoneIndex]) {
+    let count: usize = count.try_into().unwrap();
+    assert_eq!(
+        new.len(),
+        count,
+        "the number of constrained bones cannot change"
+    );
+    if new.is_empty() {
+        return;
+    }
+    let skeleton = (**bones).skeleton;
+    for (n, index) in new.iter().enumerate() {
+        *bones.add(n) = bone_ptr(skeleton, *index);
+    }
+}
+
+/// Checks the bones given to `spIkConstraint_apply2`, which reads the parent of `parent` and
+/// expects `child` to be its child.
+unsafe fn assert_ik_chain(parent: *mut ffi::spBone, child: *mut ffi::spBone) {
+    assert!(
+        !(*parent).parent.is_null(),
+        "the root bone cannot be the parent of a two bone IK chain"
+    );
+    assert!(
+        (*child).parent == parent,
+        "the child of a two bone IK chain must be a child of its parent"
+    );
+}
+
+/// A runtime instance of an IK constraint, which rotates one or two bones so the tip of the chain
+/// reaches a target bone.
+pub struct IkConstraint<'a> {
+    pub(crate) inner: *mut ffi::spIkConstraint,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
+}
+impl IkConstraint<'_> {
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr((*self.as_ref().data).name) }
+            .to_str()
+            .unwrap()
+    }
+
+    /// The constrained bones, from parent to child.
+    pub fn bones(&self) -> Vec<Bone<'_>> {
+        let r = self.as_ref();
+        unsafe { bones(r.bones, r.bonesCount) }
+    }
+
+    pub fn target(&self) -> Bone<'_> {
+        Bone {
+            inner: self.as_ref().target,
+            _lifetime: PhantomData,
+        }
+    }
+
+    /// A percentage (0-1) that controls the mix between the constrained and unconstrained rotations.
+    pub fn mix(&self) -> f32 {
+        self.as_ref().mix
+    }
+
+    /// For two bone IK, the distance from the maximum reach of the bones that rotation will slow.
+    pub fn softness(&self) -> f32 {
+        self.as_ref().softness
+    }
+
+    /// For two bone IK, controls the bend direction of the chain: `1` or `-1`.
+    pub fn bend_direction(&self) -> i32 {
+        self.as_ref().bendDirection
+    }
+
+    /// For one bone IK, when true and the target is too close, the bone is scaled to reach it.
+    pub fn compress(&self) -> bool {
+        self.as_ref().compress != 0
+    }
+
+    /// When true and the target is out of reach, the parent bone is scaled to reach it.
+    pub fn stretch(&self) -> bool {
+        self.as_ref().stretch != 0
+    }
+
+    /// For one bone IK, when true and `compress` or `stretch` is used, the bone is scaled on both axes.
+    pub fn uniform(&self) -> bool {
+        unsafe { (*self.as_ref().data).uniform != 0 }
+    }
+
+    /// Rotates a single bone so it points at the world space `target`. The rotation is applied on top
+    /// of the bone's local pose, so its world transform must be up to date with that pose.
+    ///
+    /// # Panics
+    /// If `bone` is the root bone.
+    ///
+    /// # spine-c
+    /// Maps to calling `spIkConstraint_apply1`
+    pub fn apply1(
+        bone: &mut BoneMut<'_>,
+        target: [f32; 2],
+        compress: bool,
+        stretch: bool,
+        uniform: bool,
+        alpha: f32,
+    ) {
+        assert!(
+            !unsafe { (*bone.inner).parent }.is_null(),
+            "the root bone cannot be rotated by IK"
+        );
+        unsafe {
+            ffi::spIkConstraint_apply1(
+                bone.inner,
+                target[0],
+                target[1],
+                c_int::from(compress),
+                c_int::from(stretch),
+                c_int::from(uniform),
+                alpha,
+            );
+        }
+    }
+
+    /// Rotates the `parent` and `child` bones of `skeleton` so the tip of the child reaches the world
+    /// space `target`. The rotations are applied on top of the bones' local pose, so their world
+    /// transforms must be up to date with that pose.
+    ///
+    /// # Panics
+    /// If `parent` or `child` do not refer to bones of `skeleton`, if `parent` is the root bone or
+    /// if `child` is not a child of `parent`.
+    ///
+    /// # spine-c
+    /// Maps to calling `spIkConstraint_apply2`
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply2(
+        skeleton: &mut Skeleton,
+        parent: BoneIndex,
+        child: BoneIndex,
+        target: [f32; 2],
+        bend_direction: i32,
+        stretch: bool,
+        softness: f32,
+        alpha: f32,
+    ) {
+        let (parent, child) = (skeleton.bone_ptr(parent), skeleton.bone_ptr(child));
+        unsafe {
+            assert_ik_chain(parent, child);
+            ffi::spIkConstraint_apply2(
+                parent,
+                child,
+                target[0],
+                target[1],
+                bend_direction,
+                c_int::from(stretch),
+                softness,
+                alpha,
+            );
+        }
+    }
+
+    pub(crate) fn as_ref(&self) -> &ffi::spIkConstraint {
+        unsafe { self.inner.as_ref().unwrap() }
+    }
+    pub(crate) fn as_mut(&mut self) -> &mut ffi::spIkConstraint {
+        unsafe { self.inner.as_mut().unwrap() }
+    }
+}
+
+spine_handle_mut!(
+    /// An `IkConstraint` that can also be modified, borrowed from a `&mut Skeleton`.
+    IkConstraintMut,
+    IkConstraint,
+    ffi::spIkConstraint
+);
+impl IkConstraintMut<'_> {
+    /// Sets the bone the chain reaches for. `Skeleton::update_cache` must be called afterwards so
+    /// the new target is updated before the constraint is applied.
+    ///
+    /// # Panics
+    /// If `target` does not refer to a bone of the constraint's skeleton.
+    pub fn set_target(&mut self, target: BoneIndex) {
+        let r = self.as_mut();
+        r.target = unsafe { bone_ptr((*r.target).skeleton, target) };
+    }
+
+    pub fn set_mix(&mut self, mix: f32) {
+        self.as_mut().mix = mix;
+    }
+
+    pub fn set_softness(&mut self, softness: f32) {
+        self.as_mut().softness = softness;
+    }
+
+    pub fn set_bend_direction(&mut self, bend_direction: i32) {
+        self.as_mut().bendDirection = bend_direction;
+    }
+
+    pub fn set_compress(&mut self, compress: bool) {
+        self.as_mut().compress = c_int::from(compress);
+    }
+
+    pub fn set_stretch(&mut self, stretch: bool) {
+        self.as_mut().stretch = c_int::from(stretch);
+    }
+
+    /// Applies the constraint to the constrained bones toward the current target bone position.
+    ///
+    /// # spine-c
+    /// Maps to calling `spIkConstraint_apply`
+    pub fn apply(&mut self) {
+        unsafe { ffi::spIkConstraint_apply(self.inner) }
+    }
+
+    /// Moves the target bone so it sits at the world space `position`, for example the cursor. The
+    /// chain follows on the next `Skeleton::update_world_transforms`.
+    pub fn set_target_world_position(&mut self, position: [f32; 2]) {
+        let mut target = BoneMut::new(self.as_ref().target);
+        let parent = target.as_ref().parent;
+
+        let local = if parent.is_null() {
+            position
+        } else {
+            Bone {
+                inner: parent,
+                _lifetime: PhantomData,
+            }
+            .world_to_local(position)
+        };
+        target.set_position(local);
+    }
+
+    /// Immediately solves the constrained chain toward the world space `target` point using this
+    /// constraint's settings, starting from the local pose of the bones and without moving the target
+    /// bone. Bones parented to the chain are not updated.
+    ///
+    /// # Panics
+    /// If the chain starts at the root bone, or its two bones are not parent and child.
+    pub fn solve_toward(&mut self, target: [f32; 2]) {
+        let (stretch, mix) = (self.stretch(), self.mix());
+        let r = self.as_ref();
+        let bones = unsafe {
+            std::slice::from_raw_parts(r.bones.cast_const(), r.bonesCount.try_into().unwrap())
+        };
+
+        for &bone in bones {
+            unsafe { ffi::spBone_updateWorldTransform(bone) };
+        }
+
+        match *bones {
+            [bone] => IkConstraint::apply1(
+                &mut BoneMut::new(bone),
+                target,
+                self.compress(),
+                stretch,
+                self.uniform(),
+                mix,
+            ),
+            [parent, child] => unsafe {
+                assert_ik_chain(parent, child);
+                ffi::spIkConstraint_apply2(
+                    parent,
+                    child,
+                    target[0],
+                    target[1],
+                    self.bend_direction(),
+                    c_int::from(stretch),
+                    self.softness(),
+                    mix,
+                );
+            },
+            _ => {}
+        }
+    }
+}
+
+/// A runtime instance of a transform constraint, which copies the world transform of a target bone
+/// to the constrained bones.
+pub struct TransformConstraint<'a> {
+    pub(crate) inner: *mut ffi::spTransformConstraint,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
+}
+impl TransformConstraint<'_> {
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr((*self.as_ref().data).name) }
+            .to_str()
+            .unwrap()
+    }
+
+    pub fn bones(&self) -> Vec<Bone<'_>> {
+        let r = self.as_ref();
+        unsafe { bones(r.bones, r.bonesCount) }
+    }
+
+    pub fn target(&self) -> Bone<'_> {
+        Bone {
+            inner: self.as_ref().target,
+            _lifetime: PhantomData,
+        }
+    }
+
+    /// A percentage (0-1) that controls the mix between the constrained and unconstrained rotations.
+    pub fn rotate_mix(&self) -> f32 {
+        self.as_ref().rotateMix
+    }
+
+    /// A percentage (0-1) that controls the mix between the constrained and unconstrained translations.
+    pub fn translate_mix(&self) -> f32 {
+        self.as_ref().translateMix
+    }
+
+    /// A percentage (0-1) that controls the mix between the constrained and unconstrained scales.
+    pub fn scale_mix(&self) -> f32 {
+        self.as_ref().scaleMix
+    }
+
+    /// A percentage (0-1) that controls the mix between the constrained and unconstrained shears.
+    pub fn shear_mix(&self) -> f32 {
+        self.as_ref().shearMix
+    }
+
+    /// The offsets added to the target transform, as set up in the editor: rotation, x, y, scale x,
+    /// scale y and shear y.
+    pub fn offsets(&self) -> [f32; 6] {
+        let d = unsafe { &*self.as_ref().data };
+        [
+            d.offsetRotation,
+            d.offsetX,
+            d.offsetY,
+            d.offsetScaleX,
+            d.offsetScaleY,
+            d.offsetShearY,
+        ]
+    }
+
+    /// When true, the target transform is added to the constrained bones instead of replacing it.
+    pub fn relative(&self) -> bool {
+        unsafe { (*self.as_ref().data).relative != 0 }
+    }
+
+    /// When true, the constraint works in the local space of the bones instead of world space.
+    pub fn local(&self) -> bool {
+        unsafe { (*self.as_ref().data).local != 0 }
+    }
+
+    pub(crate) fn as_ref(&self) -> &ffi::spTransformConstraint {
+        unsafe { self.inner.as_ref().unwrap() }
+    }
+    pub(crate) fn as_mut(&mut self) -> &mut ffi::spTransformConstraint {
+        unsafe { self.inner.as_mut().unwrap() }
+    }
+}
+
+spine_handle_mut!(
+    /// A `TransformConstraint` that can also be modified, borrowed from a `&mut Skeleton`.
+    TransformConstraintMut,
+    TransformConstraint,
+    ffi::spTransformConstraint
+);
+impl TransformConstraintMut<'_> {
+    /// Replaces the constrained bones. The number of bones cannot change, and
+    /// `Skeleton::update_cache` must be called afterwards.
+    ///
+    /// # Panics
+    /// If `new_bones` does not have the same length as `TransformConstraint::bones`, or refers to
+    /// bones outside the constraint's skeleton.
+    pub fn set_bones(&mut self, new_bones: &[BoneIndex]) {
+        let r = self.as_mut();
+        unsafe { set_bones(r.bones, r.bonesCount, new_bones) }
+    }
+
+    /// Sets the bone whose transform is copied. `Skeleton::update_cache` must be called afterwards.
+    ///
+    /// # Panics
+    /// If `target` does not refer to a bone of the constraint's skeleton.
+    pub fn set_target(&mut self, target: BoneIndex) {
+        let r = self.as_mut();
+        r.target = unsafe { bone_ptr((*r.target).skeleton, target) };
+    }
+
+    pub fn set_rotate_mix(&mut self, mix: f32) {
+        self.as_mut().rotateMix = mix;
+    }
+
+    pub fn set_translate_mix(&mut self, mix: f32) {
+        self.as_mut().translateMix = mix;
+    }
+
+    pub fn set_scale_mix(&mut self, mix: f32) {
+        self.as_mut().scaleMix = mix;
+    }
+
+    pub fn set_shear_mix(&mut self, mix: f32) {
+        self.as_mut().shearMix = mix;
+    }
+
+    /// # spine-c
+    /// Maps to calling `spTransformConstraint_apply`
+    pub fn apply(&mut self) {
+        unsafe { ffi::spTransformConstraint_apply(self.inner) }
+    }
+}
+
+/// A runtime instance of a path constraint, which positions and rotates the constrained bones along
+/// the path attachment of a target slot.
+pub struct PathConstraint<'a> {
+    pub(crate) inner: *mut ffi::spPathConstraint,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
+}
+impl PathConstraint<'_> {
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr((*self.as_ref().data).name) }
+            .to_str()
+            .unwrap()
+    }
+
+    pub fn bones(&self) -> Vec<Bone<'_>> {
+        let r = self.as_ref();
+        unsafe { bones(r.bones, r.bonesCount) }
+    }
+
+    /// The slot whose path attachment the bones are constrained to.
+    pub fn target(&self) -> Slot<'_> {
+        Slot {
+            inner: self.as_ref().target,
+            _lifetime: PhantomData,
+        }
+    }
+
+    /// The position along the path, either in world units or as a percentage (0-1) of the path
+    /// length depending on `PathConstraint::position_mode`.
+    pub fn position(&self) -> f32 {
+        self.as_ref().position
+    }
+
+    /// The spacing between bones, interpreted according to `PathConstraint::spacing_mode`.
+    pub fn spacing(&self) -> f32 {
+        self.as_ref().spacing
+    }
+
+    /// A percentage (0-1) that controls the mix between the constrained and unconstrained rotations.
+    pub fn rotate_mix(&self) -> f32 {
+        self.as_ref().rotateMix
+    }
+
+    /// A percentage (0-1) that controls the mix between the constrained and unconstrained translations.
+    pub fn translate_mix(&self) -> f32 {
+        self.as_ref().translateMix
+    }
+
+    pub fn position_mode(&self) -> PositionMode {
+        unsafe { (*self.as_ref().data).positionMode }.into()
+    }
+
+    pub fn spacing_mode(&self) -> SpacingMode {
+        unsafe { (*self.as_ref().data).spacingMode }.into()
+    }
+
+    pub fn rotate_mode(&self) -> RotateMode {
+        unsafe { (*self.as_ref().data).rotateMode }.into()
+    }
+
+    pub(crate) fn as_ref(&self) -> &ffi::spPathConstraint {
+        unsafe { self.inner.as_ref().unwrap() }
+    }
+    pub(crate) fn as_mut(&mut self) -> &mut ffi::spPathConstraint {
+        unsafe { self.inner.as_mut().unwrap() }
+    }
+}
+
+spine_handle_mut!(
+    /// A `PathConstraint` that can also be modified, borrowed from a `&mut Skeleton`.
+    PathConstraintMut,
+    PathConstraint,
+    ffi::spPathConstraint
+);
+impl PathConstraintMut<'_> {
+    /// Replaces the constrained bones. The number of bones cannot change, and
+    /// `Skeleton::update_cache` must be called afterwards.
+    ///
+    /// # Panics
+    /// If `new_bones` does not have the same length as `PathConstraint::bones`, or refers to bones
+    /// outside the constraint's skeleton.
+    pub fn set_bones(&mut self, new_bones: &[BoneIndex]) {
+        let r = self.as_mut();
+        unsafe { set_bones(r.bones, r.bonesCount, new_bones) }
+    }
+
+    /// Sets the slot holding the path. `Skeleton::update_cache` must be called afterwards.
+    ///
+    /// # Panics
+    /// If `target` does not refer to a slot of the constraint's skeleton.
+    pub fn set_target(&mut self, target: SlotIndex) {
+        let r = self.as_mut();
+        r.target = unsafe { slot_ptr((*(*r.target).bone).skeleton, target) };
+    }
+
+    pub fn set_position(&mut self, position: f32) {
+        self.as_mut().position = position;
+    }
+
+    pub fn set_spacing(&mut self, spacing: f32) {
+        self.as_mut().spacing = spacing;
+    }
+
+    pub fn set_rotate_mix(&mut self, mix: f32) {
+        self.as_mut().rotateMix = mix;
+    }
+
+    pub fn set_translate_mix(&mut self, mix: f32) {
+        self.as_mut().translateMix = mix;
+    }
+
+    /// # spine-c
+    /// Maps to calling `spPathConstraint_apply`
+    pub fn apply(&mut self) {
+        unsafe { ffi::spPathConstraint_apply(self.inner) }
+    }
+
+    /// Samples world space points along the target path, starting at `PathConstraint::position`.
+    /// Each entry of `spaces` is the distance from the previous point, interpreted according to the
+    /// position and spacing modes of this constraint. Every sample is returned as `[x, y, rotation]`,
+    /// where the rotation is the path tangent in radians if `tangents` is true.
+    ///
+    /// Returns an empty list if the target slot does not currently show a path attachment. The world
+    /// transforms of the skeleton must be up to date.
+    ///
+    /// # spine-c
+    /// Maps to calling `spPathConstraint_computeWorldPositions`
+    pub fn compute_world_positions(&mut self, spaces: &[f32], tangents: bool) -> Vec<[f32; 3]> {
+        let attachment = unsafe { (*self.as_ref().target).attachment };
+        if spaces.is_empty()
+            || attachment.is_null()
+            || AttachmentType::from(unsafe { (*attachment).type_ }) != AttachmentType::Path
+        {
+            return Vec::new();
+        }
+
+        let (position_mode, spacing_mode) = (self.position_mode(), self.spacing_mode());
+
+        // spine-c reads (and may scale) the spaces from the constraint itself, so lend it a copy.
+        let mut spaces = spaces.to_vec();
+        let count: c_int = spaces.len().try_into().unwrap();
+        // Dropped before `spaces`, restoring the constraint's own spaces first.
+        let _lent = LentSpaces {
+            constraint: self.inner,
+            spaces: self.as_ref().spaces,
+            count: self.as_ref().spacesCount,
+        };
+        let r = self.as_mut();
+        r.spaces = spaces.as_mut_ptr();
+        r.spacesCount = count;
+
+        unsafe {
+            let out = ffi::spPathConstraint_computeWorldPositions(
+                self.inner,
+                attachment.cast(),
+                count,
+                c_int::from(tangents),
+                c_int::from(position_mode == PositionMode::Percent),
+                c_int::from(spacing_mode == SpacingMode::Percent),
+            );
+            std::slice::from_raw_parts(out, spaces.len() * 3)
+                .chunks_exact(3)
+                .map(|p| [p[0], p[1], p[2]])
+                .collect()
+        }
+    }
+}
+
+/// Gives a path constraint back its own spaces when dropped, so it never keeps a pointer to spaces
+/// lent by `PathConstraint::compute_world_positions`, even when unwinding.
+struct LentSpaces {
+    constraint: *mut ffi::spPathConstraint,
+    spaces: *mut f32,
+    count: c_int,
+}
+impl Drop for LentSpaces {
+    fn drop(&mut self) {
+        unsafe {
+            (*self.constraint).spaces = self.spaces;
+            (*self.constraint).spacesCount = self.count;
+        }
+    }
+}
+
+/// Looks up a constraint by name with one of the `spSkeleton_find*Constraint` functions.
+fn find<T>(
+    skeleton: &Skeleton,
+    name: &str,
+    find: unsafe extern "C" fn(*const ffi::spSkeleton, *const std::os::raw::c_char) -> *mut T,
+) -> Option<*mut T> {
+    let c_name = CString::new(name).unwrap();
+    let inner = unsafe { find(skeleton.inner.as_ptr(), c_name.as_ptr()) };
+
+    if inner.is_null() {
+        spine_log!(debug, "no constraint named {:?}", name);
+        None
+    } else {
+        Some(inner)
+    }
+}
+
+/// Converts a spine-c array of constraint pointers into a list of handles.
+fn list<T, H>(constraints: *mut *mut T, count: c_int, handle: impl Fn(*mut T) -> H) -> Vec<H> {
+    (0..count.try_into().unwrap())
+        .map(|n| handle(unsafe { *constraints.add(n) }))
+        .collect()
+}
+
+/// Declares a setup pose constraint handle with the fields every spine-c constraint data shares.
+macro_rules! constraint_data {
+    ($(#[$meta:meta])* $name:ident, $ffi:ty) => {
+        $(#[$meta])*
+        pub struct $name<'a> {
+            pub(crate) inner: &'a $ffi,
+        }
+        impl<'a> $name<'a> {
+            pub fn name(&self) -> &str {
+                unsafe { CStr::from_ptr(self.inner.name) }.to_str().unwrap()
+            }
+
+            /// The position of this constraint in the order constraints and bones are updated.
+            pub fn order(&self) -> i32 {
+                self.inner.order
+            }
+
+            /// Whether this constraint is only active when a skin containing it is set.
+            pub fn skin_required(&self) -> bool {
+                self.inner.skinRequired != 0
+            }
+
+            /// The constrained bones.
+            pub fn bones(&self) -> Vec<BoneData<'a>> {
+                list(self.inner.bones, self.inner.bonesCount, |inner| BoneData {
+                    inner: unsafe { &*inner },
+                })
+            }
+        }
+        impl std::fmt::Debug for $name<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{} {{ name: {} }}", stringify!($name), self.name())
+            }
+        }
+    };
+}
+
+constraint_data!(
+    /// The setup pose of an IK constraint, shared by every `Skeleton` of a `SkeletonData`.
+    IkConstraintData,
+    ffi::spIkConstraintData
+);
+impl<'a> IkConstraintData<'a> {
+    pub fn target(&self) -> BoneData<'a> {
+        BoneData {
+            inner: unsafe { &*self.inner.target },
+        }
+    }
+}
+
+constraint_data!(
+    /// The setup pose of a transform constraint, shared by every `Skeleton` of a `SkeletonData`.
+    TransformConstraintData,
+    ffi::spTransformConstraintData
+);
+impl<'a> TransformConstraintData<'a> {
+    pub fn target(&self) -> BoneData<'a> {
+        BoneData {
+            inner: unsafe { &*self.inner.target },
+        }
+    }
+}
+
+constraint_data!(
+    /// The setup pose of a path constraint, shared by every `Skeleton` of a `SkeletonData`.
+    PathConstraintData,
+    ffi::spPathConstraintData
+);
+impl<'a> PathConstraintData<'a> {
+    /// The slot holding the path attachment the bones are constrained to.
+    pub fn target(&self) -> SlotData<'a> {
+        SlotData {
+            inner: unsafe { &*self.inner.target },
+        }
+    }
+}
+
+impl SkeletonData {
+    pub fn ik_constraints(&self) -> Vec<IkConstraintData<'_>> {
+        let r = self.as_ref();
+        list(r.ikConstraints, r.ikConstraintsCount, |inner| {
+            IkConstraintData {
+                inner: unsafe { &*inner },
+            }
+        })
+    }
+
+    pub fn transform_constraints(&self) -> Vec<TransformConstraintData<'_>> {
+        let r = self.as_ref();
+        list(
+            r.transformConstraints,
+            r.transformConstraintsCount,
+            |inner| TransformConstraintData {
+                inner: unsafe { &*inner },
+            },
+        )
+    }
+
+    pub fn path_constraints(&self) -> Vec<PathConstraintData<'_>> {
+        let r = self.as_ref();
+        list(r.pathConstraints, r.pathConstraintsCount, |inner| {
+            PathConstraintData {
+                inner: unsafe { &*inner },
+            }
+        })
+    }
+}
+
+impl Skeleton {
+    pub fn ik_constraints(&self) -> Vec<IkConstraint<'_>> {
+        let r = self.inner.as_ref();
+        list(r.ikConstraints, r.ikConstraintsCount, |inner| {
+            IkConstraint {
+                inner,
+                _lifetime: PhantomData,
+            }
+        })
+    }
+
+    /// # spine-c
+    /// Maps to calling `spSkeleton_findIkConstraint`
+    pub fn find_ik_constraint(&self, name: &str) -> Option<IkConstraint<'_>> {
+        find(self, name, ffi::spSkeleton_findIkConstraint).map(|inner| IkConstraint {
+            inner,
+            _lifetime: PhantomData,
+        })
+    }
+
+    /// Finds an IK constraint by name, for modifying it.
+    ///
+    /// # spine-c
+    /// Maps to calling `spSkeleton_findIkConstraint`
+    pub fn find_ik_constraint_mut(&mut self, name: &str) -> Option<IkConstraintMut<'_>> {
+        find(self, name, ffi::spSkeleton_findIkConstraint).map(IkConstraintMut::new)
+    }
+
+    pub fn transform_constraints(&self) -> Vec<TransformConstraint<'_>> {
+        let r = self.inner.as_ref();
+        list(
+            r.transformConstraints,
+            r.transformConstraintsCount,
+            |inner| TransformConstraint {
+                inner,
+                _lifetime: PhantomData,
+            },
+        )
+    }
+
+    /// # spine-c
+    /// Maps to calling `spSkeleton_findTransformConstraint`
+    pub fn find_transform_constraint(&self, name: &str) -> Option<TransformConstraint<'_>> {
+        find(self, name, ffi::spSkeleton_findTransformConstraint).map(|inner| TransformConstraint {
+            inner,
+            _lifetime: PhantomData,
+        })
+    }
+
+    /// Finds a transform constraint by name, for modifying it.
+    ///
+    /// # spine-c
+    /// Maps to calling `spSkeleton_findTransformConstraint`
+    pub fn find_transform_constraint_mut(
+        &mut self,
+        name: &str,
+    ) -> Option<TransformConstraintMut<'_>> {
+        find(self, name, ffi::spSkeleton_findTransformConstraint).map(TransformConstraintMut::new)
+    }
+
+    pub fn path_constraints(&self) -> Vec<PathConstraint<'_>> {
+        let r = self.inner.as_ref();
+        list(r.pathConstraints, r.pathConstraintsCount, |inner| {
+            PathConstraint {
+                inner,
+                _lifetime: PhantomData,
+            }
+        })
+    }
+
+    /// # spine-c
+    /// Maps to calling `spSkeleton_findPathConstraint`
+    pub fn find_path_constraint(&self, name: &str) -> Option<PathConstraint<'_>> {
+        find(self, name, ffi::spSkeleton_findPathConstraint).map(|inner| PathConstraint {
+            inner,
+            _lifetime: PhantomData,
+        })
+    }
+
+    /// Finds a path constraint by name, for modifying it.
+    ///
+    /// # spine-c
+    /// Maps to calling `spSkeleton_findPathConstraint`
+    pub fn find_path_constraint_mut(&mut self, name: &str) -> Option<PathConstraintMut<'_>> {
+        find(self, name, ffi::spSkeleton_findPathConstraint).map(PathConstraintMut::new)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::tests::skeleton_data_from_json;
+
+    const IK_JSON: &str = r#"{
+        "skeleton": { "hash": "ik", "spine": "3.8.55" },
+        "bones": [
+            { "name": "root" },
+            { "name": "upper", "parent": "root", "length": 100 },
+            { "name": "lower", "parent": "upper", "length": 100, "x": 100 },
+            { "name": "target", "parent": "root", "x": 150, "y": 50 },
+            { "name": "cursor", "parent": "root", "x": -50, "y": 120 }
+        ],
+        "ik": [
+            { "name": "arm", "bones": [ "upper", "lower" ], "target": "target", "bendPositive": false }
+        ]
+    }"#;
+
+    fn distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+        (a[0] - b[0]).hypot(a[1] - b[1])
+    }
+
+    fn world_position(skeleton: &Skeleton, bone: &str) -> [f32; 2] {
+        skeleton.find_bone(bone).unwrap().world_position()
+    }
+
+    fn tip(skeleton: &Skeleton) -> [f32; 2] {
+        let lower = skeleton.find_bone("lower").unwrap();
+        lower.local_to_world([lower.length(), 0.0])
+    }
+
+    #[test]
+    fn find_and_modify() {
+        let skeleton_data = skeleton_data_from_json(IK_JSON);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        assert!(skeleton.find_ik_constraint("missing").is_none());
+        assert_eq!(1, skeleton.ik_constraints().len());
+
+        let ik = skeleton.find_ik_constraint("arm").unwrap();
+        assert_eq!("arm", ik.name());
+        assert_eq!(-1, ik.bend_direction());
+        assert!((ik.mix() - 1.0).abs() < f32::EPSILON);
+        assert_eq!("target", ik.target().name());
+        assert_eq!(
+            vec!["upper", "lower"],
+            ik.bones().iter().map(Bone::name).collect::<Vec<_>>()
+        );
+
+        assert!(distance(tip(&skeleton), [150.0, 50.0]) < 0.01);
+
+        let cursor = skeleton.bone_index("cursor");
+        let mut ik = skeleton.find_ik_constraint_mut("arm").unwrap();
+        ik.set_bend_direction(1);
+        ik.set_stretch(true);
+        ik.set_softness(5.0);
+        assert!(ik.stretch());
+        assert!((ik.softness() - 5.0).abs() < f32::EPSILON);
+
+        ik.set_target(cursor);
+        skeleton.update_cache();
+        skeleton.update_world_transforms();
+        assert!(distance(tip(&skeleton), [-50.0, 120.0]) < 0.01);
+
+        let mut ik = skeleton.find_ik_constraint_mut("arm").unwrap();
+        ik.set_target_world_position([10.0, -150.0]);
+        skeleton.update_world_transforms();
+        assert!(distance(tip(&skeleton), [10.0, -150.0]) < 0.01);
+
+        let mut ik = skeleton.find_ik_constraint_mut("arm").unwrap();
+        ik.set_mix(0.0);
+        skeleton.update_world_transforms();
+        assert!(distance(tip(&skeleton), [200.0, 0.0]) < 0.01);
+    }
+
+    #[test]
+    fn solve_toward_point() {
+        let skeleton_data = skeleton_data_from_json(IK_JSON);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        skeleton
+            .find_ik_constraint_mut("arm")
+            .unwrap()
+            .solve_toward([20.0, 140.0]);
+        assert!(distance(tip(&skeleton), [20.0, 140.0]) < 0.01);
+
+        let mut cursor = skeleton.find_bone_mut("cursor").unwrap();
+        IkConstraint::apply1(&mut cursor, [-50.0, 220.0], false, false, false, 1.0);
+        assert!((cursor.world_rotation() - 90.0).abs() < 0.01);
+
+        let (upper, lower) = (skeleton.bone_index("upper"), skeleton.bone_index("lower"));
+        skeleton.find_ik_constraint_mut("arm").unwrap().set_mix(0.0);
+        skeleton.update_world_transforms();
+        IkConstraint::apply2(
+            &mut skeleton,
+            upper,
+            lower,
+            [0.0, 150.0],
+            1,
+            false,
+            0.0,
+            1.0,
+        );
+        assert!(distance(tip(&skeleton), [0.0, 150.0]) < 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "root bone")]
+    fn apply1_rejects_root() {
+        let skeleton_data = skeleton_data_from_json(IK_JSON);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+        let mut root = skeleton.find_bone_mut("root").unwrap();
+        IkConstraint::apply1(&mut root, [0.0, 100.0], false, false, false, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be a child of its parent")]
+    fn apply2_rejects_unrelated_bones() {
+        let skeleton_data = skeleton_data_from_json(IK_JSON);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+        let (upper, cursor) = (skeleton.bone_index("upper"), skeleton.bone_index("cursor"));
+        IkConstraint::apply2(
+            &mut skeleton,
+            upper,
+            cursor,
+            [0.0, 150.0],
+            1,
+            false,
+            0.0,
+            1.0,
+        );
+    }
+
+    const TRANSFORM_JSON: &str = r#"{
+        "skeleton": { "hash": "transform", "spine": "3.8.55" },
+        "bones": [
+            { "name": "root" },
+            { "name": "follower", "parent": "root", "x": 10 },
+            { "name": "leader", "parent": "root", "x": 100, "y": 50 },
+            { "name": "other", "parent": "root", "x": -30, "y": -40 }
+        ],
+        "transform": [
+            { "name": "follow", "bones": [ "follower" ], "target": "leader", "x": 5 }
+        ]
+    }"#;
+
+    const PATH_JSON: &str = r#"{
+        "skeleton": { "hash": "path", "spine": "3.8.55" },
+        "bones": [ { "name": "root" }, { "name": "car", "parent": "root" } ],
+        "slots": [ { "name": "road", "bone": "root", "attachment": "road" } ],
+        "skins": [ { "name": "default", "attachments": {
+            "road": { "road": { "type": "path", "vertexCount": 6, "lengths": [ 300, 300 ],
+                                "vertices": [ -100, 0, 0, 0, 100, 0, 200, 0, 300, 0, 400, 0 ] } }
+        } } ],
+        "path": [
+            { "name": "drive", "bones": [ "car" ], "target": "road", "position": 0.5,
+              "translateMix": 1, "rotateMix": 0 }
+        ]
+    }"#;
+
+    #[test]
+    fn transform_constraint() {
+        let skeleton_data = skeleton_data_from_json(TRANSFORM_JSON);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        assert!(skeleton.find_transform_constraint("missing").is_none());
+        assert_eq!(1, skeleton.transform_constraints().len());
+
+        let constraint = skeleton.find_transform_constraint("follow").unwrap();
+        assert_eq!("follow", constraint.name());
+        assert_eq!("leader", constraint.target().name());
+        assert_eq!([0.0, 5.0, 0.0, 0.0, 0.0, 0.0], constraint.offsets());
+        assert!(!constraint.relative());
+        assert!(!constraint.local());
+
+        assert!(distance(world_position(&skeleton, "follower"), [105.0, 50.0]) < 0.01);
+
+        let mut constraint = skeleton.find_transform_constraint_mut("follow").unwrap();
+        constraint.set_translate_mix(0.0);
+        assert!(constraint.translate_mix().abs() < f32::EPSILON);
+        skeleton.update_world_transforms();
+        assert!(distance(world_position(&skeleton, "follower"), [10.0, 0.0]) < 0.01);
+
+        let other = skeleton.bone_index("other");
+        let mut constraint = skeleton.find_transform_constraint_mut("follow").unwrap();
+        constraint.set_translate_mix(1.0);
+        constraint.set_target(other);
+        skeleton.update_cache();
+        skeleton.update_world_transforms();
+        assert!(distance(world_position(&skeleton, "follower"), [-25.0, -40.0]) < 0.01);
+    }
+
+    #[test]
+    fn path_constraint() {
+        let skeleton_data = skeleton_data_from_json(PATH_JSON);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        assert!(skeleton.find_path_constraint("missing").is_none());
+        assert_eq!(1, skeleton.path_constraints().len());
+
+        let constraint = skeleton.find_path_constraint("drive").unwrap();
+        assert_eq!("drive", constraint.name());
+        assert_eq!("road", constraint.target().name());
+        assert_eq!(PositionMode::Percent, constraint.position_mode());
+        assert_eq!(SpacingMode::Length, constraint.spacing_mode());
+        assert_eq!(RotateMode::Tangent, constraint.rotate_mode());
+        assert_eq!(
+            vec!["car"],
+            constraint
+                .bones()
+                .iter()
+                .map(Bone::name)
+                .collect::<Vec<_>>()
+        );
+
+        assert!(distance(world_position(&skeleton, "car"), [150.0, 0.0]) < 0.01);
+
+        let mut constraint = skeleton.find_path_constraint_mut("drive").unwrap();
+        constraint.set_position(0.25);
+        skeleton.update_world_transforms();
+        assert!(distance(world_position(&skeleton, "car"), [75.0, 0.0]) < 0.01);
+
+        let mut constraint = skeleton.find_path_constraint_mut("drive").unwrap();
+        constraint.set_position(0.5);
+        let own_spaces = (constraint.as_ref().spaces, constraint.as_ref().spacesCount);
+        let points = constraint.compute_world_positions(&[0.0, 50.0, 50.0], true);
+        assert_eq!(
+            own_spaces,
+            (constraint.as_ref().spaces, constraint.as_ref().spacesCount)
+        );
+        assert_eq!(3, points.len());
+        for (point, x) in points.iter().zip(&[150.0, 200.0, 250.0]) {
+            assert!(distance([point[0], point[1]], [*x, 0.0]) < 0.01);
+            assert!(point[2].abs() < 0.01);
+        }
+        assert!(constraint.compute_world_positions(&[], true).is_empty());
+    }
+}
@@ -1,11 +1,16 @@
-use crate::ffi::*;
+use crate::ffi::{
+    spAtlasFilter, spAtlasFormat, spAtlasWrap, spAttachmentType, spBlendMode, spEventType,
+    spMixBlend, spPositionMode, spRotateMode, spSpacingMode, spTransformMode,
+};
 
 macro_rules! spine_enum {
-    ($primitive:tt, $rust_name:ident, $ffi_name:ident, $($name:ident = $value:literal, )+) => {
+    ($(#[$meta:meta])* $primitive:ident, $rust_name:ident, $ffi_name:ident, $($(#[$variant_meta:meta])* $name:ident = $value:literal, )+) => {
+        $(#[$meta])*
         #[repr($primitive)]
         #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub enum $rust_name {
             $(
+                $(#[$variant_meta])*
                 $name = $value,
             )+
         }
@@ -50,13 +55,71 @@ spine_enum! { u32, AtlasWrap, spAtlasWrap,
     Repeat = 2,
 }
 
-spine_enum! { u32, BlendMode, spBlendMode,
+spine_enum! {
+    #[derive(Default)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(rename_all = "camelCase")
+    )]
+    u32, BlendMode, spBlendMode,
+    #[default]
     Normal = 0,
     Additive = 1,
     Multiply = 2,
     Screen = 3,
 }
 
+/// A factor of a blend equation, named as in `glBlendFuncSeparate`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BlendFactor {
+    One,
+    SrcAlpha,
+    OneMinusSrcAlpha,
+    DstColor,
+    OneMinusSrcColor,
+}
+
+/// The blend factors for drawing with a `BlendMode`: the framebuffer becomes
+/// `source * src + destination * dst`, with separate factors for the color and alpha channels.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BlendFunc {
+    pub src_color: BlendFactor,
+    pub dst_color: BlendFactor,
+    pub src_alpha: BlendFactor,
+    pub dst_alpha: BlendFactor,
+}
+
+impl BlendMode {
+    /// The blend factors spine-runtimes use for this mode.
+    ///
+    /// With `premultiplied_alpha`, textures and vertex colors must both be premultiplied, see
+    /// `AtlasPage::premultiplied_alpha` and `Color::premultiplied`. Drawing a premultiplied atlas
+    /// with straight alpha factors darkens the edges of every image.
+    pub fn blend_func(self, premultiplied_alpha: bool) -> BlendFunc {
+        use BlendFactor::{DstColor, One, OneMinusSrcAlpha, OneMinusSrcColor, SrcAlpha};
+
+        let src = if premultiplied_alpha { One } else { SrcAlpha };
+        let (src_color, dst_color, src_alpha, dst_alpha) = match self {
+            Self::Normal => (src, OneMinusSrcAlpha, One, OneMinusSrcAlpha),
+            Self::Additive => (src, One, One, One),
+            Self::Multiply => (
+                DstColor,
+                OneMinusSrcAlpha,
+                OneMinusSrcAlpha,
+                OneMinusSrcAlpha,
+            ),
+            Self::Screen => (One, OneMinusSrcColor, OneMinusSrcColor, OneMinusSrcColor),
+        };
+        BlendFunc {
+            src_color,
+            dst_color,
+            src_alpha,
+            dst_alpha,
+        }
+    }
+}
+
 spine_enum! { u32, AttachmentType, spAttachmentType,
     Region = 0,
     BoundingBox = 1,
@@ -67,8 +130,87 @@ spine_enum! { u32, AttachmentType, spAttachmentType,
     Clipping = 6,
 }
 
-#[repr(u32)]
-pub enum EventType {
+spine_enum! {
+    #[derive(Default)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(rename_all = "camelCase")
+    )]
+    u32, TransformMode, spTransformMode,
+    #[default]
+    Normal = 0,
+    OnlyTranslation = 1,
+    NoRotationOrReflection = 2,
+    NoScale = 3,
+    NoScaleOrReflection = 4,
+}
+
+spine_enum! {
+    #[derive(Default)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(rename_all = "camelCase")
+    )]
+    u32, PositionMode, spPositionMode,
+    Fixed = 0,
+    #[default]
+    Percent = 1,
+}
+
+spine_enum! {
+    #[derive(Default)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(rename_all = "camelCase")
+    )]
+    u32, SpacingMode, spSpacingMode,
+    #[default]
+    Length = 0,
+    Fixed = 1,
+    Percent = 2,
+}
+
+spine_enum! {
+    #[derive(Default)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(rename_all = "camelCase")
+    )]
+    u32, RotateMode, spRotateMode,
+    #[default]
+    Tangent = 0,
+    Chain = 1,
+    ChainScale = 2,
+}
+
+spine_enum! {
+    /// How a track entry's animation is mixed with the pose of the tracks below it.
+    #[derive(Default)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(rename_all = "camelCase")
+    )]
+    u32, MixBlend, spMixBlend,
+    Setup = 0,
+    First = 1,
+    #[default]
+    Replace = 2,
+    Add = 3,
+}
+
+spine_enum! {
+    /// The kind of an `AnimationEvent` raised by an `AnimationState`.
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(rename_all = "camelCase")
+    )]
+    u32, EventType, spEventType,
     Start = 0,
     Interrupt = 1,
     End = 2,
@@ -76,9 +218,3 @@ pub enum EventType {
     Dispose = 4,
     Event = 5,
 }
-//pub const spEventType_SP_ANIMATION_START: spEventType = 0;
-// pub const spEventType_SP_ANIMATION_INTERRUPT: spEventType = 1;
-// pub const spEventType_SP_ANIMATION_END: spEventType = 2;
-// pub const spEventType_SP_ANIMATION_COMPLETE: spEventType = 3;
-// pub const spEventType_SP_ANIMATION_DISPOSE: spEventType = 4;
-// pub const spEventType_SP_ANIMATION_EVENT: spEventType = 5;
@@ -0,0 +1,258 @@
+use crate::ffi;
+use std::{
+    alloc::Layout,
+    cell::RefCell,
+    collections::HashMap,
+    os::raw::{c_char, c_int, c_void},
+    sync::{Mutex, MutexGuard, Once},
+};
+
+/// Alignment of every block handed to spine-c, matching what `malloc` guarantees on 64 bit targets.
+const ALIGN: usize = 16;
+
+/// Counters for the memory spine-c allocated through the Rust allocator.
+///
+/// Allocations made before `use_rust_allocator` was called are neither counted nor affected.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// Number of blocks allocated, including blocks moved by `realloc`.
+    pub allocations: usize,
+    /// Number of blocks freed, including blocks moved by `realloc`.
+    pub frees: usize,
+    /// Number of blocks currently allocated.
+    pub live_allocations: usize,
+    /// Bytes currently allocated.
+    pub live_bytes: usize,
+    /// The highest `live_bytes` seen so far.
+    pub peak_bytes: usize,
+}
+
+#[derive(Default)]
+struct Allocations {
+    sizes: HashMap<usize, usize>,
+    stats: AllocationStats,
+}
+impl Allocations {
+    fn insert(&mut self, ptr: *mut u8, size: usize) {
+        self.sizes.insert(ptr as usize, size);
+        self.stats.allocations += 1;
+        self.stats.live_allocations += 1;
+        self.stats.live_bytes += size;
+        self.stats.peak_bytes = self.stats.peak_bytes.max(self.stats.live_bytes);
+    }
+
+    fn remove(&mut self, ptr: *mut c_void) -> Option<usize> {
+        let size = self.sizes.remove(&(ptr as usize))?;
+        self.stats.frees += 1;
+        self.stats.live_allocations -= 1;
+        self.stats.live_bytes -= size;
+        Some(size)
+    }
+}
+
+static ALLOCATIONS: Mutex<Option<Allocations>> = Mutex::new(None);
+static INSTALL_ALLOCATOR: Once = Once::new();
+
+fn allocations() -> MutexGuard<'static, Option<Allocations>> {
+    ALLOCATIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+fn layout(size: usize) -> Option<Layout> {
+    Layout::from_size_align(size.max(1), ALIGN).ok()
+}
+
+// Blocks spine-c got from libc before the hooks were installed are still released by libc.
+extern "C" {
+    fn realloc(ptr: *mut c_void, size: usize) -> *mut c_void;
+    fn free(ptr: *mut c_void);
+}
+
+unsafe extern "C" fn malloc_hook(size: usize) -> *mut c_void {
+    let Some(layout) = layout(size) else {
+        return std::ptr::null_mut();
+    };
+
+    let ptr = std::alloc::alloc(layout);
+    if !ptr.is_null() {
+        allocations()
+            .get_or_insert_with(Default::default)
+            .insert(ptr, size);
+    }
+    ptr.cast()
+}
+
+unsafe extern "C" fn debug_malloc_hook(
+    size: usize,
+    _file: *const c_char,
+    _line: c_int,
+) -> *mut c_void {
+    malloc_hook(size)
+}
+
+unsafe extern "C" fn realloc_hook(ptr: *mut c_void, size: usize) -> *mut c_void {
+    if ptr.is_null() {
+        return malloc_hook(size);
+    }
+
+    let mut guard = allocations();
+    let allocations = guard.get_or_insert_with(Default::default);
+    let Some(old_size) = allocations.remove(ptr) else {
+        return realloc(ptr, size);
+    };
+
+    let new = layout(size).map_or(std::ptr::null_mut(), |_| {
+        std::alloc::realloc(ptr.cast(), layout(old_size).unwrap(), size.max(1))
+    });
+    if new.is_null() {
+        // The old block is untouched on failure, so keep tracking it.
+        allocations.insert(ptr.cast(), old_size);
+    } else {
+        allocations.insert(new, size);
+    }
+    new.cast()
+}
+
+unsafe extern "C" fn free_hook(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+
+    let size = allocations()
+        .get_or_insert_with(Default::default)
+        .remove(ptr);
+    match size {
+        Some(size) => std::alloc::dealloc(ptr.cast(), layout(size).unwrap()),
+        None => free(ptr),
+    }
+}
+
+/// Routes every spine-c allocation through Rust's global allocator, so it shows up in
+/// `allocation_stats` and in any instrumentation of the `#[global_allocator]`.
+///
+/// The hooks are process wide and stay installed; calling this again does nothing.
+///
+/// # Safety
+/// spine-c reads its allocation hooks without synchronization, so this must be called before any
+/// spine object is created, such as at startup, and while no other thread uses spine.
+///
+/// # spine-c
+/// Maps to calling `_spSetMalloc`, `_spSetRealloc`, `_spSetDebugMalloc` and `_spSetFree`
+pub unsafe fn use_rust_allocator() {
+    INSTALL_ALLOCATOR.call_once(|| unsafe {
+        allocations().get_or_insert_with(Default::default);
+
+        ffi::_spSetMalloc(Some(malloc_hook));
+        ffi::_spSetRealloc(Some(realloc_hook));
+        ffi::_spSetDebugMalloc(Some(debug_malloc_hook));
+        ffi::_spSetFree(Some(free_hook));
+    });
+}
+
+/// Returns the allocation counters, or `None` if `use_rust_allocator` has not been called.
+pub fn allocation_stats() -> Option<AllocationStats> {
+    allocations().as_ref().map(|allocations| allocations.stats)
+}
+
+/// A `SplitMix64` generator, used by `seed_random` so replays do not depend on libc's `rand`.
+struct SplitMix64(u64);
+impl SplitMix64 {
+    #[allow(clippy::cast_precision_loss)]
+    fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        // The top 24 bits fill an f32 mantissa exactly, giving a value in [0, 1).
+        (z >> 40) as f32 / (1 << 24) as f32
+    }
+}
+
+thread_local! {
+    static RANDOM: RefCell<Option<Box<dyn FnMut() -> f32>>> = RefCell::new(None);
+}
+static INSTALL_RANDOM: Once = Once::new();
+
+unsafe extern "C" fn random_hook() -> f32 {
+    RANDOM
+        .try_with(|random| match random.try_borrow_mut() {
+            Ok(mut random) => random.as_mut().map(|random| random()),
+            Err(_) => None,
+        })
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| ffi::_spInternalRandom())
+}
+
+/// Replaces the random number generator spine-c uses on the current thread, for example for
+/// `JitterVertexEffect`. `random` must return values in the range 0-1.
+///
+/// Generators are per thread, so a replay stays deterministic no matter what other threads do.
+/// Threads without a generator keep using spine-c's default, which is based on libc's `rand`.
+///
+/// # spine-c
+/// Maps to calling `_spSetRandom`
+pub fn set_random<F>(random: F)
+where
+    F: FnMut() -> f32 + 'static,
+{
+    INSTALL_RANDOM.call_once(|| unsafe { ffi::_spSetRandom(Some(random_hook)) });
+    RANDOM.with(|r| *r.borrow_mut() = Some(Box::new(random)));
+}
+
+/// Seeds a deterministic random number generator for spine-c on the current thread. The same seed
+/// always produces the same sequence, on every platform.
+pub fn seed_random(seed: u64) {
+    let mut rng = SplitMix64(seed);
+    set_random(move || rng.next());
+}
+
+/// Restores spine-c's default random number generator on the current thread.
+pub fn reset_random() {
+    RANDOM.with(|r| *r.borrow_mut() = None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        atlas::Atlas,
+        skeleton::{Skeleton, SkeletonData, Slot},
+        tests::TEST_CASES,
+        vertex_effect::JitterVertexEffect,
+    };
+
+    #[test]
+    fn seeded_jitter() {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        let skeleton_data =
+            unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas) }.unwrap();
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        let jittered = |seed| {
+            seed_random(seed);
+            skeleton.world_vertices_with_effect(&mut JitterVertexEffect::new(5.0, 5.0))
+        };
+        let first = jittered(1);
+        assert_eq!(first, jittered(1));
+        assert_ne!(first, jittered(2));
+        reset_random();
+
+        set_random(|| 0.5);
+        // A triangular distribution sampled at its midpoint does not move anything.
+        assert_eq!(
+            skeleton
+                .draw_slots()
+                .iter()
+                .map(Slot::world_vertices)
+                .collect::<Vec<_>>(),
+            skeleton.world_vertices_with_effect(&mut JitterVertexEffect::new(5.0, 5.0))
+        );
+        reset_random();
+    }
+}
@@ -7,3 +7,37 @@
 )]
 
 include!("spine_bindings.rs");
+
+// extension.h declarations that bindgen does not pick up from spine.h.
+extern "C" {
+    pub fn _spSetMalloc(
+        malloc: Option<unsafe extern "C" fn(size: usize) -> *mut ::std::os::raw::c_void>,
+    );
+    pub fn _spSetRealloc(
+        realloc: Option<
+            unsafe extern "C" fn(
+                ptr: *mut ::std::os::raw::c_void,
+                size: usize,
+            ) -> *mut ::std::os::raw::c_void,
+        >,
+    );
+    pub fn _spSetDebugMalloc(
+        malloc: Option<
+            unsafe extern "C" fn(
+                size: usize,
+                file: *const ::std::os::raw::c_char,
+                line: ::std::os::raw::c_int,
+            ) -> *mut ::std::os::raw::c_void,
+        >,
+    );
+    pub fn _spSetFree(free: Option<unsafe extern "C" fn(ptr: *mut ::std::os::raw::c_void)>);
+    pub fn _spSetRandom(random: Option<unsafe extern "C" fn() -> f32>);
+    pub fn _spInternalRandom() -> f32;
+    pub fn _spCalloc(
+        num: usize,
+        size: usize,
+        file: *const ::std::os::raw::c_char,
+        line: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_void;
+    pub fn _spFree(ptr: *mut ::std::os::raw::c_void);
+}
@@ -0,0 +1,482 @@
+use crate::{
+    enums::{AtlasFilter, AtlasFormat, AtlasWrap},
+    SpineError,
+};
+use std::{fmt, path::Path, str::FromStr};
+
+/// A texture page of an `AtlasData`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageData {
+    /// The image file name, relative to the atlas file.
+    pub name: String,
+    /// The image size in pixels, or 0 if the atlas does not record it.
+    pub width: i32,
+    pub height: i32,
+    pub format: AtlasFormat,
+    pub min_filter: AtlasFilter,
+    pub mag_filter: AtlasFilter,
+    pub u_wrap: AtlasWrap,
+    pub v_wrap: AtlasWrap,
+    /// Whether the page image has premultiplied alpha.
+    pub pma: bool,
+}
+impl PageData {
+    /// Creates a page with the defaults used for fields an atlas file leaves out.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            width: 0,
+            height: 0,
+            format: AtlasFormat::RGBA8888,
+            min_filter: AtlasFilter::Nearest,
+            mag_filter: AtlasFilter::Nearest,
+            u_wrap: AtlasWrap::ClampToEdge,
+            v_wrap: AtlasWrap::ClampToEdge,
+            pma: false,
+        }
+    }
+}
+
+/// An image packed into a page of an `AtlasData`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionData {
+    pub name: String,
+    /// The index of the page in `AtlasData::pages`.
+    pub page: usize,
+    /// The top left corner of the packed image on the page.
+    pub x: i32,
+    pub y: i32,
+    /// The size of the packed image, before rotation and without whitespace stripped by packing.
+    pub width: i32,
+    pub height: i32,
+    /// How far the image was rotated counter clockwise when packed: 0 or 90 for most packers.
+    pub degrees: i32,
+    /// The size of the image before whitespace was stripped.
+    pub original_width: i32,
+    pub original_height: i32,
+    /// The position of the packed image in the original image, from the bottom left.
+    pub offset_x: i32,
+    pub offset_y: i32,
+    /// The nine patch split lines: left, right, top and bottom.
+    pub splits: Option<[i32; 4]>,
+    /// The nine patch content padding: left, right, top and bottom.
+    pub pads: Option<[i32; 4]>,
+    /// The frame number for images named `name_<index>`, or -1.
+    pub index: i32,
+}
+impl RegionData {
+    /// Creates a region with the defaults used for fields an atlas file leaves out.
+    pub fn new(name: &str, page: usize) -> Self {
+        Self {
+            name: name.to_owned(),
+            page,
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+            degrees: 0,
+            original_width: 0,
+            original_height: 0,
+            offset_x: 0,
+            offset_y: 0,
+            splits: None,
+            pads: None,
+            index: -1,
+        }
+    }
+
+    /// A region without an `orig` line was not stripped, so its original size is its packed size.
+    fn fill_original_size(&mut self) {
+        self.original_width = self.width;
+        self.original_height = self.height;
+    }
+
+    /// Whether the image was packed rotated by 90 degrees.
+    pub fn rotate(&self) -> bool {
+        self.degrees == 90
+    }
+}
+
+/// The contents of a `.atlas` file, parsed and written without spine-c.
+///
+/// Parsing accepts the keys of a page or region in any order, while writing uses the order
+/// spine-c expects, so a parsed atlas written back matches the file the Spine editor exported.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AtlasData {
+    pub pages: Vec<PageData>,
+    /// Every region, in file order.
+    pub regions: Vec<RegionData>,
+}
+
+// The names spine-c uses in atlas files, in `AtlasFilter` order.
+const FILTERS: [(&str, AtlasFilter); 8] = [
+    ("", AtlasFilter::Unknown),
+    ("Nearest", AtlasFilter::Nearest),
+    ("Linear", AtlasFilter::Linear),
+    ("MipMap", AtlasFilter::Mipmap),
+    ("MipMapNearestNearest", AtlasFilter::MipmapNereastNearest),
+    ("MipMapLinearNearest", AtlasFilter::MipmapLinearNearest),
+    ("MipMapNearestLinear", AtlasFilter::MipmapNearestLinear),
+    ("MipMapLinearLinear", AtlasFilter::MipmapLinearLinear),
+];
+
+const FORMATS: [(&str, AtlasFormat); 8] = [
+    ("", AtlasFormat::Unknown),
+    ("Alpha", AtlasFormat::Alpha),
+    ("Intensity", AtlasFormat::Intensity),
+    ("LuminanceAlpha", AtlasFormat::LuminanceAlpha),
+    ("RGB565", AtlasFormat::RGB565),
+    ("RGBA4444", AtlasFormat::RGBA4444),
+    ("RGB888", AtlasFormat::RGB888),
+    ("RGBA8888", AtlasFormat::RGBA8888),
+];
+
+fn filter_name(filter: AtlasFilter) -> &'static str {
+    FILTERS.iter().find(|(_, f)| *f == filter).unwrap().0
+}
+
+fn format_name(format: AtlasFormat) -> &'static str {
+    FORMATS.iter().find(|(_, f)| *f == format).unwrap().0
+}
+
+/// Parses the comma separated integers of a `key: a, b` line.
+fn ints<const N: usize>(line: usize, key: &str, value: &str) -> Result<[i32; N], SpineError> {
+    let error = || SpineError::FailLoadAtlas(format!("line {line}: invalid {key} {value:?}"));
+    let mut result = [0; N];
+    let mut values = value.split(',');
+    for slot in &mut result {
+        *slot = values
+            .next()
+            .and_then(|v| v.trim().parse().ok())
+            .ok_or_else(error)?;
+    }
+    if values.next().is_some() {
+        return Err(error());
+    }
+    Ok(result)
+}
+
+fn parse_page(line: usize, page: &mut PageData, key: &str, value: &str) -> Result<(), SpineError> {
+    let error = || SpineError::FailLoadAtlas(format!("line {line}: invalid {key} {value:?}"));
+    match key {
+        "size" => [page.width, page.height] = ints(line, key, value)?,
+        "format" => {
+            page.format = FORMATS
+                .iter()
+                .find(|(name, _)| *name == value)
+                .ok_or_else(error)?
+                .1;
+        }
+        "filter" => {
+            let (min, mag) = value.split_once(',').ok_or_else(error)?;
+            let filter = |name: &str| {
+                FILTERS
+                    .iter()
+                    .find(|(n, _)| *n == name.trim())
+                    .map(|(_, filter)| *filter)
+                    .ok_or_else(error)
+            };
+            page.min_filter = filter(min)?;
+            page.mag_filter = filter(mag)?;
+        }
+        "repeat" => {
+            let (u, v) = match value {
+                "none" => (false, false),
+                "x" => (true, false),
+                "y" => (false, true),
+                "xy" => (true, true),
+                _ => return Err(error()),
+            };
+            let wrap = |repeat| {
+                if repeat {
+                    AtlasWrap::Repeat
+                } else {
+                    AtlasWrap::ClampToEdge
+                }
+            };
+            page.u_wrap = wrap(u);
+            page.v_wrap = wrap(v);
+        }
+        "pma" => page.pma = value == "true",
+        _ => spine_log!(debug, "line {}: ignoring page key {:?}", line, key),
+    }
+    Ok(())
+}
+
+fn parse_region(
+    line: usize,
+    region: &mut RegionData,
+    key: &str,
+    value: &str,
+) -> Result<(), SpineError> {
+    match key {
+        "rotate" => {
+            region.degrees = match value {
+                "true" => 90,
+                "false" => 0,
+                _ => ints::<1>(line, key, value)?[0],
+            };
+        }
+        "xy" => [region.x, region.y] = ints(line, key, value)?,
+        "size" => [region.width, region.height] = ints(line, key, value)?,
+        "orig" => [region.original_width, region.original_height] = ints(line, key, value)?,
+        "offset" => [region.offset_x, region.offset_y] = ints(line, key, value)?,
+        "split" => region.splits = Some(ints(line, key, value)?),
+        "pad" => region.pads = Some(ints(line, key, value)?),
+        "index" => region.index = ints::<1>(line, key, value)?[0],
+        _ => spine_log!(debug, "line {}: ignoring region key {:?}", line, key),
+    }
+    Ok(())
+}
+
+impl FromStr for AtlasData {
+    type Err = SpineError;
+
+    /// Parses the text of a `.atlas` file.
+    fn from_str(text: &str) -> Result<Self, SpineError> {
+        let mut data = Self::default();
+        let mut in_page = false;
+        let mut in_region = false;
+        // Whether the open region had an `orig` line.
+        let mut has_original = false;
+
+        for (n, line) in text.lines().enumerate() {
+            let line_number = n + 1;
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                if in_region && !has_original {
+                    data.regions.last_mut().unwrap().fill_original_size();
+                }
+                in_page = false;
+                in_region = false;
+            } else if !in_page {
+                data.pages.push(PageData::new(trimmed));
+                in_page = true;
+            } else if let Some((key, value)) = trimmed.split_once(':') {
+                let (key, value) = (key.trim(), value.trim());
+                if in_region {
+                    has_original |= key == "orig";
+                    parse_region(line_number, data.regions.last_mut().unwrap(), key, value)?;
+                } else {
+                    parse_page(line_number, data.pages.last_mut().unwrap(), key, value)?;
+                }
+            } else {
+                if in_region && !has_original {
+                    data.regions.last_mut().unwrap().fill_original_size();
+                }
+                data.regions
+                    .push(RegionData::new(trimmed, data.pages.len() - 1));
+                in_region = true;
+                has_original = false;
+            }
+        }
+        if in_region && !has_original {
+            data.regions.last_mut().unwrap().fill_original_size();
+        }
+
+        Ok(data)
+    }
+}
+
+impl AtlasData {
+    /// Reads and parses a `.atlas` file.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadAtlas` if the file cannot be read or parsed.
+    pub fn from_file<P>(path: P) -> Result<Self, SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        std::fs::read_to_string(path)
+            .map_err(|e| {
+                SpineError::FailLoadAtlas(format!("failed to read {}: {e}", path.display()))
+            })?
+            .parse()
+    }
+
+    /// Writes this atlas as a `.atlas` file.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadAtlas` if the file cannot be written.
+    pub fn write_file<P>(&self, path: P) -> Result<(), SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_string()).map_err(|e| {
+            SpineError::FailLoadAtlas(format!("failed to write {}: {e}", path.display()))
+        })
+    }
+
+    /// Returns the first region named `name`.
+    pub fn find_region(&self, name: &str) -> Option<&RegionData> {
+        self.regions.iter().find(|region| region.name == name)
+    }
+
+    /// Writes the atlas text, leaving out the keys spine-c 3.8 cannot parse unless `extended`.
+    pub(crate) fn write(&self, out: &mut impl fmt::Write, extended: bool) -> fmt::Result {
+        for (index, page) in self.pages.iter().enumerate() {
+            writeln!(out)?;
+            writeln!(out, "{}", page.name)?;
+            if page.width != 0 || page.height != 0 {
+                writeln!(out, "size: {},{}", page.width, page.height)?;
+            }
+            writeln!(out, "format: {}", format_name(page.format))?;
+            writeln!(
+                out,
+                "filter: {},{}",
+                filter_name(page.min_filter),
+                filter_name(page.mag_filter)
+            )?;
+            let repeat = match (page.u_wrap, page.v_wrap) {
+                (AtlasWrap::Repeat, AtlasWrap::Repeat) => "xy",
+                (AtlasWrap::Repeat, _) => "x",
+                (_, AtlasWrap::Repeat) => "y",
+                _ => "none",
+            };
+            writeln!(out, "repeat: {repeat}")?;
+            if extended && page.pma {
+                writeln!(out, "pma: true")?;
+            }
+
+            for region in self.regions.iter().filter(|region| region.page == index) {
+                writeln!(out, "{}", region.name)?;
+                match region.degrees {
+                    0 => writeln!(out, "  rotate: false")?,
+                    90 => writeln!(out, "  rotate: true")?,
+                    degrees => writeln!(out, "  rotate: {degrees}")?,
+                }
+                writeln!(out, "  xy: {}, {}", region.x, region.y)?;
+                writeln!(out, "  size: {}, {}", region.width, region.height)?;
+                // spine-c only reads pads after splits.
+                if region.splits.is_some() || region.pads.is_some() {
+                    let [left, right, top, bottom] = region.splits.unwrap_or_default();
+                    writeln!(out, "  split: {left}, {right}, {top}, {bottom}")?;
+                }
+                if let Some([left, right, top, bottom]) = region.pads {
+                    writeln!(out, "  pad: {left}, {right}, {top}, {bottom}")?;
+                }
+                writeln!(
+                    out,
+                    "  orig: {}, {}",
+                    region.original_width, region.original_height
+                )?;
+                writeln!(out, "  offset: {}, {}", region.offset_x, region.offset_y)?;
+                writeln!(out, "  index: {}", region.index)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats the atlas as the text of a `.atlas` file.
+impl fmt::Display for AtlasData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{atlas::Atlas, tests::TEST_CASES};
+
+    const NINE_PATCH: &str = "
+ui.png
+size: 64,32
+format: RGBA4444
+filter: MipMapLinearLinear,Nearest
+repeat: xy
+pma: true
+button
+  rotate: 270
+  xy: 2, 4
+  size: 20, 10
+  split: 4, 5, 2, 3
+  pad: 1, 1, 1, 1
+  orig: 22, 12
+  offset: 1, 1
+  index: 3
+";
+
+    #[test]
+    fn round_trip() {
+        let test_case = &TEST_CASES[0];
+        let text = std::fs::read_to_string(test_case.atlas()).unwrap();
+        let data: AtlasData = text.parse().unwrap();
+
+        assert_eq!(2, data.pages.len());
+        assert_eq!((1024, 512), (data.pages[1].width, data.pages[1].height));
+        assert_eq!(AtlasFilter::Linear, data.pages[0].mag_filter);
+        let chin = data.find_region("chin").unwrap();
+        assert_eq!(0, chin.page);
+        assert!(chin.rotate());
+        assert_eq!(
+            (856, 138, 214, 146),
+            (chin.x, chin.y, chin.width, chin.height)
+        );
+        assert_eq!(text, data.to_string());
+
+        let nine_patch: AtlasData = NINE_PATCH.parse().unwrap();
+        assert_eq!(NINE_PATCH, nine_patch.to_string());
+        let page = &nine_patch.pages[0];
+        assert!(page.pma);
+        assert_eq!(AtlasFormat::RGBA4444, page.format);
+        assert_eq!(AtlasFilter::MipmapLinearLinear, page.min_filter);
+        assert_eq!(
+            (AtlasWrap::Repeat, AtlasWrap::Repeat),
+            (page.u_wrap, page.v_wrap)
+        );
+        let button = &nine_patch.regions[0];
+        assert_eq!((270, false), (button.degrees, button.rotate()));
+        assert_eq!(Some([4, 5, 2, 3]), button.splits);
+        assert_eq!(Some([1, 1, 1, 1]), button.pads);
+        assert_eq!(3, button.index);
+    }
+
+    #[test]
+    fn lenient_parsing() {
+        let data: AtlasData =
+            "page.png\nfilter: Linear, Linear\nregion\n  size: 4, 6\n  xy: 1, 2\n"
+                .parse()
+                .unwrap();
+        let region = &data.regions[0];
+        assert_eq!(
+            (1, 2, 4, 6),
+            (region.x, region.y, region.width, region.height)
+        );
+        assert_eq!((4, 6), (region.original_width, region.original_height));
+        assert_eq!(-1, region.index);
+
+        let error = "page.png\nsize: 4\n".parse::<AtlasData>().unwrap_err();
+        assert_eq!("line 2: invalid size \"4\"", error.to_string());
+        assert!("page.png\nformat: RGBA9999\n".parse::<AtlasData>().is_err());
+    }
+
+    #[test]
+    fn feeds_spine_c() {
+        let data: AtlasData = NINE_PATCH.parse().unwrap();
+        let mut paths = Vec::new();
+        let atlas = Atlas::from_data(&data, "assets", |_, path| {
+            paths.push(path.to_owned());
+            7
+        })
+        .unwrap();
+
+        assert_eq!(vec![Path::new("assets/ui.png")], paths);
+        let pages = atlas.pages();
+        assert_eq!(7, pages[0].texture_id());
+        assert_eq!((64, 32), pages[0].dimensions());
+        assert_eq!(AtlasFilter::MipmapLinearLinear, pages[0].min_filter());
+
+        let regions = atlas.regions();
+        assert_eq!(1, regions.len());
+        assert_eq!("button", regions[0].name());
+        assert_eq!((2, 4), regions[0].position());
+        assert_eq!(3, regions[0].index());
+        assert_eq!(270, regions[0].degrees());
+        assert_eq!(Some([4, 5, 2, 3]), regions[0].splits());
+        assert_eq!(Some([1, 1, 1, 1]), regions[0].pads());
+    }
+}
@@ -0,0 +1,1107 @@
+//! A typed model of the Spine JSON format, for generating and editing skeletons in Rust tooling.
+//!
+//! The model follows the files the Spine 3.8 editor exports, nonessential data included. Keys a
+//! file leaves out read as the editor's defaults, and fields at their defaults are left out when
+//! writing, as the editor does, so an exported skeleton reads and writes back without loss.
+//! Curves are kept as written rather than sampled like spine-c does.
+
+use crate::{
+    color::Color,
+    enums::{BlendMode, PositionMode, RotateMode, SpacingMode, TransformMode},
+    SpineError,
+};
+use indexmap::IndexMap;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, path::Path, str::FromStr};
+
+/// Named entries, in the order the file lists them.
+pub type Map<T> = IndexMap<String, T>;
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+fn one() -> f32 {
+    1.0
+}
+
+#[allow(clippy::float_cmp, clippy::trivially_copy_pass_by_ref)]
+fn is_one(value: &f32) -> bool {
+    *value == 1.0
+}
+
+fn yes() -> bool {
+    true
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
+/// Parses a `rrggbbaa` color, or a `rrggbb` one with full alpha.
+fn parse_color<E: de::Error>(hex: &str) -> Result<Color, E> {
+    let value = u32::from_str_radix(hex, 16).map_err(E::custom)?;
+    match hex.len() {
+        8 => Ok(Color::from_rgba8(value)),
+        6 => Ok(Color::from_rgba8(value << 8 | 0xff)),
+        _ => Err(E::invalid_length(hex.len(), &"6 or 8 hex digits")),
+    }
+}
+
+/// Colors written as `rrggbbaa`.
+mod rgba {
+    use super::{parse_color, Color, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:08x}", color.to_rgba8()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        parse_color(&String::deserialize(deserializer)?)
+    }
+}
+
+/// Colors without alpha, written as `rrggbb`.
+mod rgb {
+    use super::{parse_color, Color, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:06x}", color.to_rgba8() >> 8))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        parse_color(&String::deserialize(deserializer)?)
+    }
+}
+
+/// Optional colors written as `rrggbbaa`.
+mod optional_rgba {
+    use super::{parse_color, Color, Deserialize, Deserializer, Serializer};
+
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S: Serializer>(
+        color: &Option<Color>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match color {
+            Some(color) => super::rgba::serialize(color, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Color>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|hex| parse_color(&hex))
+            .transpose()
+    }
+}
+
+/// Optional colors without alpha, written as `rrggbb`.
+mod optional_rgb {
+    use super::{Color, Deserializer, Serializer};
+
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S: Serializer>(
+        color: &Option<Color>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match color {
+            Some(color) => super::rgb::serialize(color, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Color>, D::Error> {
+        super::optional_rgba::deserialize(deserializer)
+    }
+}
+
+/// A skeleton as stored in a Spine JSON file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SkeletonJson {
+    #[serde(default)]
+    pub skeleton: Header,
+    /// The bones, each after its parent.
+    #[serde(default)]
+    pub bones: Vec<Bone>,
+    /// The slots, in setup draw order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub slots: Vec<Slot>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ik: Vec<IkConstraint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transform: Vec<TransformConstraint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<PathConstraint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skins: Vec<Skin>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub events: Map<Event>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub animations: Map<Animation>,
+}
+
+/// The `skeleton` section of a Spine JSON file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Header {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// The version of the editor that exported the skeleton.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spine: Option<String>,
+    /// The bounds of the setup pose.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub x: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub y: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub width: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub height: f32,
+    /// The dopesheet frame rate, or `None` for the editor's default of 30.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fps: Option<f32>,
+    /// The images directory, relative to the skeleton file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub images: Option<String>,
+    /// The audio directory, relative to the skeleton file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bone {
+    pub name: String,
+    /// The parent bone, or `None` for the root bone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub length: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rotation: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub x: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub y: f32,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub scale_x: f32,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub scale_y: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub shear_x: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub shear_y: f32,
+    #[serde(rename = "transform", default, skip_serializing_if = "is_default")]
+    pub transform_mode: TransformMode,
+    /// Whether the bone is only active while a skin including it is set.
+    #[serde(rename = "skin", default, skip_serializing_if = "is_default")]
+    pub skin_required: bool,
+    /// The color of the bone in the editor, or `None` for the editor's default.
+    #[serde(
+        default,
+        with = "optional_rgba",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color: Option<Color>,
+}
+impl Bone {
+    /// Creates a bone at its parent's origin.
+    pub fn new(name: &str, parent: Option<&str>) -> Self {
+        Self {
+            name: name.to_owned(),
+            parent: parent.map(str::to_owned),
+            length: 0.0,
+            rotation: 0.0,
+            x: 0.0,
+            y: 0.0,
+            scale_x: 1.0,
+            scale_y: 1.0,
+            shear_x: 0.0,
+            shear_y: 0.0,
+            transform_mode: TransformMode::Normal,
+            skin_required: false,
+            color: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Slot {
+    pub name: String,
+    pub bone: String,
+    #[serde(default, with = "rgba", skip_serializing_if = "is_default")]
+    pub color: Color,
+    /// The dark color of two color tinting, or `None` if the slot does not use it.
+    #[serde(
+        default,
+        with = "optional_rgb",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dark: Option<Color>,
+    /// The attachment visible in the setup pose.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<String>,
+    #[serde(rename = "blend", default, skip_serializing_if = "is_default")]
+    pub blend_mode: BlendMode,
+}
+impl Slot {
+    /// Creates a slot without a setup attachment.
+    pub fn new(name: &str, bone: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            bone: bone.to_owned(),
+            color: Color::WHITE,
+            dark: None,
+            attachment: None,
+            blend_mode: BlendMode::Normal,
+        }
+    }
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IkConstraint {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub order: i32,
+    #[serde(rename = "skin", default, skip_serializing_if = "is_default")]
+    pub skin_required: bool,
+    pub bones: Vec<String>,
+    pub target: String,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub mix: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub softness: f32,
+    #[serde(default = "yes", skip_serializing_if = "is_true")]
+    pub bend_positive: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub compress: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub stretch: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub uniform: bool,
+}
+
+/// A transform constraint. The rotation, translation, scale and shear are offsets from the
+/// target bone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformConstraint {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub order: i32,
+    #[serde(rename = "skin", default, skip_serializing_if = "is_default")]
+    pub skin_required: bool,
+    pub bones: Vec<String>,
+    pub target: String,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub local: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub relative: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rotation: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub x: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub y: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub scale_x: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub scale_y: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub shear_y: f32,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub rotate_mix: f32,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub translate_mix: f32,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub scale_mix: f32,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub shear_mix: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathConstraint {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub order: i32,
+    #[serde(rename = "skin", default, skip_serializing_if = "is_default")]
+    pub skin_required: bool,
+    pub bones: Vec<String>,
+    /// The slot holding the path attachment.
+    pub target: String,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub position_mode: PositionMode,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub spacing_mode: SpacingMode,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rotate_mode: RotateMode,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rotation: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub position: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub spacing: f32,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub rotate_mix: f32,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub translate_mix: f32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Skin {
+    pub name: String,
+    /// The bones and constraints only active while this skin is set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bones: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ik: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transform: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<String>,
+    /// The attachments by slot, then by the name the skin gives them.
+    #[serde(default)]
+    pub attachments: Map<Map<Attachment>>,
+}
+
+/// An attachment of a skin, tagged by the `type` key, which region attachments leave out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Attachment {
+    Region(RegionAttachment),
+    BoundingBox(BoundingBoxAttachment),
+    Mesh(MeshAttachment),
+    LinkedMesh(LinkedMeshAttachment),
+    Path(PathAttachment),
+    Point(PointAttachment),
+    Clipping(ClippingAttachment),
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum TaggedRef<'a> {
+    BoundingBox(&'a BoundingBoxAttachment),
+    Mesh(&'a MeshAttachment),
+    LinkedMesh(&'a LinkedMeshAttachment),
+    Path(&'a PathAttachment),
+    Point(&'a PointAttachment),
+    Clipping(&'a ClippingAttachment),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Tagged {
+    Region(RegionAttachment),
+    BoundingBox(BoundingBoxAttachment),
+    Mesh(MeshAttachment),
+    LinkedMesh(LinkedMeshAttachment),
+    Path(PathAttachment),
+    Point(PointAttachment),
+    Clipping(ClippingAttachment),
+}
+
+impl Serialize for Attachment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let tagged = match self {
+            Self::Region(region) => return region.serialize(serializer),
+            Self::BoundingBox(attachment) => TaggedRef::BoundingBox(attachment),
+            Self::Mesh(attachment) => TaggedRef::Mesh(attachment),
+            Self::LinkedMesh(attachment) => TaggedRef::LinkedMesh(attachment),
+            Self::Path(attachment) => TaggedRef::Path(attachment),
+            Self::Point(attachment) => TaggedRef::Point(attachment),
+            Self::Clipping(attachment) => TaggedRef::Clipping(attachment),
+        };
+        tagged.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Attachment {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = serde_json::Value::deserialize(deserializer)?;
+        if let Some(object) = value.as_object_mut() {
+            object.entry("type").or_insert_with(|| "region".into());
+        }
+        Ok(
+            match Tagged::deserialize(value).map_err(de::Error::custom)? {
+                Tagged::Region(attachment) => Self::Region(attachment),
+                Tagged::BoundingBox(attachment) => Self::BoundingBox(attachment),
+                Tagged::Mesh(attachment) => Self::Mesh(attachment),
+                Tagged::LinkedMesh(attachment) => Self::LinkedMesh(attachment),
+                Tagged::Path(attachment) => Self::Path(attachment),
+                Tagged::Point(attachment) => Self::Point(attachment),
+                Tagged::Clipping(attachment) => Self::Clipping(attachment),
+            },
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegionAttachment {
+    /// The attachment name, when it differs from the name the skin gives it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The atlas region, when it differs from the attachment name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub x: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub y: f32,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub scale_x: f32,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub scale_y: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rotation: f32,
+    #[serde(default)]
+    pub width: f32,
+    #[serde(default)]
+    pub height: f32,
+    #[serde(default, with = "rgba", skip_serializing_if = "is_default")]
+    pub color: Color,
+}
+impl RegionAttachment {
+    /// Creates a region attachment centered on its bone.
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            name: None,
+            path: None,
+            x: 0.0,
+            y: 0.0,
+            scale_x: 1.0,
+            scale_y: 1.0,
+            rotation: 0.0,
+            width,
+            height,
+            color: Color::WHITE,
+        }
+    }
+}
+
+/// A polygon for hit detection.
+///
+/// Like those of the other vertex attachments, its `vertices` are `x, y` pairs when unweighted.
+/// When there are more than two values per vertex they are weighted, and hold for each vertex a
+/// bone count followed by the bone index and the `x, y, weight` of each bone.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoundingBoxAttachment {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub vertex_count: u32,
+    pub vertices: Vec<f32>,
+    /// The color of the attachment in the editor, or `None` for the editor's default.
+    #[serde(
+        default,
+        with = "optional_rgba",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color: Option<Color>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeshAttachment {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, with = "rgba", skip_serializing_if = "is_default")]
+    pub color: Color,
+    pub uvs: Vec<f32>,
+    pub triangles: Vec<u16>,
+    /// The vertices, laid out as described for `BoundingBoxAttachment`.
+    pub vertices: Vec<f32>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub hull: u32,
+    /// Pairs of vertex indices, times 2, of the edges drawn in the editor.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub edges: Vec<u32>,
+    /// The size of the region in the editor.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub width: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub height: f32,
+}
+
+/// A mesh sharing the geometry of another mesh, usually with a different region.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedMeshAttachment {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, with = "rgba", skip_serializing_if = "is_default")]
+    pub color: Color,
+    /// The skin holding the parent mesh, or `None` for the default skin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skin: Option<String>,
+    /// The parent mesh, by the name its skin gives it in the same slot.
+    pub parent: String,
+    /// Whether deform timelines of the parent mesh apply to this mesh.
+    #[serde(default = "yes", skip_serializing_if = "is_true")]
+    pub deform: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub width: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub height: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathAttachment {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub closed: bool,
+    #[serde(default = "yes", skip_serializing_if = "is_true")]
+    pub constant_speed: bool,
+    #[serde(default)]
+    pub vertex_count: u32,
+    /// The vertices, laid out as described for `BoundingBoxAttachment`.
+    pub vertices: Vec<f32>,
+    /// The length of each curve of the path.
+    pub lengths: Vec<f32>,
+    #[serde(
+        default,
+        with = "optional_rgba",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color: Option<Color>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PointAttachment {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub x: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub y: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rotation: f32,
+    #[serde(
+        default,
+        with = "optional_rgba",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color: Option<Color>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClippingAttachment {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The slot after which clipping stops, or `None` to clip every slot after this one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    #[serde(default)]
+    pub vertex_count: u32,
+    /// The vertices, laid out as described for `BoundingBoxAttachment`.
+    pub vertices: Vec<f32>,
+    #[serde(
+        default,
+        with = "optional_rgba",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color: Option<Color>,
+}
+
+/// The setup values of an event, which its key frames can override.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub int: i32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub float: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub string: Option<String>,
+    /// The audio file, relative to the skeleton's audio directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<String>,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub volume: f32,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub balance: f32,
+}
+impl Default for Event {
+    fn default() -> Self {
+        Self {
+            int: 0,
+            float: 0.0,
+            string: None,
+            audio: None,
+            volume: 1.0,
+            balance: 0.0,
+        }
+    }
+}
+
+/// The timelines of an animation, keyed by the name of what they animate.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Animation {
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub slots: Map<SlotTimelines>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub bones: Map<BoneTimelines>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub ik: Map<Vec<IkKeyFrame>>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub transform: Map<Vec<TransformKeyFrame>>,
+    /// Path constraint timelines, which spine-c 3.8 only reads from `paths`. `path` is accepted
+    /// too.
+    #[serde(default, alias = "path", skip_serializing_if = "Map::is_empty")]
+    pub paths: Map<PathTimelines>,
+    /// Deform timelines by skin, slot and attachment.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub deform: Map<Map<Map<Vec<DeformKeyFrame>>>>,
+    #[serde(
+        rename = "drawOrder",
+        alias = "draworder",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub draw_order: Vec<DrawOrderKeyFrame>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<EventKeyFrame>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotTimelines {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachment: Vec<AttachmentKeyFrame>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub color: Vec<ColorKeyFrame>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub two_color: Vec<TwoColorKeyFrame>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BoneTimelines {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rotate: Vec<RotateKeyFrame>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub translate: Vec<TranslateKeyFrame>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scale: Vec<ScaleKeyFrame>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shear: Vec<ShearKeyFrame>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PathTimelines {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub position: Vec<PathPositionKeyFrame>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spacing: Vec<PathSpacingKeyFrame>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mix: Vec<PathMixKeyFrame>,
+}
+
+/// How a key frame interpolates to the next one, written in the `curve`, `c2`, `c3` and `c4`
+/// keys of the key frame.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub enum Curve {
+    #[default]
+    Linear,
+    Stepped,
+    /// A Bezier curve from `(0, 0)` to `(1, 1)`, with these control points.
+    Bezier {
+        cx1: f32,
+        cy1: f32,
+        cx2: f32,
+        cy2: f32,
+    },
+}
+
+impl Serialize for Curve {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        match *self {
+            Self::Linear => {}
+            Self::Stepped => map.serialize_entry("curve", "stepped")?,
+            Self::Bezier { cx1, cy1, cx2, cy2 } => {
+                map.serialize_entry("curve", &cx1)?;
+                if !is_default(&cy1) {
+                    map.serialize_entry("c2", &cy1)?;
+                }
+                if !is_one(&cx2) {
+                    map.serialize_entry("c3", &cx2)?;
+                }
+                if !is_one(&cy2) {
+                    map.serialize_entry("c4", &cy2)?;
+                }
+            }
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Curve {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Kind {
+            Bezier(f32),
+            Named(String),
+        }
+        #[derive(Deserialize)]
+        struct Keys {
+            curve: Option<Kind>,
+            #[serde(default)]
+            c2: f32,
+            #[serde(default = "one")]
+            c3: f32,
+            #[serde(default = "one")]
+            c4: f32,
+        }
+
+        let keys = Keys::deserialize(deserializer)?;
+        match keys.curve {
+            None => Ok(Self::Linear),
+            Some(Kind::Named(name)) => match name.as_str() {
+                "linear" => Ok(Self::Linear),
+                "stepped" => Ok(Self::Stepped),
+                _ => Err(de::Error::unknown_variant(&name, &["linear", "stepped"])),
+            },
+            Some(Kind::Bezier(cx1)) => Ok(Self::Bezier {
+                cx1,
+                cy1: keys.c2,
+                cx2: keys.c3,
+                cy2: keys.c4,
+            }),
+        }
+    }
+}
+
+/// Declares a key frame type: its time, the given fields, and a curve when `curve` is given.
+macro_rules! key_frame {
+    (
+        $(#[$meta:meta])* $name:ident { $($(#[$field_meta:meta])* $field:ident: $ty:ty,)* } $($curve:ident)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct $name {
+            /// The time of the key frame, in seconds.
+            #[serde(default, skip_serializing_if = "is_default")]
+            pub time: f32,
+            $(
+                $(#[$field_meta])*
+                pub $field: $ty,
+            )*
+            $(
+                #[serde(flatten)]
+                pub $curve: Curve,
+            )?
+        }
+    };
+}
+
+key_frame! {
+    AttachmentKeyFrame {
+        /// The attachment to show, or `None` to hide it.
+        name: Option<String>,
+    }
+}
+
+key_frame! {
+    ColorKeyFrame {
+        #[serde(with = "rgba")]
+        color: Color,
+    } curve
+}
+
+key_frame! {
+    TwoColorKeyFrame {
+        #[serde(with = "rgba")]
+        light: Color,
+        #[serde(with = "rgb")]
+        dark: Color,
+    } curve
+}
+
+key_frame! {
+    RotateKeyFrame {
+        #[serde(default, skip_serializing_if = "is_default")]
+        angle: f32,
+    } curve
+}
+
+key_frame! {
+    /// A key frame of a translate or shear timeline.
+    TranslateKeyFrame {
+        #[serde(default, skip_serializing_if = "is_default")]
+        x: f32,
+        #[serde(default, skip_serializing_if = "is_default")]
+        y: f32,
+    } curve
+}
+
+pub type ShearKeyFrame = TranslateKeyFrame;
+
+key_frame! {
+    ScaleKeyFrame {
+        #[serde(default = "one", skip_serializing_if = "is_one")]
+        x: f32,
+        #[serde(default = "one", skip_serializing_if = "is_one")]
+        y: f32,
+    } curve
+}
+
+key_frame! {
+    IkKeyFrame {
+        #[serde(default = "one", skip_serializing_if = "is_one")]
+        mix: f32,
+        #[serde(default, skip_serializing_if = "is_default")]
+        softness: f32,
+        #[serde(default = "yes", skip_serializing_if = "is_true")]
+        bend_positive: bool,
+        #[serde(default, skip_serializing_if = "is_default")]
+        compress: bool,
+        #[serde(default, skip_serializing_if = "is_default")]
+        stretch: bool,
+    } curve
+}
+
+key_frame! {
+    TransformKeyFrame {
+        #[serde(default = "one", skip_serializing_if = "is_one")]
+        rotate_mix: f32,
+        #[serde(default = "one", skip_serializing_if = "is_one")]
+        translate_mix: f32,
+        #[serde(default = "one", skip_serializing_if = "is_one")]
+        scale_mix: f32,
+        #[serde(default = "one", skip_serializing_if = "is_one")]
+        shear_mix: f32,
+    } curve
+}
+
+key_frame! {
+    PathPositionKeyFrame {
+        #[serde(default, skip_serializing_if = "is_default")]
+        position: f32,
+    } curve
+}
+
+key_frame! {
+    PathSpacingKeyFrame {
+        #[serde(default, skip_serializing_if = "is_default")]
+        spacing: f32,
+    } curve
+}
+
+key_frame! {
+    PathMixKeyFrame {
+        #[serde(default = "one", skip_serializing_if = "is_one")]
+        rotate_mix: f32,
+        #[serde(default = "one", skip_serializing_if = "is_one")]
+        translate_mix: f32,
+    } curve
+}
+
+key_frame! {
+    DeformKeyFrame {
+        /// The index of the first vertex value `vertices` replaces.
+        #[serde(default, skip_serializing_if = "is_default")]
+        offset: u32,
+        /// Offsets from the setup vertices, starting at `offset`. Values left out are 0.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        vertices: Vec<f32>,
+    } curve
+}
+
+key_frame! {
+    DrawOrderKeyFrame {
+        /// The slots moved from their setup position, or none to restore the setup order.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        offsets: Vec<DrawOrderOffset>,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrawOrderOffset {
+    pub slot: String,
+    /// How many places the slot moves from its setup position.
+    pub offset: i32,
+}
+
+key_frame! {
+    /// An event key frame. Values left as `None` are the event's setup values.
+    EventKeyFrame {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        int: Option<i32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        float: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        string: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        volume: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        balance: Option<f32>,
+    }
+}
+
+impl FromStr for SkeletonJson {
+    type Err = SpineError;
+
+    /// Parses the text of a Spine JSON file.
+    fn from_str(text: &str) -> Result<Self, SpineError> {
+        serde_json::from_str(text)
+            .map_err(|e| SpineError::FailLoadSkeleton(format!("invalid skeleton JSON: {e}")))
+    }
+}
+
+impl SkeletonJson {
+    /// Reads and parses a Spine JSON file.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadSkeleton` if the file cannot be read or parsed.
+    pub fn from_file<P>(path: P) -> Result<Self, SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        std::fs::read_to_string(path)
+            .map_err(|e| {
+                SpineError::FailLoadSkeleton(format!("failed to read {}: {e}", path.display()))
+            })?
+            .parse()
+    }
+
+    /// Writes this skeleton as a Spine JSON file.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadSkeleton` if the file cannot be written.
+    pub fn write_file<P>(&self, path: P) -> Result<(), SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_string()).map_err(|e| {
+            SpineError::FailLoadSkeleton(format!("failed to write {}: {e}", path.display()))
+        })
+    }
+
+    pub fn find_bone(&self, name: &str) -> Option<&Bone> {
+        self.bones.iter().find(|bone| bone.name == name)
+    }
+
+    pub fn find_slot(&self, name: &str) -> Option<&Slot> {
+        self.slots.iter().find(|slot| slot.name == name)
+    }
+
+    pub fn find_skin(&self, name: &str) -> Option<&Skin> {
+        self.skins.iter().find(|skin| skin.name == name)
+    }
+}
+
+/// Formats the skeleton as the text of a Spine JSON file, laid out like the editor's exports.
+impl fmt::Display for SkeletonJson {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = serde_json::to_value(self).map_err(|_| fmt::Error)?;
+        f.write_str(&super::skeleton::json::layout(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{format::skeleton::tests::FEATURES, tests::TEST_CASES};
+
+    fn value(text: &str) -> serde_json::Value {
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = std::fs::read_to_string(TEST_CASES[0].json()).unwrap();
+        let skeleton: SkeletonJson = text.parse().unwrap();
+        assert_eq!(33, skeleton.bones.len());
+        assert_eq!(Some("./images/"), skeleton.skeleton.images.as_deref());
+        let written = skeleton.to_string();
+        assert_eq!(value(&text), value(&written));
+        assert_eq!(skeleton, written.parse().unwrap());
+
+        let head = &skeleton.animations["flying"].bones["head"].rotate;
+        assert_eq!(
+            Curve::Bezier {
+                cx1: 0.408,
+                cy1: 1.37,
+                cx2: 0.675,
+                cy2: 1.43
+            },
+            head[0].curve
+        );
+    }
+
+    #[test]
+    fn round_trip_features() {
+        let skeleton: SkeletonJson = FEATURES.parse().unwrap();
+        assert_eq!(value(FEATURES), value(&skeleton.to_string()));
+
+        let cape = &skeleton.find_skin("armored").unwrap().attachments["cape"]["cape"];
+        assert!(matches!(cape, Attachment::LinkedMesh(mesh) if !mesh.deform));
+        let default = &skeleton.find_skin("default").unwrap().attachments;
+        assert!(
+            matches!(&default["body"]["body"], Attachment::Region(region) if region.path.as_deref() == Some("head"))
+        );
+        assert!(
+            matches!(&default["mask"]["mask"], Attachment::Clipping(clipping) if clipping.end.as_deref() == Some("muzzle"))
+        );
+        assert_eq!(
+            TransformMode::NoScale,
+            skeleton.find_bone("arm").unwrap().transform_mode
+        );
+        assert_eq!(
+            Some(Color::from_rgba8(0x1020_30ff)),
+            skeleton.find_slot("body").unwrap().dark
+        );
+
+        let animation = &skeleton.animations["features"];
+        assert_eq!(Curve::Stepped, animation.slots["body"].color[0].curve);
+        assert_eq!(-3, animation.draw_order[0].offsets[0].offset);
+        assert_eq!(Some(0.75), animation.events[2].volume);
+    }
+
+    #[test]
+    fn edits_write_back() {
+        let mut skeleton = SkeletonJson::from_file(TEST_CASES[0].json()).unwrap();
+        let mut hitbox = Bone::new("hitbox", Some("root"));
+        hitbox.y = 40.0;
+        skeleton.bones.push(hitbox);
+        skeleton.slots.push(Slot::new("hitbox", "hitbox"));
+        let attachment = BoundingBoxAttachment {
+            vertex_count: 3,
+            vertices: vec![0.0, 0.0, 10.0, 0.0, 0.0, 10.0],
+            ..BoundingBoxAttachment::default()
+        };
+        let mut slot = Map::new();
+        slot.insert("hitbox".to_owned(), Attachment::BoundingBox(attachment));
+        skeleton.skins[0]
+            .attachments
+            .insert("hitbox".to_owned(), slot);
+
+        let text = skeleton.to_string();
+        assert!(text.contains(r#"{ "name": "hitbox", "parent": "root", "y": 40 }"#));
+        assert!(text.contains(r#""type": "boundingbox""#));
+        assert_eq!(skeleton, text.parse().unwrap());
+        assert!("{ \"bones\": 1 }".parse::<SkeletonJson>().is_err());
+    }
+}
@@ -0,0 +1,6 @@
+//! Pure-Rust readers and writers for the files the Spine editor exports.
+
+pub mod atlas;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod skeleton;
@@ -0,0 +1,712 @@
+//! Writers for loaded skeleton data, producing the Spine JSON and binary formats spine-c 3.8
+//! reads.
+//!
+//! spine-c samples Bezier curves when loading, so curves are written with control points
+//! recovered from the samples: rounded to 4 decimal places like the Spine editor exports them if
+//! those reproduce the samples exactly, or else the nearby single precision values that do.
+//! Nonessential data spine-c drops or misreads when loading, such as bone colors and mesh edges,
+//! is not written.
+
+use crate::{ffi, skeleton::SkeletonData};
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    ffi::CStr,
+    os::raw::{c_char, c_int},
+};
+
+mod binary;
+pub(super) mod json;
+
+/// Serializes `data` in the Spine binary format, as read by `SkeletonData::from_binary_file`.
+pub fn to_binary(data: &SkeletonData) -> Vec<u8> {
+    binary::write(data.as_ref())
+}
+
+/// Serializes `data` in the Spine JSON format, as read by `SkeletonData::from_json_file`.
+pub fn to_json(data: &SkeletonData) -> String {
+    json::write(data.as_ref())
+}
+
+fn count(n: c_int) -> usize {
+    usize::try_from(n).unwrap()
+}
+
+unsafe fn string<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s).to_str().unwrap())
+    }
+}
+
+/// Borrows a spine-c array of `n` values, which may be null when empty.
+unsafe fn slice<'a, T>(items: *const T, n: c_int) -> &'a [T] {
+    if items.is_null() || n == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(items, count(n))
+    }
+}
+
+/// Borrows the items of a spine-c array of pointers.
+unsafe fn items<'a, T: 'a>(items: *const *mut T, n: c_int) -> impl Iterator<Item = &'a T> {
+    slice(items, n).iter().map(|item| &**item)
+}
+
+/// Reinterprets an attachment as the attachment type it was created as.
+unsafe fn downcast<T>(attachment: &ffi::spAttachment) -> &T {
+    &*std::ptr::from_ref(attachment).cast::<T>()
+}
+
+fn hex(color: &ffi::spColor, alpha: bool) -> String {
+    let [r, g, b, a] = [color.r, color.g, color.b, color.a].map(byte);
+    if alpha {
+        format!("{r:02x}{g:02x}{b:02x}{a:02x}")
+    } else {
+        format!("{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn byte(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// The color of the `r, g, b` or `r, g, b, a` values of a key frame.
+fn color(values: &[f32]) -> ffi::spColor {
+    ffi::spColor {
+        r: values[0],
+        g: values[1],
+        b: values[2],
+        a: values.get(3).copied().unwrap_or(1.0),
+    }
+}
+
+fn is_white(color: &ffi::spColor) -> bool {
+    [color.r, color.g, color.b, color.a].map(byte) == [255; 4]
+}
+
+/// The interpolation from a key frame to the next.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Curve {
+    Linear,
+    Stepped,
+    /// The control points `cx1, cy1, cx2, cy2`.
+    Bezier([f32; 4]),
+}
+
+// The layout of `spCurveTimeline::curves`: a type and 9 sampled points per frame.
+const BEZIER_SIZE: usize = 19;
+const CURVE_STEPPED: f32 = 1.0;
+const CURVE_BEZIER: f32 = 2.0;
+
+#[allow(clippy::float_cmp)]
+fn curve(timeline: &ffi::spCurveTimeline, frame: usize) -> Curve {
+    let curves = unsafe {
+        std::slice::from_raw_parts(timeline.curves.add(frame * BEZIER_SIZE), BEZIER_SIZE)
+    };
+    if curves[0] == CURVE_STEPPED {
+        return Curve::Stepped;
+    }
+    if curves[0] != CURVE_BEZIER {
+        return Curve::Linear;
+    }
+
+    let axis = |offset: usize| {
+        let mut samples = [0.0; BEZIER_SAMPLES];
+        for (sample, point) in samples.iter_mut().zip(curves[1..].chunks_exact(2)) {
+            *sample = point[offset];
+        }
+        control_points(&samples)
+    };
+    let [cx1, cx2] = axis(0);
+    let [cy1, cy2] = axis(1);
+    Curve::Bezier([cx1, cy1, cx2, cy2])
+}
+
+const BEZIER_SAMPLES: usize = (BEZIER_SIZE - 1) / 2;
+
+/// Recovers the control points of one axis of a curve from its samples.
+#[allow(clippy::cast_precision_loss, clippy::float_cmp)]
+fn control_points(samples: &[f32; BEZIER_SAMPLES]) -> [f32; 2] {
+    // Sample `i` is the Bezier curve from 0 to 1 at `t = (i + 1) / 10`, linear in the control
+    // points, so a least squares fit over all samples gets within a few units in the last place.
+    let (mut aa, mut ab, mut bb, mut ar, mut br) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for (i, &sample) in samples.iter().enumerate() {
+        let t = (i + 1) as f64 / 10.0;
+        let (a, b) = (3.0 * (1.0 - t) * (1.0 - t) * t, 3.0 * (1.0 - t) * t * t);
+        let residual = f64::from(sample) - t * t * t;
+        (aa, ab, bb) = (aa + a * a, ab + a * b, bb + b * b);
+        (ar, br) = (ar + a * residual, br + b * residual);
+    }
+    let determinant = aa * bb - ab * ab;
+    let fit = [
+        (bb * ar - ab * br) / determinant,
+        (aa * br - ab * ar) / determinant,
+    ];
+
+    // spine-c only keeps the samples, so any control points reproducing them lose nothing.
+    let exact = |[c1, c2]: [f32; 2]| bezier_samples(c1, c2) == *samples;
+    let rounded = fit.map(round);
+    if exact(rounded) {
+        return rounded;
+    }
+    // Near zero the samples cannot tell single precision values apart, so the search steps by
+    // units in the last place of at least 0.5 rather than of the fit itself.
+    #[allow(clippy::cast_possible_truncation)]
+    let neighbours = |c: f64| {
+        let step = f64::from(f32::EPSILON) / 2.0 * c.abs().max(0.5);
+        (0..=32).map(move |i| {
+            (c + f64::from((i + 1) / 2 * if i % 2 == 0 { 1 } else { -1 }) * step) as f32
+        })
+    };
+    neighbours(fit[0])
+        .flat_map(|c1| neighbours(fit[1]).map(move |c2| [c1, c2]))
+        .find(|&points| exact(points))
+        .unwrap_or(rounded)
+}
+
+/// The samples `spCurveTimeline_setCurve` takes of one axis, by forward differencing in single
+/// precision in the same order of operations.
+fn bezier_samples(c1: f32, c2: f32) -> [f32; BEZIER_SAMPLES] {
+    let tmp = (-c1 * 2.0 + c2) * 0.03;
+    let ddd = ((c1 - c2) * 3.0 + 1.0) * 0.006;
+    let mut dd = tmp * 2.0 + ddd;
+    let mut d = c1 * 0.3 + tmp + ddd * 0.166_666_67;
+    let mut x = d;
+    let mut samples = [0.0; BEZIER_SAMPLES];
+    for sample in &mut samples {
+        *sample = x;
+        d += dd;
+        dd += ddd;
+        x += d;
+    }
+    samples
+}
+
+/// Splits the flat `frames` of a curve timeline into key frames of `entries` values, each paired
+/// with the curve to the next key frame.
+fn key_frames<'a>(
+    timeline: &ffi::spCurveTimeline,
+    frames: *const f32,
+    len: c_int,
+    entries: usize,
+) -> Vec<(&'a [f32], Option<Curve>)> {
+    let frames = unsafe { slice(frames, len) };
+    let count = frames.len() / entries;
+    frames
+        .chunks(entries)
+        .enumerate()
+        .map(|(frame, values)| (values, (frame + 1 < count).then(|| curve(timeline, frame))))
+        .collect()
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn round(value: f64) -> f32 {
+    ((value * 10_000.0).round() / 10_000.0) as f32
+}
+
+/// The setup vertices of a vertex attachment.
+struct SetupVertices<'a> {
+    /// For weighted vertices, the bone count of each vertex followed by its bone indices.
+    bones: &'a [c_int],
+    /// The positions of unweighted vertices, or the `x, y, weight` of each bone of each weighted
+    /// vertex.
+    vertices: &'a [f32],
+    /// The number of vertices.
+    count: usize,
+}
+impl<'a> SetupVertices<'a> {
+    fn new(attachment: &'a ffi::spVertexAttachment) -> Self {
+        unsafe {
+            Self {
+                bones: slice(attachment.bones, attachment.bonesCount),
+                vertices: slice(attachment.vertices, attachment.verticesCount),
+                count: count(attachment.worldVerticesLength) / 2,
+            }
+        }
+    }
+
+    fn weighted(&self) -> bool {
+        !self.bones.is_empty()
+    }
+
+    /// The bones of every weighted vertex, as the bone index and the position and weight of the
+    /// vertex relative to that bone.
+    fn weights(&self) -> Vec<Vec<(c_int, &'a [f32])>> {
+        let mut bones = self.bones.iter();
+        let mut weights = self.vertices.chunks(3);
+        let mut vertices = Vec::with_capacity(self.count);
+        while let Some(&n) = bones.next() {
+            let vertex = (0..n)
+                .map(|_| (*bones.next().unwrap(), weights.next().unwrap()))
+                .collect();
+            vertices.push(vertex);
+        }
+        vertices
+    }
+}
+
+/// An attachment in a skin, under the name its slot refers to it by.
+struct Entry<'a> {
+    skin: usize,
+    slot: usize,
+    name: &'a str,
+    attachment: &'a ffi::spAttachment,
+}
+
+/// The attachments of every skin, ordered by slot and then by name.
+fn skin_entries(data: &ffi::spSkeletonData) -> Vec<Vec<Entry<'_>>> {
+    unsafe { items(data.skins, data.skinsCount) }
+        .enumerate()
+        .map(|(skin_index, skin)| {
+            let mut entries = Vec::new();
+            let mut entry = unsafe { ffi::spSkin_getAttachments(skin) };
+            while let Some(e) = unsafe { entry.as_ref() } {
+                entries.push(Entry {
+                    skin: skin_index,
+                    slot: count(e.slotIndex),
+                    name: unsafe { string(e.name) }.unwrap(),
+                    attachment: unsafe { &*e.attachment },
+                });
+                entry = e.next;
+            }
+            entries.sort_by(|a, b| (a.slot, a.name).cmp(&(b.slot, b.name)));
+            entries
+        })
+        .collect()
+}
+
+/// Finds where `attachment` is stored, preferring `slot` when one is given.
+fn find_entry<'a, 'b>(
+    skins: &'b [Vec<Entry<'a>>],
+    attachment: *const ffi::spAttachment,
+    slot: Option<usize>,
+) -> Option<&'b Entry<'a>> {
+    skins.iter().flatten().find(|entry| {
+        std::ptr::eq(entry.attachment, attachment) && slot.is_none_or(|slot| entry.slot == slot)
+    })
+}
+
+/// The name of the skin holding `mesh`'s parent, or `None` for the default skin, and the name
+/// of the parent.
+fn linked_parent<'a>(
+    data: &ffi::spSkeletonData,
+    skins: &[Vec<Entry<'a>>],
+    mesh: &ffi::spMeshAttachment,
+    slot: usize,
+) -> (Option<&'a str>, &'a str) {
+    let parent = find_entry(skins, mesh.parentMesh.cast(), Some(slot))
+        .or_else(|| find_entry(skins, mesh.parentMesh.cast(), None))
+        .expect("the parent of a linked mesh is in a skin");
+    let skin = unsafe { &**data.skins.add(parent.skin) };
+    let skin_name = if std::ptr::eq(skin, data.defaultSkin) {
+        None
+    } else {
+        unsafe { string(skin.name) }
+    };
+    (skin_name, parent.name)
+}
+
+/// A timeline of an animation, by type.
+enum Timeline<'a> {
+    Attachment(&'a ffi::spAttachmentTimeline),
+    Color(&'a ffi::spColorTimeline),
+    TwoColor(&'a ffi::spTwoColorTimeline),
+    Rotate(&'a ffi::spRotateTimeline),
+    Translate(&'a ffi::spTranslateTimeline),
+    Scale(&'a ffi::spScaleTimeline),
+    Shear(&'a ffi::spShearTimeline),
+    PathPosition(&'a ffi::spPathConstraintPositionTimeline),
+    PathSpacing(&'a ffi::spPathConstraintSpacingTimeline),
+    PathMix(&'a ffi::spPathConstraintMixTimeline),
+}
+
+/// The deform timelines of a skin, by slot, with the name of the attachment each deforms.
+type DeformTimelines<'a> = BTreeMap<usize, Vec<(&'a str, &'a ffi::spDeformTimeline)>>;
+
+/// The timelines of an animation, grouped the way both file formats store them.
+#[derive(Default)]
+struct Timelines<'a> {
+    slots: BTreeMap<usize, Vec<Timeline<'a>>>,
+    bones: BTreeMap<usize, Vec<Timeline<'a>>>,
+    ik: Vec<&'a ffi::spIkConstraintTimeline>,
+    transform: Vec<&'a ffi::spTransformConstraintTimeline>,
+    paths: BTreeMap<usize, Vec<Timeline<'a>>>,
+    /// Deform timelines by skin, slot and attachment name.
+    deform: BTreeMap<usize, DeformTimelines<'a>>,
+    draw_order: Option<&'a ffi::spDrawOrderTimeline>,
+    events: Option<&'a ffi::spEventTimeline>,
+}
+impl<'a> Timelines<'a> {
+    #[allow(clippy::cast_ptr_alignment)]
+    fn new(animation: &'a ffi::spAnimation, skins: &[Vec<Entry<'a>>]) -> Self {
+        let mut timelines = Self::default();
+        for timeline in unsafe { slice(animation.timelines, animation.timelinesCount) } {
+            let timeline = *timeline;
+            let kind = unsafe { (*timeline).type_ };
+            unsafe {
+                match kind {
+                    ffi::spTimelineType_SP_TIMELINE_ATTACHMENT => {
+                        let t = &*timeline.cast::<ffi::spAttachmentTimeline>();
+                        timelines.slot(t.slotIndex, Timeline::Attachment(t));
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_COLOR => {
+                        let t = &*timeline.cast::<ffi::spColorTimeline>();
+                        timelines.slot(t.slotIndex, Timeline::Color(t));
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_TWOCOLOR => {
+                        let t = &*timeline.cast::<ffi::spTwoColorTimeline>();
+                        timelines.slot(t.slotIndex, Timeline::TwoColor(t));
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_ROTATE
+                    | ffi::spTimelineType_SP_TIMELINE_TRANSLATE
+                    | ffi::spTimelineType_SP_TIMELINE_SCALE
+                    | ffi::spTimelineType_SP_TIMELINE_SHEAR => {
+                        let t = &*timeline.cast::<ffi::spBaseTimeline>();
+                        let typed = match kind {
+                            ffi::spTimelineType_SP_TIMELINE_ROTATE => Timeline::Rotate(t),
+                            ffi::spTimelineType_SP_TIMELINE_TRANSLATE => Timeline::Translate(t),
+                            ffi::spTimelineType_SP_TIMELINE_SCALE => Timeline::Scale(t),
+                            _ => Timeline::Shear(t),
+                        };
+                        timelines
+                            .bones
+                            .entry(count(t.boneIndex))
+                            .or_default()
+                            .push(typed);
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_IKCONSTRAINT => {
+                        timelines
+                            .ik
+                            .push(&*timeline.cast::<ffi::spIkConstraintTimeline>());
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_TRANSFORMCONSTRAINT => {
+                        timelines
+                            .transform
+                            .push(&*timeline.cast::<ffi::spTransformConstraintTimeline>());
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_PATHCONSTRAINTPOSITION => {
+                        let t = &*timeline.cast::<ffi::spPathConstraintPositionTimeline>();
+                        timelines.path(t.pathConstraintIndex, Timeline::PathPosition(t));
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_PATHCONSTRAINTSPACING => {
+                        let t = &*timeline.cast::<ffi::spPathConstraintSpacingTimeline>();
+                        timelines.path(t.pathConstraintIndex, Timeline::PathSpacing(t));
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_PATHCONSTRAINTMIX => {
+                        let t = &*timeline.cast::<ffi::spPathConstraintMixTimeline>();
+                        timelines.path(t.pathConstraintIndex, Timeline::PathMix(t));
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_DEFORM => {
+                        let t = &*timeline.cast::<ffi::spDeformTimeline>();
+                        let slot = count(t.slotIndex);
+                        match find_entry(skins, t.attachment, Some(slot)) {
+                            Some(entry) => timelines
+                                .deform
+                                .entry(entry.skin)
+                                .or_default()
+                                .entry(slot)
+                                .or_default()
+                                .push((entry.name, t)),
+                            None => spine_log!(
+                                warn,
+                                "skipping a deform timeline of an attachment in no skin"
+                            ),
+                        }
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_DRAWORDER => {
+                        timelines.draw_order = Some(&*timeline.cast::<ffi::spDrawOrderTimeline>());
+                    }
+                    ffi::spTimelineType_SP_TIMELINE_EVENT => {
+                        timelines.events = Some(&*timeline.cast::<ffi::spEventTimeline>());
+                    }
+                    _ => spine_log!(warn, "skipping a timeline of unknown type {}", kind),
+                }
+            }
+        }
+        timelines
+    }
+
+    fn slot(&mut self, index: c_int, timeline: Timeline<'a>) {
+        self.slots.entry(count(index)).or_default().push(timeline);
+    }
+
+    fn path(&mut self, index: c_int, timeline: Timeline<'a>) {
+        self.paths.entry(count(index)).or_default().push(timeline);
+    }
+}
+
+/// The changed part of a deform key frame: the offset of the first changed value, and the
+/// values up to the last changed one, relative to the setup vertices.
+fn deform_delta(frame: &[f32], setup: &SetupVertices<'_>) -> (usize, Vec<f32>) {
+    let delta: Vec<f32> = if setup.weighted() {
+        frame.to_vec()
+    } else {
+        frame
+            .iter()
+            .zip(setup.vertices)
+            .map(|(value, setup)| value - setup)
+            .collect()
+    };
+    match delta.iter().position(|value| *value != 0.0) {
+        Some(start) => {
+            let end = delta.iter().rposition(|value| *value != 0.0).unwrap() + 1;
+            (start, delta[start..end].to_vec())
+        }
+        None => (0, Vec::new()),
+    }
+}
+
+/// The slots moved by a draw order key frame, as `(slot, offset)` pairs.
+///
+/// Both formats fill in the slots left out in setup order, so the longest run of slots still in
+/// setup order relative to each other is left out.
+fn draw_order_offsets(order: &[c_int]) -> Vec<(usize, i64)> {
+    let slots: Vec<usize> = order.iter().map(|slot| count(*slot)).collect();
+    let mut length = vec![1; slots.len()];
+    let mut previous = vec![None; slots.len()];
+    for position in 0..slots.len() {
+        for before in 0..position {
+            if slots[before] < slots[position] && length[before] + 1 > length[position] {
+                length[position] = length[before] + 1;
+                previous[position] = Some(before);
+            }
+        }
+    }
+    let mut kept = vec![false; slots.len()];
+    let mut position = (0..slots.len()).max_by_key(|position| length[*position]);
+    while let Some(p) = position {
+        kept[p] = true;
+        position = previous[p];
+    }
+
+    let mut offsets: Vec<_> = slots
+        .iter()
+        .enumerate()
+        .filter(|(position, _)| !kept[*position])
+        .map(|(position, slot)| {
+            let offset = i64::try_from(position).unwrap() - i64::try_from(*slot).unwrap();
+            (*slot, offset)
+        })
+        .collect();
+    offsets.sort_unstable();
+    offsets
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{
+        animation::{AnimationEvent, AnimationState, AnimationStateData, TrackIndex},
+        atlas::Atlas,
+        skeleton::{Skeleton, Slot, WorldVertex},
+        snapshot::PoseSnapshot,
+        tests::{skeleton_data_from_json, TEST_CASES},
+    };
+    use std::path::Path;
+
+    fn load(path: &Path) -> SkeletonData {
+        let atlas = Atlas::from_file(TEST_CASES[0].atlas(), |_, _| 0).unwrap();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            SkeletonData::from_json_file(path, atlas).unwrap()
+        } else {
+            unsafe { SkeletonData::from_binary_file(path, atlas) }.unwrap()
+        }
+    }
+
+    fn reload(bytes: &[u8], file_name: &str) -> SkeletonData {
+        let path =
+            std::env::temp_dir().join(format!("spine-convert-{}-{file_name}", std::process::id()));
+        std::fs::write(&path, bytes).unwrap();
+        let data = load(&path);
+        std::fs::remove_file(&path).unwrap();
+        data
+    }
+
+    /// Every skin posed by every animation at its start, end and two times in between, with the
+    /// world vertices of the drawn slots and the events fired on the way.
+    fn poses(
+        data: &SkeletonData,
+    ) -> Vec<(PoseSnapshot, Vec<Vec<WorldVertex>>, Vec<AnimationEvent>)> {
+        let state_data = AnimationStateData::new(data);
+        let mut poses = Vec::new();
+        for skin in data.skins() {
+            for animation in data.animations() {
+                for step in 0..4_u8 {
+                    let mut skeleton = Skeleton::new(data);
+                    assert!(skeleton.set_skin_by_name(skin.name()));
+                    let mut state = AnimationState::new(&state_data);
+                    state.set_record_events(true);
+                    state.set_by_name(animation.name(), TrackIndex::zero(), false);
+                    state.update(animation.duration() * f32::from(step) / 3.0);
+                    state.apply(&mut skeleton);
+                    skeleton.update_world_transforms();
+
+                    let vertices = skeleton
+                        .draw_slots()
+                        .iter()
+                        .map(Slot::world_vertices)
+                        .collect();
+                    poses.push((skeleton.pose_snapshot(), vertices, state.take_events()));
+                }
+            }
+        }
+        poses
+    }
+
+    /// Compares what spine-c loaded from both, so the writers are not their own oracle.
+    fn assert_same_data(expected: &SkeletonData, actual: &SkeletonData) {
+        assert_eq!(expected.to_snapshot(), actual.to_snapshot());
+        assert_eq!(poses(expected), poses(actual));
+    }
+
+    #[test]
+    fn round_trip() {
+        let test_case = &TEST_CASES[0];
+        let original = load(&test_case.json());
+        let expected = to_json(&original);
+
+        // The JSON writer writes out every bone, slot, skin, attachment and key frame, and the
+        // poses catch anything it does not.
+        let binary = reload(&to_binary(&original), "dragon.skel");
+        assert_eq!(expected, to_json(&binary));
+        assert_same_data(&original, &binary);
+        let json = reload(expected.as_bytes(), "dragon.json");
+        assert_eq!(expected, to_json(&json));
+        assert_same_data(&original, &json);
+
+        // The binary export of the editor survives the same trip.
+        let exported = load(&test_case.binary());
+        let expected = to_json(&exported);
+        let binary = reload(&to_binary(&exported), "exported.skel");
+        assert_eq!(expected, to_json(&binary));
+        assert_same_data(&exported, &binary);
+        let json = reload(expected.as_bytes(), "exported.json");
+        assert_eq!(expected, to_json(&json));
+        assert_same_data(&exported, &json);
+    }
+
+    /// A rig with the features the dragon does not use: meshes, paths, clipping, constraints,
+    /// events, and the timelines of all of them.
+    pub const FEATURES: &str = r#"{
+        "skeleton": { "hash": "features", "spine": "3.8.55" },
+        "bones": [
+            { "name": "root" },
+            { "name": "arm", "parent": "root", "length": 40, "rotation": 30, "transform": "noScale", "skin": true },
+            { "name": "hand", "parent": "arm", "x": 40 },
+            { "name": "target", "parent": "root", "x": 60, "y": 20 }
+        ],
+        "slots": [
+            { "name": "body", "bone": "root", "attachment": "body", "color": "ff8080c0", "dark": "102030", "blend": "additive" },
+            { "name": "cape", "bone": "root", "attachment": "cape", "dark": "000000" },
+            { "name": "rail", "bone": "root", "attachment": "rail" },
+            { "name": "mask", "bone": "root", "attachment": "mask" },
+            { "name": "muzzle", "bone": "hand", "attachment": "muzzle" },
+            { "name": "hitbox", "bone": "arm", "attachment": "hitbox" }
+        ],
+        "ik": [ { "name": "reach", "bones": [ "arm", "hand" ], "target": "target", "mix": 0.5, "bendPositive": false, "stretch": true, "skin": true } ],
+        "transform": [ { "name": "follow", "order": 1, "bones": [ "hand" ], "target": "target", "rotation": 10, "x": 2, "rotateMix": 0.5, "shearMix": 0, "local": true } ],
+        "path": [ { "name": "ride", "order": 2, "bones": [ "target" ], "target": "rail", "spacingMode": "fixed", "rotateMode": "chain", "position": 0.25, "spacing": 3 } ],
+        "skins": [
+            { "name": "default", "attachments": {
+                "body": { "body": { "name": "torso", "path": "head", "x": 5, "rotation": 12.5, "width": 100, "height": 80, "color": "ffffff80" } },
+                "cape": { "cape": {
+                    "type": "mesh", "path": "chin", "width": 10, "height": 10,
+                    "uvs": [ 0, 0, 1, 0, 1, 1, 0, 1 ], "triangles": [ 0, 1, 2, 2, 3, 0 ],
+                    "vertices": [ 1, 0, 0, 0, 1, 2, 0, 10, 0, 0.5, 1, 0, 0, 0.5, 1, 1, 10, 10, 1, 1, 1, 0, 10, 1 ],
+                    "hull": 4
+                } },
+                "rail": { "rail": {
+                    "type": "path", "closed": true, "constantSpeed": false, "vertexCount": 6,
+                    "vertices": [ 0, 0, 10, 0, 20, 0, 20, 10, 10, 10, 0, 10 ], "lengths": [ 25, 50 ]
+                } },
+                "mask": { "mask": { "type": "clipping", "end": "muzzle", "vertexCount": 3, "vertices": [ 0, 0, 10, 0, 0, 10 ] } },
+                "muzzle": { "muzzle": { "type": "point", "x": 5, "rotation": 90, "color": "ff0000ff" } },
+                "hitbox": { "hitbox": { "type": "boundingbox", "vertexCount": 3, "vertices": [ 0, 0, 4, 0, 0, 4 ] } }
+            } },
+            { "name": "armored", "bones": [ "arm" ], "ik": [ "reach" ], "attachments": {
+                "cape": { "cape": { "type": "linkedmesh", "path": "head", "parent": "cape", "deform": false, "width": 10, "height": 10 } }
+            } }
+        ],
+        "events": {
+            "step": { "int": 3, "string": "left \"foot\"" },
+            "shot": { "audio": "bang.ogg", "volume": 0.5, "balance": -0.25 }
+        },
+        "animations": {
+            "features": {
+                "slots": {
+                    "body": { "color": [ { "color": "ff0000ff", "curve": "stepped" }, { "time": 1, "color": "00ff00ff" } ] },
+                    "cape": { "twoColor": [ { "light": "ffffffff", "dark": "000000", "curve": 0.25, "c3": 0.75 }, { "time": 1, "light": "ff00ffff", "dark": "00ff00" } ] },
+                    "mask": { "attachment": [ { "time": 0.5, "name": null } ] }
+                },
+                "bones": {
+                    "arm": {
+                        "rotate": [ { "angle": 10, "curve": 0.25, "c2": 0.1, "c3": 0.75, "c4": 0.9 }, { "time": 1, "angle": -20 } ],
+                        "scale": [ { "x": 2 } ],
+                        "shear": [ { "time": 0.5, "y": 5 } ]
+                    }
+                },
+                "ik": { "reach": [ { "mix": 0.5, "bendPositive": false }, { "time": 1, "softness": 2, "compress": true, "stretch": true } ] },
+                "transform": { "follow": [ { "rotateMix": 0.5, "translateMix": 0 } ] },
+                "paths": { "ride": {
+                    "position": [ { "position": 0.5 } ],
+                    "spacing": [ { "spacing": 4 } ],
+                    "mix": [ { "rotateMix": 0.5 } ]
+                } },
+                "deform": { "default": { "cape": { "cape": [ { "offset": 2, "vertices": [ 1, 1 ], "curve": "stepped" }, { "time": 1 } ] } } },
+                "drawOrder": [ { "time": 0.5, "offsets": [ { "slot": "muzzle", "offset": -3 } ] }, { "time": 1 } ],
+                "events": [
+                    { "time": 0.5, "name": "step" },
+                    { "time": 1, "name": "step", "int": 4, "string": "right" },
+                    { "time": 1.5, "name": "shot", "volume": 0.75 }
+                ]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn round_trip_features() {
+        let original = skeleton_data_from_json(FEATURES);
+        let expected = to_json(&original);
+        for written in [
+            r#""type": "linkedmesh""#,
+            r#""type": "clipping", "end": "muzzle""#,
+            r#""deform": false"#,
+            r#""string": "left \"foot\"""#,
+            r#"[
+						{ "slot": "muzzle", "offset": -3 }
+					]"#,
+        ] {
+            assert!(expected.contains(written));
+        }
+
+        let binary = reload(&to_binary(&original), "features.skel");
+        assert_eq!(expected, to_json(&binary));
+        assert_same_data(&original, &binary);
+        let json = reload(expected.as_bytes(), "features.json");
+        assert_eq!(expected, to_json(&json));
+        assert_same_data(&original, &json);
+    }
+
+    #[test]
+    fn curves_are_recovered() {
+        let data = load(&TEST_CASES[0].json());
+        let json = to_json(&data);
+        assert!(json.contains(r#""curve": 0.408, "c2": 1.37, "c3": 0.675, "c4": 1.43"#));
+        assert!(json.contains(r#""curve": 0.411, "c3": 0.888, "c4": 0.75"#));
+    }
+
+    #[test]
+    fn draw_order() {
+        assert!(draw_order_offsets(&[0, 1, 2, 3]).is_empty());
+        assert_eq!(vec![(3, -2)], draw_order_offsets(&[0, 3, 1, 2]));
+        assert_eq!(vec![(0, 3)], draw_order_offsets(&[1, 2, 3, 0]));
+        let offsets = draw_order_offsets(&[3, 2, 1, 0]);
+        assert_eq!(vec![(1, 1), (2, -1), (3, -3)], offsets);
+    }
+}
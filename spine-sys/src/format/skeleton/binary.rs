@@ -0,0 +1,649 @@
+use super::{
+    color, count, deform_delta, downcast, draw_order_offsets, items, key_frames, linked_parent,
+    skin_entries, slice, string, Curve, Entry, SetupVertices, Timeline, Timelines,
+};
+use crate::ffi;
+use std::{
+    convert::TryFrom,
+    os::raw::{c_char, c_int},
+};
+
+/// The editor's default bone color, stored with the nonessential data.
+const BONE_COLOR: u32 = 0x9b9b_9bff;
+
+/// A `.skel` file being written, and the table of the strings it refers to.
+#[derive(Default)]
+struct Output {
+    bytes: Vec<u8>,
+    strings: Vec<String>,
+}
+impl Output {
+    fn byte(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn signed_byte(&mut self, value: i8) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn boolean(&mut self, value: bool) {
+        self.byte(value.into());
+    }
+
+    fn int(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn float(&mut self, value: f32) {
+        self.int(value.to_bits());
+    }
+
+    fn floats(&mut self, values: &[f32]) {
+        for value in values {
+            self.float(*value);
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn varint(&mut self, mut value: u32) {
+        while value >= 0x80 {
+            self.byte((value & 0x7f) as u8 | 0x80);
+            value >>= 7;
+        }
+        self.byte(value as u8);
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    fn signed_varint(&mut self, value: c_int) {
+        self.varint(((value << 1) ^ (value >> 31)) as u32);
+    }
+
+    fn count(&mut self, value: usize) {
+        self.varint(u32::try_from(value).unwrap());
+    }
+
+    fn index(&mut self, value: c_int) {
+        self.count(count(value));
+    }
+
+    fn string(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.count(value.len() + 1);
+                self.bytes.extend_from_slice(value.as_bytes());
+            }
+            None => self.byte(0),
+        }
+    }
+
+    fn string_ref(&mut self, value: Option<&str>) {
+        let index = value.map_or(0, |value| {
+            let index = self
+                .strings
+                .iter()
+                .position(|s| s == value)
+                .unwrap_or_else(|| {
+                    self.strings.push(value.to_owned());
+                    self.strings.len() - 1
+                });
+            index + 1
+        });
+        self.count(index);
+    }
+
+    fn color(&mut self, color: &ffi::spColor) {
+        for value in [color.r, color.g, color.b, color.a] {
+            self.byte(super::byte(value));
+        }
+    }
+
+    fn curve(&mut self, curve: Option<Curve>) {
+        match curve {
+            None => {}
+            Some(Curve::Linear) => self.byte(0),
+            Some(Curve::Stepped) => self.byte(1),
+            Some(Curve::Bezier(points)) => {
+                self.byte(2);
+                self.floats(&points);
+            }
+        }
+    }
+
+    /// Writes key frames with their curves, skipping the curve of the last.
+    fn key_frames(
+        &mut self,
+        frames: &[(&[f32], Option<Curve>)],
+        values: impl Fn(&mut Self, &[f32]),
+    ) {
+        self.count(frames.len());
+        for (frame, curve) in frames {
+            self.float(frame[0]);
+            values(self, &frame[1..]);
+            self.curve(*curve);
+        }
+    }
+}
+
+fn name<'a>(name: *const c_char) -> &'a str {
+    unsafe { string(name) }.unwrap()
+}
+
+fn index_of<T>(items: *const *mut T, n: c_int, item: *const T) -> usize {
+    unsafe { slice(items, n) }
+        .iter()
+        .position(|candidate| std::ptr::eq(*candidate, item))
+        .unwrap()
+}
+
+#[allow(clippy::too_many_lines)]
+pub(super) fn write(data: &ffi::spSkeletonData) -> Vec<u8> {
+    let skins = skin_entries(data);
+    let mut body = Output::default();
+
+    body.index(data.bonesCount);
+    for (n, bone) in unsafe { items(data.bones, data.bonesCount) }.enumerate() {
+        body.string(Some(name(bone.name)));
+        if n > 0 {
+            body.index(unsafe { &*bone.parent }.index);
+        }
+        body.floats(&[bone.rotation, bone.x, bone.y, bone.scaleX, bone.scaleY]);
+        body.floats(&[bone.shearX, bone.shearY, bone.length]);
+        body.varint(bone.transformMode);
+        body.boolean(bone.skinRequired != 0);
+        body.int(BONE_COLOR);
+    }
+
+    body.index(data.slotsCount);
+    for slot in unsafe { items(data.slots, data.slotsCount) } {
+        body.string(Some(name(slot.name)));
+        body.index(unsafe { &*slot.boneData }.index);
+        body.color(&slot.color);
+        // The dark color is read as the `argb` of an integer, with all bits set meaning none.
+        match unsafe { slot.darkColor.as_ref() } {
+            Some(dark) => {
+                body.byte(0);
+                for value in [dark.r, dark.g, dark.b] {
+                    body.byte(super::byte(value));
+                }
+            }
+            None => body.int(u32::MAX),
+        }
+        body.string_ref(unsafe { string(slot.attachmentName) });
+        body.varint(slot.blendMode);
+    }
+
+    body.index(data.ikConstraintsCount);
+    for constraint in unsafe { items(data.ikConstraints, data.ikConstraintsCount) } {
+        body.string(Some(name(constraint.name)));
+        body.index(constraint.order);
+        body.boolean(constraint.skinRequired != 0);
+        bones(&mut body, constraint.bones, constraint.bonesCount);
+        body.index(unsafe { &*constraint.target }.index);
+        body.floats(&[constraint.mix, constraint.softness]);
+        body.signed_byte(if constraint.bendDirection < 0 { -1 } else { 1 });
+        body.boolean(constraint.compress != 0);
+        body.boolean(constraint.stretch != 0);
+        body.boolean(constraint.uniform != 0);
+    }
+
+    body.index(data.transformConstraintsCount);
+    for constraint in unsafe { items(data.transformConstraints, data.transformConstraintsCount) } {
+        body.string(Some(name(constraint.name)));
+        body.index(constraint.order);
+        body.boolean(constraint.skinRequired != 0);
+        bones(&mut body, constraint.bones, constraint.bonesCount);
+        body.index(unsafe { &*constraint.target }.index);
+        body.boolean(constraint.local != 0);
+        body.boolean(constraint.relative != 0);
+        body.floats(&[
+            constraint.offsetRotation,
+            constraint.offsetX,
+            constraint.offsetY,
+            constraint.offsetScaleX,
+            constraint.offsetScaleY,
+            constraint.offsetShearY,
+        ]);
+        body.floats(&[
+            constraint.rotateMix,
+            constraint.translateMix,
+            constraint.scaleMix,
+            constraint.shearMix,
+        ]);
+    }
+
+    body.index(data.pathConstraintsCount);
+    for constraint in unsafe { items(data.pathConstraints, data.pathConstraintsCount) } {
+        body.string(Some(name(constraint.name)));
+        body.index(constraint.order);
+        body.boolean(constraint.skinRequired != 0);
+        bones(&mut body, constraint.bones, constraint.bonesCount);
+        body.index(unsafe { &*constraint.target }.index);
+        body.varint(constraint.positionMode);
+        body.varint(constraint.spacingMode);
+        body.varint(constraint.rotateMode);
+        body.floats(&[
+            constraint.offsetRotation,
+            constraint.position,
+            constraint.spacing,
+            constraint.rotateMix,
+            constraint.translateMix,
+        ]);
+    }
+
+    // The default skin comes first, without a name, and is only kept when it has attachments.
+    let default_skin = (!data.defaultSkin.is_null())
+        .then(|| index_of(data.skins, data.skinsCount, data.defaultSkin))
+        .filter(|default_skin| !skins[*default_skin].is_empty());
+    let skin_order: Vec<usize> = default_skin
+        .into_iter()
+        .chain((0..skins.len()).filter(|skin| Some(*skin) != default_skin))
+        .collect();
+    match default_skin {
+        Some(default_skin) => skin_attachments(&mut body, data, &skins, &skins[default_skin]),
+        None => body.count(0),
+    }
+    body.count(skin_order.len() - usize::from(default_skin.is_some()));
+    for skin_index in &skin_order[usize::from(default_skin.is_some())..] {
+        let skin = unsafe { &**data.skins.add(*skin_index) };
+        body.string_ref(unsafe { string(skin.name) });
+        unsafe {
+            let bones = &*skin.bones;
+            self::bones(&mut body, bones.items, bones.size);
+            let ik = &*skin.ikConstraints;
+            body.index(ik.size);
+            for constraint in slice(ik.items, ik.size) {
+                body.count(index_of(
+                    data.ikConstraints,
+                    data.ikConstraintsCount,
+                    *constraint,
+                ));
+            }
+            let transform = &*skin.transformConstraints;
+            body.index(transform.size);
+            for constraint in slice(transform.items, transform.size) {
+                let constraints = data.transformConstraints;
+                body.count(index_of(
+                    constraints,
+                    data.transformConstraintsCount,
+                    *constraint,
+                ));
+            }
+            let path = &*skin.pathConstraints;
+            body.index(path.size);
+            for constraint in slice(path.items, path.size) {
+                body.count(index_of(
+                    data.pathConstraints,
+                    data.pathConstraintsCount,
+                    *constraint,
+                ));
+            }
+        }
+        skin_attachments(&mut body, data, &skins, &skins[*skin_index]);
+    }
+
+    body.index(data.eventsCount);
+    for event in unsafe { items(data.events, data.eventsCount) } {
+        body.string_ref(Some(name(event.name)));
+        body.signed_varint(event.intValue);
+        body.float(event.floatValue);
+        body.string(unsafe { string(event.stringValue) });
+        let audio = unsafe { string(event.audioPath) };
+        body.string(audio);
+        if audio.is_some() {
+            body.floats(&[event.volume, event.balance]);
+        }
+    }
+
+    body.index(data.animationsCount);
+    for animation in unsafe { items(data.animations, data.animationsCount) } {
+        body.string(Some(name(animation.name)));
+        self::animation(&mut body, data, &skins, &skin_order, animation);
+    }
+
+    let mut out = Output::default();
+    // spine-c expects a hash and version, even if empty.
+    out.string(Some(unsafe { string(data.hash) }.unwrap_or_default()));
+    out.string(Some(unsafe { string(data.version) }.unwrap_or_default()));
+    out.floats(&[data.x, data.y, data.width, data.height]);
+    // Nonessential data carries the mesh edges and sizes, which spine-c keeps.
+    out.boolean(true);
+    out.float(30.0);
+    out.string(None);
+    out.string(None);
+    out.count(body.strings.len());
+    for string in &body.strings {
+        out.string(Some(string));
+    }
+    out.bytes.extend(body.bytes);
+    out.bytes
+}
+
+fn bones(out: &mut Output, bones: *const *mut ffi::spBoneData, n: c_int) {
+    out.index(n);
+    for bone in unsafe { items(bones, n) } {
+        out.index(bone.index);
+    }
+}
+
+fn skin_attachments(
+    out: &mut Output,
+    data: &ffi::spSkeletonData,
+    skins: &[Vec<Entry<'_>>],
+    entries: &[Entry<'_>],
+) {
+    let slots: Vec<_> = entries.chunk_by(|a, b| a.slot == b.slot).collect();
+    out.count(slots.len());
+    for slot_entries in slots {
+        out.count(slot_entries[0].slot);
+        out.count(slot_entries.len());
+        for entry in slot_entries {
+            out.string_ref(Some(entry.name));
+            attachment(out, data, skins, entry);
+        }
+    }
+}
+
+fn attachment(
+    out: &mut Output,
+    data: &ffi::spSkeletonData,
+    skins: &[Vec<Entry<'_>>],
+    entry: &Entry<'_>,
+) {
+    let attachment = entry.attachment;
+    let attachment_name = name(attachment.name);
+    out.string_ref(Some(attachment_name).filter(|name| *name != entry.name));
+    let path = |out: &mut Output, path: *const c_char| {
+        out.string_ref(Some(name(path)).filter(|path| *path != attachment_name));
+    };
+
+    match attachment.type_ {
+        ffi::spAttachmentType_SP_ATTACHMENT_REGION => {
+            let region = unsafe { downcast::<ffi::spRegionAttachment>(attachment) };
+            out.byte(0);
+            path(out, region.path);
+            out.floats(&[
+                region.rotation,
+                region.x,
+                region.y,
+                region.scaleX,
+                region.scaleY,
+            ]);
+            out.floats(&[region.width, region.height]);
+            out.color(&region.color);
+        }
+        ffi::spAttachmentType_SP_ATTACHMENT_BOUNDING_BOX => {
+            let bounding_box = unsafe { downcast::<ffi::spVertexAttachment>(attachment) };
+            out.byte(1);
+            out.count(SetupVertices::new(bounding_box).count);
+            vertices(out, bounding_box);
+            out.int(u32::MAX);
+        }
+        ffi::spAttachmentType_SP_ATTACHMENT_MESH
+        | ffi::spAttachmentType_SP_ATTACHMENT_LINKED_MESH => {
+            let mesh = unsafe { downcast::<ffi::spMeshAttachment>(attachment) };
+            if mesh.parentMesh.is_null() {
+                out.byte(2);
+                path(out, mesh.path);
+                out.color(&mesh.color);
+                let vertex_count = SetupVertices::new(&mesh.super_).count;
+                out.count(vertex_count);
+                out.floats(unsafe { slice(mesh.regionUVs, mesh.super_.worldVerticesLength) });
+                let triangles = unsafe { slice(mesh.triangles, mesh.trianglesCount) };
+                out.count(triangles.len());
+                for triangle in triangles {
+                    out.bytes.extend_from_slice(&triangle.to_be_bytes());
+                }
+                vertices(out, &mesh.super_);
+                out.index(mesh.hullLength / 2);
+                // spine-c reads edges as shorts into an array of ints, so none are written.
+                out.count(0);
+            } else {
+                out.byte(3);
+                path(out, mesh.path);
+                out.color(&mesh.color);
+                let (skin, parent) = linked_parent(data, skins, mesh, entry.slot);
+                out.string_ref(skin);
+                out.string_ref(Some(parent));
+                out.boolean(!std::ptr::eq(
+                    mesh.super_.deformAttachment,
+                    std::ptr::addr_of!(mesh.super_),
+                ));
+            }
+            out.floats(&[mesh.width, mesh.height]);
+        }
+        ffi::spAttachmentType_SP_ATTACHMENT_PATH => {
+            let path = unsafe { downcast::<ffi::spPathAttachment>(attachment) };
+            out.byte(4);
+            out.boolean(path.closed != 0);
+            out.boolean(path.constantSpeed != 0);
+            out.count(SetupVertices::new(&path.super_).count);
+            vertices(out, &path.super_);
+            out.floats(unsafe { slice(path.lengths, path.lengthsLength) });
+            out.int(u32::MAX);
+        }
+        ffi::spAttachmentType_SP_ATTACHMENT_POINT => {
+            let point = unsafe { downcast::<ffi::spPointAttachment>(attachment) };
+            out.byte(5);
+            out.floats(&[point.rotation, point.x, point.y]);
+            out.color(&point.color);
+        }
+        ffi::spAttachmentType_SP_ATTACHMENT_CLIPPING => {
+            let clipping = unsafe { downcast::<ffi::spClippingAttachment>(attachment) };
+            out.byte(6);
+            // The binary format cannot leave out the end slot, so clip to the last slot instead.
+            let end =
+                unsafe { clipping.endSlot.as_ref() }.map_or(data.slotsCount - 1, |end| end.index);
+            out.index(end);
+            out.count(SetupVertices::new(&clipping.super_).count);
+            vertices(out, &clipping.super_);
+            out.int(u32::MAX);
+        }
+        kind => spine_log!(warn, "writing an attachment of unknown type {}", kind),
+    }
+}
+
+fn vertices(out: &mut Output, attachment: &ffi::spVertexAttachment) {
+    let vertices = SetupVertices::new(attachment);
+    out.boolean(vertices.weighted());
+    if vertices.weighted() {
+        for vertex in vertices.weights() {
+            out.count(vertex.len());
+            for (bone, weight) in vertex {
+                out.index(bone);
+                out.floats(weight);
+            }
+        }
+    } else {
+        out.floats(vertices.vertices);
+    }
+}
+
+#[allow(clippy::too_many_lines, clippy::cast_sign_loss)]
+fn animation(
+    out: &mut Output,
+    data: &ffi::spSkeletonData,
+    skins: &[Vec<Entry<'_>>],
+    skin_order: &[usize],
+    animation: &ffi::spAnimation,
+) {
+    let timelines = Timelines::new(animation, skins);
+
+    out.count(timelines.slots.len());
+    for (slot, slot_timelines) in &timelines.slots {
+        out.count(*slot);
+        out.count(slot_timelines.len());
+        for timeline in slot_timelines {
+            match timeline {
+                Timeline::Attachment(t) => {
+                    out.byte(0);
+                    out.index(t.framesCount);
+                    let times = unsafe { slice(t.frames, t.framesCount) };
+                    let names = unsafe { slice(t.attachmentNames, t.framesCount) };
+                    for (time, attachment) in times.iter().zip(names) {
+                        out.float(*time);
+                        out.string_ref(unsafe { string(*attachment) });
+                    }
+                }
+                Timeline::Color(t) => {
+                    out.byte(1);
+                    let frames = key_frames(&t.super_, t.frames, t.framesCount, 5);
+                    out.key_frames(&frames, |out, values| out.color(&color(values)));
+                }
+                Timeline::TwoColor(t) => {
+                    out.byte(2);
+                    let frames = key_frames(&t.super_, t.frames, t.framesCount, 8);
+                    out.key_frames(&frames, |out, values| {
+                        out.color(&color(&values[..4]));
+                        // The dark color is read as `argb`, ignoring the alpha.
+                        out.byte(0xff);
+                        for value in &values[4..] {
+                            out.byte(super::byte(*value));
+                        }
+                    });
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    out.count(timelines.bones.len());
+    for (bone, bone_timelines) in &timelines.bones {
+        out.count(*bone);
+        out.count(bone_timelines.len());
+        for timeline in bone_timelines {
+            let (kind, t, entries) = match timeline {
+                Timeline::Rotate(t) => (0, t, 2),
+                Timeline::Translate(t) => (1, t, 3),
+                Timeline::Scale(t) => (2, t, 3),
+                Timeline::Shear(t) => (3, t, 3),
+                _ => unreachable!(),
+            };
+            out.byte(kind);
+            let frames = key_frames(&t.super_, t.frames, t.framesCount, entries);
+            out.key_frames(&frames, Output::floats);
+        }
+    }
+
+    out.count(timelines.ik.len());
+    for t in &timelines.ik {
+        out.index(t.ikConstraintIndex);
+        let frames = key_frames(&t.super_, t.frames, t.framesCount, 6);
+        out.key_frames(&frames, |out, values| {
+            out.floats(&values[..2]);
+            out.signed_byte(if values[2] < 0.0 { -1 } else { 1 });
+            out.boolean(values[3] != 0.0);
+            out.boolean(values[4] != 0.0);
+        });
+    }
+
+    out.count(timelines.transform.len());
+    for t in &timelines.transform {
+        out.index(t.transformConstraintIndex);
+        let frames = key_frames(&t.super_, t.frames, t.framesCount, 5);
+        out.key_frames(&frames, Output::floats);
+    }
+
+    out.count(timelines.paths.len());
+    for (path, path_timelines) in &timelines.paths {
+        out.count(*path);
+        out.count(path_timelines.len());
+        for timeline in path_timelines {
+            let frames = match timeline {
+                Timeline::PathPosition(t) => {
+                    out.byte(0);
+                    key_frames(&t.super_, t.frames, t.framesCount, 2)
+                }
+                Timeline::PathSpacing(t) => {
+                    out.byte(1);
+                    key_frames(&t.super_, t.frames, t.framesCount, 2)
+                }
+                Timeline::PathMix(t) => {
+                    out.byte(2);
+                    key_frames(&t.super_, t.frames, t.framesCount, 3)
+                }
+                _ => unreachable!(),
+            };
+            out.key_frames(&frames, Output::floats);
+        }
+    }
+
+    out.count(timelines.deform.len());
+    for (skin, skin_timelines) in &timelines.deform {
+        out.count(skin_order.iter().position(|s| s == skin).unwrap());
+        out.count(skin_timelines.len());
+        for (slot, slot_timelines) in skin_timelines {
+            out.count(*slot);
+            out.count(slot_timelines.len());
+            for (attachment, t) in slot_timelines {
+                out.string_ref(Some(attachment));
+                let setup =
+                    SetupVertices::new(unsafe { &*t.attachment.cast::<ffi::spVertexAttachment>() });
+                let times = unsafe { slice(t.frames, t.framesCount) };
+                let vertices = unsafe { slice(t.frameVertices, t.framesCount) };
+                out.count(times.len());
+                for (n, (time, frame)) in times.iter().zip(vertices).enumerate() {
+                    out.float(*time);
+                    let (start, delta) =
+                        deform_delta(unsafe { slice(*frame, t.frameVerticesCount) }, &setup);
+                    out.count(delta.len());
+                    if !delta.is_empty() {
+                        out.count(start);
+                        out.floats(&delta);
+                    }
+                    if n + 1 < times.len() {
+                        out.curve(Some(super::curve(&t.super_, n)));
+                    }
+                }
+            }
+        }
+    }
+
+    match timelines.draw_order {
+        Some(t) => {
+            out.index(t.framesCount);
+            let times = unsafe { slice(t.frames, t.framesCount) };
+            let orders = unsafe { slice(t.drawOrders, t.framesCount) };
+            for (time, order) in times.iter().zip(orders) {
+                out.float(*time);
+                let offsets = if order.is_null() {
+                    Vec::new()
+                } else {
+                    draw_order_offsets(unsafe { slice(*order, t.slotsCount) })
+                };
+                out.count(offsets.len());
+                for (slot, offset) in offsets {
+                    out.count(slot);
+                    // Negative offsets are read back from their two's complement.
+                    out.varint(i32::try_from(offset).unwrap() as u32);
+                }
+            }
+        }
+        None => out.count(0),
+    }
+
+    match timelines.events {
+        Some(t) => {
+            out.index(t.framesCount);
+            for event in unsafe { items(t.events, t.framesCount) } {
+                let event_data = unsafe { &*event.data };
+                out.float(event.time);
+                out.count(index_of(data.events, data.eventsCount, event.data));
+                out.signed_varint(event.intValue);
+                out.float(event.floatValue);
+                let value = unsafe { string(event.stringValue) };
+                // spine-c copies the event data string when none is read, which crashes when the
+                // event data has no string, so the value is always written then.
+                let changed = event_data.stringValue.is_null()
+                    || value != unsafe { string(event_data.stringValue) };
+                out.boolean(changed);
+                if changed {
+                    out.string(value);
+                }
+                if !event_data.audioPath.is_null() {
+                    out.floats(&[event.volume, event.balance]);
+                }
+            }
+        }
+        None => out.count(0),
+    }
+}
@@ -0,0 +1,842 @@
+use super::{
+    color, count, deform_delta, draw_order_offsets, hex, is_white, items, key_frames,
+    linked_parent, skin_entries, slice, string, Curve, Entry, SetupVertices, Timeline, Timelines,
+};
+use crate::ffi;
+use std::{
+    convert::TryFrom,
+    fmt::Write,
+    os::raw::{c_char, c_int},
+};
+
+const TRANSFORM_MODES: [&str; 5] = [
+    "normal",
+    "onlyTranslation",
+    "noRotationOrReflection",
+    "noScale",
+    "noScaleOrReflection",
+];
+const BLEND_MODES: [&str; 4] = ["normal", "additive", "multiply", "screen"];
+const POSITION_MODES: [&str; 2] = ["fixed", "percent"];
+const SPACING_MODES: [&str; 3] = ["length", "fixed", "percent"];
+const ROTATE_MODES: [&str; 3] = ["tangent", "chain", "chainScale"];
+
+fn mode(names: &[&'static str], value: u32) -> &'static str {
+    names[usize::try_from(value).unwrap()]
+}
+
+fn name<'a>(name: *const c_char) -> &'a str {
+    unsafe { string(name) }.unwrap()
+}
+
+/// A JSON value.
+enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f32),
+    String(String),
+    Array(Vec<Value>),
+    Object(Object),
+}
+impl Value {
+    fn floats(values: &[f32]) -> Self {
+        Self::Array(values.iter().map(|value| Self::Float(*value)).collect())
+    }
+
+    fn names<T>(values: impl Iterator<Item = T>, name_of: impl Fn(T) -> *const c_char) -> Self {
+        Self::Array(values.map(|value| name(name_of(value)).into()).collect())
+    }
+
+    fn is_scalar(&self) -> bool {
+        !matches!(self, Self::Array(_) | Self::Object(_))
+    }
+
+    /// Whether the value is written on one line: scalars, arrays of scalars, and objects of
+    /// those, like key frames.
+    fn is_inline(&self) -> bool {
+        match self {
+            Self::Array(items) => items.iter().all(Self::is_scalar),
+            Self::Object(object) => object.0.iter().all(|(_, value)| match value {
+                Self::Array(_) => value.is_inline(),
+                _ => value.is_scalar(),
+            }),
+            _ => true,
+        }
+    }
+
+    /// Writes the value laid out like the Spine editor's exports, indented with tabs.
+    fn write(&self, out: &mut String, indent: usize) {
+        match self {
+            Self::Null => out.push_str("null"),
+            Self::Bool(value) => write!(out, "{value}").unwrap(),
+            Self::Int(value) => write!(out, "{value}").unwrap(),
+            Self::Float(value) => write!(out, "{value}").unwrap(),
+            Self::String(value) => {
+                out.push('"');
+                for c in value.chars() {
+                    match c {
+                        '"' => out.push_str("\\\""),
+                        '\\' => out.push_str("\\\\"),
+                        '\n' => out.push_str("\\n"),
+                        '\r' => out.push_str("\\r"),
+                        '\t' => out.push_str("\\t"),
+                        c if c.is_control() => write!(out, "\\u{:04x}", u32::from(c)).unwrap(),
+                        c => out.push(c),
+                    }
+                }
+                out.push('"');
+            }
+            Self::Array(items) if items.is_empty() => out.push_str("[]"),
+            Self::Object(object) if object.0.is_empty() => out.push_str("{}"),
+            Self::Array(items) => {
+                Self::write_items(out, indent, self.is_inline(), '[', items, |out, item| {
+                    item.write(out, indent + 1);
+                });
+            }
+            Self::Object(object) => Self::write_items(
+                out,
+                indent,
+                self.is_inline(),
+                '{',
+                &object.0,
+                |out, (key, value)| {
+                    Self::String(key.clone()).write(out, indent + 1);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                },
+            ),
+        }
+    }
+
+    fn write_items<T>(
+        out: &mut String,
+        indent: usize,
+        inline: bool,
+        open: char,
+        items: &[T],
+        mut write_item: impl FnMut(&mut String, &T),
+    ) {
+        let close = if open == '[' { ']' } else { '}' };
+        out.push(open);
+        for (n, item) in items.iter().enumerate() {
+            if n > 0 {
+                out.push(',');
+            }
+            if inline {
+                out.push(' ');
+            } else {
+                out.push('\n');
+                out.extend(std::iter::repeat_n('\t', indent + 1));
+            }
+            write_item(out, item);
+        }
+        if inline {
+            out.push(' ');
+        } else {
+            out.push('\n');
+            out.extend(std::iter::repeat_n('\t', indent));
+        }
+        out.push(close);
+    }
+}
+#[cfg(feature = "json")]
+impl From<serde_json::Value> for Value {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Self::Null,
+            serde_json::Value::Bool(value) => Self::Bool(value),
+            // The floats come from the `f32` fields of `format::json`, so narrowing is exact.
+            serde_json::Value::Number(number) => number.as_i64().map_or_else(
+                || Self::Float(number.as_f64().unwrap_or_default() as f32),
+                Self::Int,
+            ),
+            serde_json::Value::String(value) => Self::String(value),
+            serde_json::Value::Array(items) => {
+                Self::Array(items.into_iter().map(Self::from).collect())
+            }
+            serde_json::Value::Object(map) => Self::Object(Object(
+                map.into_iter()
+                    .map(|(key, value)| (key, value.into()))
+                    .collect(),
+            )),
+        }
+    }
+}
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+impl From<c_int> for Value {
+    fn from(value: c_int) -> Self {
+        Self::Int(value.into())
+    }
+}
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Self::Float(value)
+    }
+}
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+impl From<Option<&str>> for Value {
+    fn from(value: Option<&str>) -> Self {
+        value.map_or(Self::Null, Self::from)
+    }
+}
+impl From<Object> for Value {
+    fn from(value: Object) -> Self {
+        Self::Object(value)
+    }
+}
+impl From<Vec<Value>> for Value {
+    fn from(value: Vec<Value>) -> Self {
+        Self::Array(value)
+    }
+}
+
+/// A JSON object, keeping its keys in insertion order.
+#[derive(Default)]
+struct Object(Vec<(String, Value)>);
+impl Object {
+    fn set(&mut self, key: &str, value: impl Into<Value>) {
+        self.0.push((key.to_owned(), value.into()));
+    }
+
+    /// Sets `key` unless `value` is what spine-c reads when the key is missing.
+    #[allow(clippy::float_cmp)]
+    fn float(&mut self, key: &str, value: f32, default: f32) {
+        if value != default {
+            self.set(key, value);
+        }
+    }
+
+    fn int(&mut self, key: &str, value: c_int, default: c_int) {
+        if value != default {
+            self.set(key, value);
+        }
+    }
+
+    fn flag(&mut self, key: &str, value: bool, default: bool) {
+        if value != default {
+            self.set(key, value);
+        }
+    }
+
+    fn string(&mut self, key: &str, value: Option<&str>) {
+        if let Some(value) = value {
+            self.set(key, value);
+        }
+    }
+
+    fn color(&mut self, key: &str, color: &ffi::spColor) {
+        if !is_white(color) {
+            self.set(key, hex(color, true).as_str());
+        }
+    }
+
+    /// Sets `key` unless `values` is empty.
+    fn array(&mut self, key: &str, values: Value) {
+        if !matches!(&values, Value::Array(items) if items.is_empty()) {
+            self.set(key, values);
+        }
+    }
+
+    /// Sets `key` unless `object` is empty.
+    fn object(&mut self, key: &str, object: Self) {
+        if !object.0.is_empty() {
+            self.set(key, object);
+        }
+    }
+}
+
+/// Lays out `value` like the Spine editor's exports.
+#[cfg(feature = "json")]
+pub(crate) fn layout(value: serde_json::Value) -> String {
+    let mut out = String::new();
+    Value::from(value).write(&mut out, 0);
+    out.push('\n');
+    out
+}
+
+pub(super) fn write(data: &ffi::spSkeletonData) -> String {
+    let skins = skin_entries(data);
+    let mut root = Object::default();
+
+    let mut skeleton = Object::default();
+    skeleton.string("hash", unsafe { string(data.hash) });
+    skeleton.set("spine", unsafe { string(data.version) }.unwrap_or_default());
+    skeleton.set("x", data.x);
+    skeleton.set("y", data.y);
+    skeleton.set("width", data.width);
+    skeleton.set("height", data.height);
+    root.set("skeleton", skeleton);
+
+    let bones = unsafe { items(data.bones, data.bonesCount) };
+    root.set("bones", bones.map(bone).collect::<Vec<_>>());
+    let slots = unsafe { items(data.slots, data.slotsCount) };
+    root.array("slots", Value::Array(slots.map(slot).collect()));
+    let ik = unsafe { items(data.ikConstraints, data.ikConstraintsCount) };
+    root.array("ik", Value::Array(ik.map(ik_constraint).collect()));
+    let transform = unsafe { items(data.transformConstraints, data.transformConstraintsCount) };
+    root.array(
+        "transform",
+        Value::Array(transform.map(transform_constraint).collect()),
+    );
+    let path = unsafe { items(data.pathConstraints, data.pathConstraintsCount) };
+    root.array("path", Value::Array(path.map(path_constraint).collect()));
+
+    let skin_values = unsafe { items(data.skins, data.skinsCount) }
+        .zip(&skins)
+        .map(|(skin, entries)| self::skin(data, &skins, skin, entries))
+        .collect();
+    root.array("skins", Value::Array(skin_values));
+
+    let mut events = Object::default();
+    for event in unsafe { items(data.events, data.eventsCount) } {
+        events.set(name(event.name), event_data(event));
+    }
+    root.object("events", events);
+
+    let mut animations = Object::default();
+    for animation in unsafe { items(data.animations, data.animationsCount) } {
+        animations.set(
+            name(animation.name),
+            self::animation(data, &skins, animation),
+        );
+    }
+    root.object("animations", animations);
+
+    let mut out = String::new();
+    Value::Object(root).write(&mut out, 0);
+    out.push('\n');
+    out
+}
+
+fn bone(bone: &ffi::spBoneData) -> Value {
+    let mut object = Object::default();
+    object.set("name", name(bone.name));
+    if let Some(parent) = unsafe { bone.parent.as_ref() } {
+        object.set("parent", name(parent.name));
+    }
+    object.float("length", bone.length, 0.0);
+    object.float("rotation", bone.rotation, 0.0);
+    object.float("x", bone.x, 0.0);
+    object.float("y", bone.y, 0.0);
+    object.float("scaleX", bone.scaleX, 1.0);
+    object.float("scaleY", bone.scaleY, 1.0);
+    object.float("shearX", bone.shearX, 0.0);
+    object.float("shearY", bone.shearY, 0.0);
+    if bone.transformMode != ffi::spTransformMode_SP_TRANSFORMMODE_NORMAL {
+        object.set("transform", mode(&TRANSFORM_MODES, bone.transformMode));
+    }
+    object.flag("skin", bone.skinRequired != 0, false);
+    object.into()
+}
+
+fn slot(slot: &ffi::spSlotData) -> Value {
+    let mut object = Object::default();
+    object.set("name", name(slot.name));
+    object.set("bone", name(unsafe { &*slot.boneData }.name));
+    object.color("color", &slot.color);
+    if let Some(dark) = unsafe { slot.darkColor.as_ref() } {
+        object.set("dark", hex(dark, false).as_str());
+    }
+    object.string("attachment", unsafe { string(slot.attachmentName) });
+    if slot.blendMode != ffi::spBlendMode_SP_BLEND_MODE_NORMAL {
+        object.set("blend", mode(&BLEND_MODES, slot.blendMode));
+    }
+    object.into()
+}
+
+fn ik_constraint(constraint: &ffi::spIkConstraintData) -> Value {
+    let mut object = Object::default();
+    object.set("name", name(constraint.name));
+    object.int("order", constraint.order, 0);
+    object.flag("skin", constraint.skinRequired != 0, false);
+    let bones = unsafe { items(constraint.bones, constraint.bonesCount) };
+    object.set("bones", Value::names(bones, |bone| bone.name));
+    object.set("target", name(unsafe { &*constraint.target }.name));
+    object.float("mix", constraint.mix, 1.0);
+    object.float("softness", constraint.softness, 0.0);
+    object.flag("bendPositive", constraint.bendDirection > 0, true);
+    object.flag("compress", constraint.compress != 0, false);
+    object.flag("stretch", constraint.stretch != 0, false);
+    object.flag("uniform", constraint.uniform != 0, false);
+    object.into()
+}
+
+fn transform_constraint(constraint: &ffi::spTransformConstraintData) -> Value {
+    let mut object = Object::default();
+    object.set("name", name(constraint.name));
+    object.int("order", constraint.order, 0);
+    object.flag("skin", constraint.skinRequired != 0, false);
+    let bones = unsafe { items(constraint.bones, constraint.bonesCount) };
+    object.set("bones", Value::names(bones, |bone| bone.name));
+    object.set("target", name(unsafe { &*constraint.target }.name));
+    object.flag("local", constraint.local != 0, false);
+    object.flag("relative", constraint.relative != 0, false);
+    object.float("rotation", constraint.offsetRotation, 0.0);
+    object.float("x", constraint.offsetX, 0.0);
+    object.float("y", constraint.offsetY, 0.0);
+    object.float("scaleX", constraint.offsetScaleX, 0.0);
+    object.float("scaleY", constraint.offsetScaleY, 0.0);
+    object.float("shearY", constraint.offsetShearY, 0.0);
+    object.float("rotateMix", constraint.rotateMix, 1.0);
+    object.float("translateMix", constraint.translateMix, 1.0);
+    object.float("scaleMix", constraint.scaleMix, 1.0);
+    object.float("shearMix", constraint.shearMix, 1.0);
+    object.into()
+}
+
+fn path_constraint(constraint: &ffi::spPathConstraintData) -> Value {
+    let mut object = Object::default();
+    object.set("name", name(constraint.name));
+    object.int("order", constraint.order, 0);
+    object.flag("skin", constraint.skinRequired != 0, false);
+    let bones = unsafe { items(constraint.bones, constraint.bonesCount) };
+    object.set("bones", Value::names(bones, |bone| bone.name));
+    object.set("target", name(unsafe { &*constraint.target }.name));
+    if constraint.positionMode != ffi::spPositionMode_SP_POSITION_MODE_PERCENT {
+        object.set(
+            "positionMode",
+            mode(&POSITION_MODES, constraint.positionMode),
+        );
+    }
+    if constraint.spacingMode != ffi::spSpacingMode_SP_SPACING_MODE_LENGTH {
+        object.set("spacingMode", mode(&SPACING_MODES, constraint.spacingMode));
+    }
+    if constraint.rotateMode != ffi::spRotateMode_SP_ROTATE_MODE_TANGENT {
+        object.set("rotateMode", mode(&ROTATE_MODES, constraint.rotateMode));
+    }
+    object.float("rotation", constraint.offsetRotation, 0.0);
+    object.float("position", constraint.position, 0.0);
+    object.float("spacing", constraint.spacing, 0.0);
+    object.float("rotateMix", constraint.rotateMix, 1.0);
+    object.float("translateMix", constraint.translateMix, 1.0);
+    object.into()
+}
+
+fn skin(
+    data: &ffi::spSkeletonData,
+    skins: &[Vec<Entry<'_>>],
+    skin: &ffi::spSkin,
+    entries: &[Entry<'_>],
+) -> Value {
+    let mut object = Object::default();
+    object.set("name", name(skin.name));
+    unsafe {
+        let bones = &*skin.bones;
+        let bones = items(bones.items, bones.size);
+        object.array("bones", Value::names(bones, |bone| bone.name));
+        let ik = &*skin.ikConstraints;
+        let ik = items(ik.items, ik.size);
+        object.array("ik", Value::names(ik, |constraint| constraint.name));
+        let transform = &*skin.transformConstraints;
+        let transform = items(transform.items, transform.size);
+        object.array(
+            "transform",
+            Value::names(transform, |constraint| constraint.name),
+        );
+        let path = &*skin.pathConstraints;
+        let path = items(path.items, path.size);
+        object.array("path", Value::names(path, |constraint| constraint.name));
+    }
+
+    // spine-c requires the attachments of every skin, even when there are none.
+    let mut attachments = Object::default();
+    for slot_entries in entries.chunk_by(|a, b| a.slot == b.slot) {
+        let slot = unsafe { &**data.slots.add(slot_entries[0].slot) };
+        let mut slot_attachments = Object::default();
+        for entry in slot_entries {
+            slot_attachments.set(entry.name, attachment(data, skins, entry));
+        }
+        attachments.set(name(slot.name), slot_attachments);
+    }
+    object.set("attachments", attachments);
+    object.into()
+}
+
+#[allow(clippy::too_many_lines)]
+fn attachment(data: &ffi::spSkeletonData, skins: &[Vec<Entry<'_>>], entry: &Entry<'_>) -> Value {
+    let attachment = entry.attachment;
+    let attachment_name = name(attachment.name);
+    let mut object = Object::default();
+    if attachment_name != entry.name {
+        object.set("name", attachment_name);
+    }
+    let path = |object: &mut Object, path: *const c_char| {
+        let path = name(path);
+        if path != attachment_name {
+            object.set("path", path);
+        }
+    };
+
+    match attachment.type_ {
+        ffi::spAttachmentType_SP_ATTACHMENT_REGION => {
+            let region = unsafe { super::downcast::<ffi::spRegionAttachment>(attachment) };
+            path(&mut object, region.path);
+            object.float("x", region.x, 0.0);
+            object.float("y", region.y, 0.0);
+            object.float("scaleX", region.scaleX, 1.0);
+            object.float("scaleY", region.scaleY, 1.0);
+            object.float("rotation", region.rotation, 0.0);
+            object.set("width", region.width);
+            object.set("height", region.height);
+            object.color("color", &region.color);
+        }
+        ffi::spAttachmentType_SP_ATTACHMENT_BOUNDING_BOX => {
+            let bounding_box = unsafe { super::downcast::<ffi::spVertexAttachment>(attachment) };
+            object.set("type", "boundingbox");
+            vertices(&mut object, bounding_box);
+        }
+        ffi::spAttachmentType_SP_ATTACHMENT_MESH
+        | ffi::spAttachmentType_SP_ATTACHMENT_LINKED_MESH => {
+            let mesh = unsafe { super::downcast::<ffi::spMeshAttachment>(attachment) };
+            let linked = !mesh.parentMesh.is_null();
+            object.set("type", if linked { "linkedmesh" } else { "mesh" });
+            path(&mut object, mesh.path);
+            object.color("color", &mesh.color);
+            object.set("width", mesh.width);
+            object.set("height", mesh.height);
+            if linked {
+                let (skin, parent) = linked_parent(data, skins, mesh, entry.slot);
+                object.string("skin", skin);
+                object.set("parent", parent);
+                let inherit = !std::ptr::eq(
+                    mesh.super_.deformAttachment,
+                    std::ptr::addr_of!(mesh.super_),
+                );
+                object.flag("deform", inherit, true);
+            } else {
+                let uvs = unsafe { slice(mesh.regionUVs, mesh.super_.worldVerticesLength) };
+                object.set("uvs", Value::floats(uvs));
+                let triangles = unsafe { slice(mesh.triangles, mesh.trianglesCount) };
+                let triangles = triangles.iter().map(|t| c_int::from(*t).into());
+                object.set("triangles", triangles.collect::<Vec<_>>());
+                vertices(&mut object, &mesh.super_);
+                // Unlike its binary reader, spine-c's JSON reader stores the hull as read.
+                object.int("hull", mesh.hullLength, 0);
+            }
+        }
+        ffi::spAttachmentType_SP_ATTACHMENT_PATH => {
+            let path = unsafe { super::downcast::<ffi::spPathAttachment>(attachment) };
+            object.set("type", "path");
+            object.flag("closed", path.closed != 0, false);
+            object.flag("constantSpeed", path.constantSpeed != 0, true);
+            vertices(&mut object, &path.super_);
+            let lengths = unsafe { slice(path.lengths, path.lengthsLength) };
+            object.set("lengths", Value::floats(lengths));
+        }
+        ffi::spAttachmentType_SP_ATTACHMENT_POINT => {
+            let point = unsafe { super::downcast::<ffi::spPointAttachment>(attachment) };
+            object.set("type", "point");
+            object.float("x", point.x, 0.0);
+            object.float("y", point.y, 0.0);
+            object.float("rotation", point.rotation, 0.0);
+            object.set("color", hex(&point.color, true).as_str());
+        }
+        ffi::spAttachmentType_SP_ATTACHMENT_CLIPPING => {
+            let clipping = unsafe { super::downcast::<ffi::spClippingAttachment>(attachment) };
+            object.set("type", "clipping");
+            if let Some(end) = unsafe { clipping.endSlot.as_ref() } {
+                object.set("end", name(end.name));
+            }
+            vertices(&mut object, &clipping.super_);
+        }
+        kind => spine_log!(warn, "writing an attachment of unknown type {}", kind),
+    }
+    object.into()
+}
+
+fn vertices(object: &mut Object, attachment: &ffi::spVertexAttachment) {
+    let vertices = SetupVertices::new(attachment);
+    if attachment.super_.type_ != ffi::spAttachmentType_SP_ATTACHMENT_MESH {
+        object.set("vertexCount", c_int::try_from(vertices.count).unwrap());
+    }
+    if vertices.weighted() {
+        let mut values = Vec::new();
+        for vertex in vertices.weights() {
+            values.push(c_int::try_from(vertex.len()).unwrap().into());
+            for (bone, weight) in vertex {
+                values.push(bone.into());
+                values.extend(weight.iter().map(|value| Value::Float(*value)));
+            }
+        }
+        object.set("vertices", values);
+    } else {
+        object.set("vertices", Value::floats(vertices.vertices));
+    }
+}
+
+fn event_data(event: &ffi::spEventData) -> Value {
+    let mut object = Object::default();
+    object.int("int", event.intValue, 0);
+    object.float("float", event.floatValue, 0.0);
+    object.string("string", unsafe { string(event.stringValue) });
+    if let Some(audio) = unsafe { string(event.audioPath) } {
+        object.set("audio", audio);
+        object.float("volume", event.volume, 1.0);
+        object.float("balance", event.balance, 0.0);
+    }
+    object.into()
+}
+
+/// Writes key frames, with the time and curve of each around the values `values` sets.
+fn frames(frames: Vec<(&[f32], Option<Curve>)>, values: impl Fn(&mut Object, &[f32])) -> Value {
+    let frames = frames.into_iter().map(|(frame, curve)| {
+        let mut object = Object::default();
+        object.float("time", frame[0], 0.0);
+        values(&mut object, &frame[1..]);
+        if let Some(curve) = curve {
+            curve_into(&mut object, curve);
+        }
+        object.into()
+    });
+    Value::Array(frames.collect())
+}
+
+#[allow(clippy::too_many_lines)]
+fn animation(
+    data: &ffi::spSkeletonData,
+    skins: &[Vec<Entry<'_>>],
+    animation: &ffi::spAnimation,
+) -> Value {
+    let timelines = Timelines::new(animation, skins);
+    let mut object = Object::default();
+
+    let mut slots = Object::default();
+    for (slot, slot_timelines) in &timelines.slots {
+        let mut slot_object = Object::default();
+        for timeline in slot_timelines {
+            match timeline {
+                Timeline::Attachment(t) => {
+                    let times = unsafe { slice(t.frames, t.framesCount) };
+                    let names = unsafe { slice(t.attachmentNames, t.framesCount) };
+                    let frames = times.iter().zip(names).map(|(time, attachment)| {
+                        let mut frame = Object::default();
+                        frame.float("time", *time, 0.0);
+                        frame.set("name", unsafe { string(*attachment) });
+                        frame.into()
+                    });
+                    slot_object.set("attachment", frames.collect::<Vec<_>>());
+                }
+                Timeline::Color(t) => {
+                    let key_frames = key_frames(&t.super_, t.frames, t.framesCount, 5);
+                    let frames = frames(key_frames, |frame, values| {
+                        frame.set("color", hex(&color(values), true).as_str());
+                    });
+                    slot_object.set("color", frames);
+                }
+                Timeline::TwoColor(t) => {
+                    let key_frames = key_frames(&t.super_, t.frames, t.framesCount, 8);
+                    let frames = frames(key_frames, |frame, values| {
+                        frame.set("light", hex(&color(&values[..4]), true).as_str());
+                        frame.set("dark", hex(&color(&values[4..]), false).as_str());
+                    });
+                    slot_object.set("twoColor", frames);
+                }
+                _ => unreachable!(),
+            }
+        }
+        let slot = unsafe { &**data.slots.add(*slot) };
+        slots.set(name(slot.name), slot_object);
+    }
+    object.object("slots", slots);
+
+    let mut bones = Object::default();
+    for (bone, bone_timelines) in &timelines.bones {
+        let mut bone_object = Object::default();
+        for timeline in bone_timelines {
+            let (key, t, entries) = match timeline {
+                Timeline::Rotate(t) => ("rotate", t, 2),
+                Timeline::Translate(t) => ("translate", t, 3),
+                Timeline::Scale(t) => ("scale", t, 3),
+                Timeline::Shear(t) => ("shear", t, 3),
+                _ => unreachable!(),
+            };
+            let key_frames = key_frames(&t.super_, t.frames, t.framesCount, entries);
+            let frames = frames(key_frames, |frame, values| {
+                if key == "rotate" {
+                    frame.float("angle", values[0], 0.0);
+                } else {
+                    let default = if key == "scale" { 1.0 } else { 0.0 };
+                    frame.float("x", values[0], default);
+                    frame.float("y", values[1], default);
+                }
+            });
+            bone_object.set(key, frames);
+        }
+        let bone = unsafe { &**data.bones.add(*bone) };
+        bones.set(name(bone.name), bone_object);
+    }
+    object.object("bones", bones);
+
+    let mut ik = Object::default();
+    for t in &timelines.ik {
+        let key_frames = key_frames(&t.super_, t.frames, t.framesCount, 6);
+        let frames = frames(key_frames, |frame, values| {
+            frame.float("mix", values[0], 1.0);
+            frame.float("softness", values[1], 0.0);
+            frame.flag("bendPositive", values[2] > 0.0, true);
+            frame.flag("compress", values[3] != 0.0, false);
+            frame.flag("stretch", values[4] != 0.0, false);
+        });
+        let constraint = unsafe { &**data.ikConstraints.add(count(t.ikConstraintIndex)) };
+        ik.set(name(constraint.name), frames);
+    }
+    object.object("ik", ik);
+
+    let mut transform = Object::default();
+    for t in &timelines.transform {
+        let key_frames = key_frames(&t.super_, t.frames, t.framesCount, 5);
+        let frames = frames(key_frames, |frame, values| {
+            frame.float("rotateMix", values[0], 1.0);
+            frame.float("translateMix", values[1], 1.0);
+            frame.float("scaleMix", values[2], 1.0);
+            frame.float("shearMix", values[3], 1.0);
+        });
+        let index = count(t.transformConstraintIndex);
+        let constraint = unsafe { &**data.transformConstraints.add(index) };
+        transform.set(name(constraint.name), frames);
+    }
+    object.object("transform", transform);
+
+    let mut paths = Object::default();
+    for (path, path_timelines) in &timelines.paths {
+        let mut path_object = Object::default();
+        for timeline in path_timelines {
+            match timeline {
+                Timeline::PathPosition(t) => {
+                    let key_frames = key_frames(&t.super_, t.frames, t.framesCount, 2);
+                    let frames = frames(key_frames, |frame, values| {
+                        frame.float("position", values[0], 0.0);
+                    });
+                    path_object.set("position", frames);
+                }
+                Timeline::PathSpacing(t) => {
+                    let key_frames = key_frames(&t.super_, t.frames, t.framesCount, 2);
+                    let frames = frames(key_frames, |frame, values| {
+                        frame.float("spacing", values[0], 0.0);
+                    });
+                    path_object.set("spacing", frames);
+                }
+                Timeline::PathMix(t) => {
+                    let key_frames = key_frames(&t.super_, t.frames, t.framesCount, 3);
+                    let frames = frames(key_frames, |frame, values| {
+                        frame.float("rotateMix", values[0], 1.0);
+                        frame.float("translateMix", values[1], 1.0);
+                    });
+                    path_object.set("mix", frames);
+                }
+                _ => unreachable!(),
+            }
+        }
+        let constraint = unsafe { &**data.pathConstraints.add(*path) };
+        paths.set(name(constraint.name), path_object);
+    }
+    // spine-c 3.8 reads path constraint timelines from "paths" rather than the editor's "path".
+    object.object("paths", paths);
+
+    let mut deform = Object::default();
+    for (skin, skin_timelines) in &timelines.deform {
+        let mut skin_object = Object::default();
+        for (slot, slot_timelines) in skin_timelines {
+            let mut slot_object = Object::default();
+            for (attachment, t) in slot_timelines {
+                let setup =
+                    SetupVertices::new(unsafe { &*t.attachment.cast::<ffi::spVertexAttachment>() });
+                let times = unsafe { slice(t.frames, t.framesCount) };
+                let vertices = unsafe { slice(t.frameVertices, t.framesCount) };
+                let frames = times
+                    .iter()
+                    .zip(vertices)
+                    .enumerate()
+                    .map(|(n, (time, frame))| {
+                        let frame = unsafe { slice(*frame, t.frameVerticesCount) };
+                        let mut object = Object::default();
+                        object.float("time", *time, 0.0);
+                        let (offset, delta) = deform_delta(frame, &setup);
+                        if !delta.is_empty() {
+                            object.int("offset", c_int::try_from(offset).unwrap(), 0);
+                            object.set("vertices", Value::floats(&delta));
+                        }
+                        if n + 1 < times.len() {
+                            curve_into(&mut object, super::curve(&t.super_, n));
+                        }
+                        object.into()
+                    });
+                slot_object.set(attachment, frames.collect::<Vec<_>>());
+            }
+            let slot = unsafe { &**data.slots.add(*slot) };
+            skin_object.set(name(slot.name), slot_object);
+        }
+        let skin = unsafe { &**data.skins.add(*skin) };
+        deform.set(name(skin.name), skin_object);
+    }
+    object.object("deform", deform);
+
+    if let Some(t) = timelines.draw_order {
+        let times = unsafe { slice(t.frames, t.framesCount) };
+        let orders = unsafe { slice(t.drawOrders, t.framesCount) };
+        let frames = times.iter().zip(orders).map(|(time, order)| {
+            let mut frame = Object::default();
+            frame.float("time", *time, 0.0);
+            if !order.is_null() {
+                let order = unsafe { slice(*order, t.slotsCount) };
+                let offsets = draw_order_offsets(order).into_iter().map(|(slot, offset)| {
+                    let mut object = Object::default();
+                    object.set("slot", name(unsafe { &**data.slots.add(slot) }.name));
+                    object.set("offset", c_int::try_from(offset).unwrap());
+                    object.into()
+                });
+                // Key frames back in setup order are written without offsets, as spine-c
+                // reads them from either format.
+                frame.array("offsets", Value::Array(offsets.collect()));
+            }
+            frame.into()
+        });
+        object.set("drawOrder", frames.collect::<Vec<_>>());
+    }
+
+    if let Some(t) = timelines.events {
+        let events = unsafe { items(t.events, t.framesCount) }.map(|event| {
+            let data = unsafe { &*event.data };
+            let mut frame = Object::default();
+            frame.float("time", event.time, 0.0);
+            frame.set("name", name(data.name));
+            frame.int("int", event.intValue, data.intValue);
+            frame.float("float", event.floatValue, data.floatValue);
+            let value = unsafe { string(event.stringValue) };
+            if value != unsafe { string(data.stringValue) } {
+                frame.set("string", value.unwrap_or_default());
+            }
+            if !data.audioPath.is_null() {
+                frame.float("volume", event.volume, 1.0);
+                frame.float("balance", event.balance, 0.0);
+            }
+            frame.into()
+        });
+        object.set("events", events.collect::<Vec<_>>());
+    }
+
+    object.into()
+}
+
+fn curve_into(object: &mut Object, curve: Curve) {
+    match curve {
+        Curve::Stepped => object.set("curve", "stepped"),
+        Curve::Bezier([cx1, cy1, cx2, cy2]) => {
+            object.set("curve", cx1);
+            object.float("c2", cy1, 0.0);
+            object.float("c3", cx2, 1.0);
+            object.float("c4", cy2, 1.0);
+        }
+        Curve::Linear => {}
+    }
+}
@@ -1,30 +1,107 @@
 #![deny(clippy::all, clippy::pedantic)]
-#![allow(dead_code, clippy::module_name_repetitions, clippy::must_use_candidate)]
+#![allow(
+    dead_code,
+    clippy::module_name_repetitions,
+    clippy::must_use_candidate,
+    clippy::missing_panics_doc
+)]
 
-use atlas::AtlasPage;
+use atlas::{AtlasPage, TextureLoader};
 use std::ffi::CStr;
-use std::{os::raw::c_char, path::Path};
+use std::{os::raw::c_char, path::Path, sync::Mutex};
 use thiserror::Error;
 
+/// Declares `$name`, the mutable counterpart of the read-only handle `$handle`. It derefs to
+/// `$handle` for reading, and is only handed out from a mutable borrow of the owning `Skeleton`, so
+/// no other handle into that skeleton can be alive while it is used.
+macro_rules! spine_handle_mut {
+    ($(#[$meta:meta])* $name:ident, $handle:ident, $ffi:ty) => {
+        $(#[$meta])*
+        pub struct $name<'a> {
+            pub(crate) handle: $handle<'a>,
+            pub(crate) _lifetime: std::marker::PhantomData<&'a mut ()>,
+        }
+        impl<'a> $name<'a> {
+            pub(crate) fn new(inner: *mut $ffi) -> Self {
+                Self {
+                    handle: $handle {
+                        inner,
+                        _lifetime: std::marker::PhantomData,
+                    },
+                    _lifetime: std::marker::PhantomData,
+                }
+            }
+
+            pub(crate) fn as_mut(&mut self) -> &mut $ffi {
+                self.handle.as_mut()
+            }
+        }
+        impl<'a> std::ops::Deref for $name<'a> {
+            type Target = $handle<'a>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.handle
+            }
+        }
+    };
+}
+
+/// Logs through the `log` crate when the `log` feature is enabled. Without it the arguments are
+/// only type checked, so call sites do not need their own `cfg`.
+macro_rules! spine_log {
+    ($level:ident, $($arg:tt)+) => {{
+        #[cfg(feature = "log")]
+        log::$level!(target: "spine", $($arg)+);
+        #[cfg(not(feature = "log"))]
+        let _ = format_args!($($arg)+);
+    }};
+}
+
 pub mod ffi;
 
 pub mod animation;
 pub mod atlas;
+pub mod batch;
+pub mod bounds;
+pub mod color;
+pub mod constraint;
 pub mod enums;
+pub mod extension;
+pub mod format;
+#[cfg(feature = "raster")]
+pub mod raster;
 pub mod skeleton;
+pub mod snapshot;
+pub mod validate;
+pub mod vertex_effect;
 
 mod spine_ptr;
-use spine_ptr::*;
+use spine_ptr::SpineMutPtr;
+
+/// Serializes the spine-c calls that touch its unsynchronized global state: the attachment id
+/// counter used while loading skeleton data, and the shared empty animation lazily created with
+/// the first `spAnimationState`.
+pub(crate) static GLOBAL_STATE: Mutex<()> = Mutex::new(());
+
+/// Locks `GLOBAL_STATE`, ignoring poisoning since the guarded state is owned by spine-c.
+pub(crate) fn lock_global_state() -> std::sync::MutexGuard<'static, ()> {
+    GLOBAL_STATE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
 
 #[derive(Debug, Error)]
 pub enum SpineError {
-    #[error("LOL")]
+    #[error("{0}")]
     FailLoadAtlas(String),
-    #[error("{}", 0)]
+    #[error("{0}")]
     FailLoadSkeleton(String),
+    #[error("{0}")]
+    FailImage(String),
+    #[error("{0}")]
+    InvalidSnapshot(String),
 }
 
-#[allow(clippy::mut_mut)]
 #[no_mangle]
 extern "C" fn _spAtlasPage_createTexture(
     atlas_page_ptr: *mut ffi::spAtlasPage,
@@ -33,45 +110,127 @@ extern "C" fn _spAtlasPage_createTexture(
     std::panic::catch_unwind(|| {
         let path = unsafe { CStr::from_ptr(path).to_str().unwrap().to_owned() };
 
-        let mut atlas_page = AtlasPage {
-            inner: SpineMutPtr::new(atlas_page_ptr, None),
-        };
-
-        let atlas_object_ptr = unsafe { (*atlas_page.inner.as_mut().atlas).rendererObject };
-        //let atlas_page_object_ptr = atlas_page.inner.as_mut().rendererObject;
+        let atlas_object_ptr = unsafe { (*(*atlas_page_ptr).atlas).rendererObject };
 
         if !atlas_object_ptr.is_null() {
-            let closure: &mut &mut dyn FnMut(&AtlasPage, &Path) -> u32 =
-                unsafe { &mut *(atlas_object_ptr as *mut _) };
+            let loader: &mut TextureLoader<'_> = unsafe { &mut *atlas_object_ptr.cast() };
+            let mut atlas_page =
+                AtlasPage::new(atlas_page_ptr, (loader.premultiplied_alpha)(loader.page));
+            loader.page += 1;
 
-            atlas_page.set_texture_id(closure(&atlas_page, Path::new(&path)));
+            atlas_page.set_texture_id((loader.create_texture)(&atlas_page, Path::new(&path)));
         }
     })
-    .unwrap_or_else(|e| println!("ERROR: {:?}", e));
+    .unwrap_or_else(|e| {
+        spine_log!(
+            error,
+            "creating an atlas texture panicked: {}",
+            panic_message(&e)
+        );
+    });
 }
 
 #[no_mangle]
 extern "C" fn _spAtlasPage_disposeTexture(atlas: *mut ffi::spAtlasPage) {
     std::panic::catch_unwind(|| {
-        let _atlas_page = AtlasPage {
-            inner: SpineMutPtr::new(atlas, None),
-        };
+        let _atlas_page = AtlasPage::new(atlas, false);
 
         // TODO: no-op?
     })
-    .unwrap_or_else(|e| println!("ERROR: {:?}", e));
+    .unwrap_or_else(|e| {
+        spine_log!(
+            error,
+            "disposing an atlas texture panicked: {}",
+            panic_message(&e)
+        );
+    });
 }
 
 #[no_mangle]
 unsafe extern "C" fn _spUtil_readFile(path: *const c_char, length: *mut i32) -> *mut c_char {
-    std::panic::catch_unwind(|| ffi::_spReadFile(path, length)).unwrap_or_else(|e| {
-        println!("ERROR: {:?}", e);
+    std::panic::catch_unwind(|| {
+        let data = ffi::_spReadFile(path, length);
+        if data.is_null() {
+            spine_log!(
+                warn,
+                "failed to read {}",
+                CStr::from_ptr(path).to_string_lossy()
+            );
+        }
+        data
+    })
+    .unwrap_or_else(|e| {
+        spine_log!(error, "reading a file panicked: {}", panic_message(&e));
         std::ptr::null_mut()
     })
 }
 
+/// Extracts the message of a panic caught at the FFI boundary.
+fn panic_message(payload: &Box<dyn std::any::Any + Send>) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic payload")
+}
+
+/// Usage errors the borrow checker is expected to reject.
+///
+/// Handles borrow the `Skeleton` they were taken from, so they cannot outlive it:
+///
+/// ```compile_fail,E0597
+/// use spine_sys::skeleton::{Skeleton, SkeletonData};
+///
+/// fn dangling(data: &SkeletonData) {
+///     let bone = {
+///         let skeleton = Skeleton::new(data);
+///         skeleton.find_bone("root").unwrap()
+///     };
+///     bone.name();
+/// }
+/// ```
+///
+/// Mutable handles require a mutable skeleton:
+///
+/// ```compile_fail,E0596
+/// use spine_sys::skeleton::{Skeleton, SkeletonData};
+///
+/// fn immutable(data: &SkeletonData) {
+///     let skeleton = Skeleton::new(data);
+///     skeleton.find_bone_mut("root").unwrap().set_rotation(90.0);
+/// }
+/// ```
+///
+/// A read handle cannot be held across a call that mutates the skeleton:
+///
+/// ```compile_fail,E0502
+/// use spine_sys::skeleton::{Skeleton, SkeletonData};
+///
+/// fn stale(data: &SkeletonData) {
+///     let mut skeleton = Skeleton::new(data);
+///     let bone = skeleton.find_bone("root").unwrap();
+///     skeleton.update_world_transforms();
+///     bone.name();
+/// }
+/// ```
+///
+/// Instances may move between threads but cannot be shared between them:
+///
+/// ```compile_fail,E0277
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<spine_sys::skeleton::Skeleton>();
+/// ```
+///
+/// ```compile_fail,E0277
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<spine_sys::animation::AnimationState>();
+/// ```
+#[cfg(doctest)]
+pub mod compile_fail {}
+
 #[cfg(test)]
 pub(crate) mod tests {
+    use crate::{atlas::Atlas, skeleton::SkeletonData};
     use std::path::PathBuf;
 
     pub struct TestCase {
@@ -106,4 +265,50 @@ pub(crate) mod tests {
         json: "dragon-ess.json",
         path: "../spine-example/examples/dragon/export",
     }];
+
+    /// Loads an inline spine JSON skeleton, for rigs using features the test assets do not cover.
+    pub fn skeleton_data_from_json(json: &str) -> SkeletonData {
+        let atlas = Atlas::from_file(TEST_CASES[0].atlas(), |_, _| 0).unwrap();
+        SkeletonData::from_json_str(json, atlas).unwrap()
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn diagnostics_are_logged() {
+        use crate::skeleton::Skeleton;
+        use std::sync::Mutex;
+
+        struct Capture(Mutex<Vec<String>>);
+        impl log::Log for Capture {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.target() == "spine"
+            }
+
+            fn log(&self, record: &log::Record) {
+                if self.enabled(record.metadata()) {
+                    self.0.lock().unwrap().push(record.args().to_string());
+                }
+            }
+
+            fn flush(&self) {}
+        }
+        static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+
+        log::set_logger(&CAPTURE).unwrap();
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let atlas = Atlas::from_file(TEST_CASES[0].atlas(), |_, _| 0).unwrap();
+        let skeleton_data =
+            unsafe { SkeletonData::from_binary_file(TEST_CASES[0].binary(), atlas) }.unwrap();
+        let skeleton = Skeleton::new(&skeleton_data);
+        assert!(skeleton.find_bone("no such bone").is_none());
+        assert!(Atlas::from_file("no such file.atlas", |_, _| 0).is_err());
+
+        let messages = CAPTURE.0.lock().unwrap();
+        let logged = |prefix: &str| messages.iter().any(|m| m.starts_with(prefix));
+        assert!(logged("loaded atlas"));
+        assert!(logged("loaded skeleton"));
+        assert!(logged("no bone named \"no such bone\""));
+        assert!(logged("failed to read no such file.atlas"));
+    }
 }
@@ -0,0 +1,692 @@
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss,
+    clippy::many_single_char_names
+)]
+
+use crate::{
+    atlas::Atlas,
+    batch::SkeletonBatcher,
+    color::Color,
+    enums::BlendMode,
+    skeleton::{is_y_down, Skeleton, Slot, WorldVertex},
+    SpineError,
+};
+use std::{collections::HashMap, convert::TryInto, fs::File, io::BufWriter, path::Path};
+
+/// An RGBA8 image with straight alpha, stored row by row starting at the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+impl Image {
+    /// Creates a fully transparent image.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self::from_rgba8(width, height, vec![0; width as usize * height as usize * 4])
+    }
+
+    /// # Panics
+    /// If `pixels` does not hold exactly `width * height` RGBA8 pixels.
+    pub fn from_rgba8(width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        assert_eq!(
+            width as usize * height as usize * 4,
+            pixels.len(),
+            "pixel buffer does not match the image size"
+        );
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Decodes a PNG file, converting it to RGBA8.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailImage` if the file cannot be read or decoded.
+    pub fn from_png<P>(path: P) -> Result<Self, SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let error = |e: &dyn std::fmt::Display| {
+            SpineError::FailImage(format!("failed to decode {}: {e}", path.display()))
+        };
+
+        let mut decoder = png::Decoder::new(File::open(path).map_err(|e| error(&e))?);
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().map_err(|e| error(&e))?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).map_err(|e| error(&e))?;
+        buffer.truncate(info.buffer_size());
+
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer
+                .chunks_exact(3)
+                .flat_map(|p| [p[0], p[1], p[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => buffer
+                .chunks_exact(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|&p| [p, p, p, 255]).collect(),
+            png::ColorType::Indexed => return Err(error(&"unexpanded palette")),
+        };
+
+        Ok(Self::from_rgba8(info.width, info.height, pixels))
+    }
+
+    /// Encodes this image as an RGBA8 PNG file.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailImage` if the file cannot be written.
+    pub fn write_png<P>(&self, path: P) -> Result<(), SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let error = |e: &dyn std::fmt::Display| {
+            SpineError::FailImage(format!("failed to write {}: {e}", path.display()))
+        };
+
+        let file = File::create(path).map_err(|e| error(&e))?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.pixels))
+            .map_err(|e| error(&e))
+    }
+
+    /// Returns the RGBA8 pixel at column `x` and row `y`.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        self.pixels[offset..offset + 4].try_into().unwrap()
+    }
+
+    /// Returns the texel at column `x` and row `y` with 0-1 channels, premultiplying it unless the
+    /// image already is.
+    fn texel(&self, x: usize, y: usize, premultiplied: bool) -> [f32; 4] {
+        let offset = (y * self.width as usize + x) * 4;
+        let p = &self.pixels[offset..offset + 4];
+        let [r, g, b, a] = [p[0], p[1], p[2], p[3]].map(|c| f32::from(c) / 255.0);
+        if premultiplied {
+            [r, g, b, a]
+        } else {
+            [r * a, g * a, b * a, a]
+        }
+    }
+
+    /// Samples the image with bilinear filtering, clamping to its edges.
+    fn sample(&self, uv: [f32; 2], premultiplied: bool) -> [f32; 4] {
+        if self.width == 0 || self.height == 0 {
+            return [0.0; 4];
+        }
+
+        let x = uv[0] * self.width as f32 - 0.5;
+        let y = uv[1] * self.height as f32 - 0.5;
+        let (x0, y0) = (x.floor(), y.floor());
+        let (fx, fy) = (x - x0, y - y0);
+
+        let clamp = |v: f32, size: u32| (v.max(0.0) as usize).min(size as usize - 1);
+        let (left, right) = (clamp(x0, self.width), clamp(x0 + 1.0, self.width));
+        let (top, bottom) = (clamp(y0, self.height), clamp(y0 + 1.0, self.height));
+
+        let lerp =
+            |a: [f32; 4], b: [f32; 4], t: f32| [0, 1, 2, 3].map(|i| a[i] + (b[i] - a[i]) * t);
+        lerp(
+            lerp(
+                self.texel(left, top, premultiplied),
+                self.texel(right, top, premultiplied),
+                fx,
+            ),
+            lerp(
+                self.texel(left, bottom, premultiplied),
+                self.texel(right, bottom, premultiplied),
+                fx,
+            ),
+            fy,
+        )
+    }
+}
+
+/// Maps world space to the pixels of an image.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Camera {
+    /// The world position shown at the center of the image.
+    pub center: [f32; 2],
+    /// Image pixels per world unit.
+    pub zoom: f32,
+}
+impl Camera {
+    pub fn new(center: [f32; 2], zoom: f32) -> Self {
+        Self { center, zoom }
+    }
+
+    /// Centers the world space rectangle from `min` to `max` in a `width` x `height` image, scaled
+    /// to fill 90% of it.
+    pub fn fit(min: [f32; 2], max: [f32; 2], width: u32, height: u32) -> Self {
+        let size = [(max[0] - min[0]).max(1.0), (max[1] - min[1]).max(1.0)];
+        Self {
+            center: [f32::midpoint(min[0], max[0]), f32::midpoint(min[1], max[1])],
+            zoom: 0.9 * (width as f32 / size[0]).min(height as f32 / size[1]),
+        }
+    }
+
+    /// Fits every visible attachment of `skeleton`, whose world transforms must be up to date.
+    pub fn fit_skeleton(skeleton: &Skeleton, width: u32, height: u32) -> Self {
+        let mut min = [f32::MAX; 2];
+        let mut max = [f32::MIN; 2];
+        for vertex in skeleton.draw_slots().iter().flat_map(Slot::world_vertices) {
+            for axis in 0..2 {
+                min[axis] = min[axis].min(vertex.position[axis]);
+                max[axis] = max[axis].max(vertex.position[axis]);
+            }
+        }
+
+        if min[0] > max[0] {
+            Self::new(skeleton.position(), 1.0)
+        } else {
+            Self::fit(min, max, width, height)
+        }
+    }
+
+    fn to_screen(self, position: [f32; 2], width: u32, height: u32) -> [f32; 2] {
+        let x = (position[0] - self.center[0]) * self.zoom;
+        let y = (position[1] - self.center[1]) * self.zoom;
+        let y = if is_y_down() { y } else { -y };
+        [x + width as f32 / 2.0, y + height as f32 / 2.0]
+    }
+}
+
+/// A vertex in image space, with its colors premultiplied by alpha.
+#[derive(Debug, Copy, Clone)]
+struct ScreenVertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+    light: [f32; 4],
+    dark: [f32; 3],
+}
+
+/// Premultiplied RGBA pixels being drawn into.
+struct Target {
+    width: u32,
+    height: u32,
+    pixels: Vec<[f32; 4]>,
+}
+impl Target {
+    fn from_image(image: &Image) -> Self {
+        Self {
+            width: image.width,
+            height: image.height,
+            pixels: image
+                .pixels
+                .chunks_exact(4)
+                .map(|p| {
+                    let [r, g, b, a] = [p[0], p[1], p[2], p[3]].map(|c| f32::from(c) / 255.0);
+                    [r * a, g * a, b * a, a]
+                })
+                .collect(),
+        }
+    }
+
+    fn write_to(&self, image: &mut Image) {
+        for (pixel, out) in self.pixels.iter().zip(image.pixels.chunks_exact_mut(4)) {
+            let a = pixel[3];
+            let color = if a > 0.0 {
+                Color::new(pixel[0] / a, pixel[1] / a, pixel[2] / a, a)
+            } else {
+                Color::TRANSPARENT
+            };
+            out.copy_from_slice(&color.to_rgba8().to_be_bytes());
+        }
+    }
+
+    fn fill_triangle(
+        &mut self,
+        vertices: [&ScreenVertex; 3],
+        texture: &Image,
+        premultiplied: bool,
+        blend_mode: BlendMode,
+    ) {
+        let [a, mut b, mut c] = vertices;
+        let mut area = edge(a.position, b.position, c.position);
+        if area < 0.0 {
+            std::mem::swap(&mut b, &mut c);
+            area = -area;
+        }
+        if !(area > 0.0 && area.is_finite()) {
+            return;
+        }
+
+        let xs = [a.position[0], b.position[0], c.position[0]];
+        let ys = [a.position[1], b.position[1], c.position[1]];
+        let span = |v: [f32; 3], size: u32| {
+            let min = v[0].min(v[1]).min(v[2]).floor().max(0.0) as u32;
+            let max = (v[0].max(v[1]).max(v[2]).ceil().max(0.0) as u32).min(size);
+            min..max
+        };
+
+        for y in span(ys, self.height) {
+            for x in span(xs, self.width) {
+                let p = [x as f32 + 0.5, y as f32 + 0.5];
+                let weights = [
+                    edge(b.position, c.position, p),
+                    edge(c.position, a.position, p),
+                    edge(a.position, b.position, p),
+                ];
+                if !(covers(weights[0], b, c)
+                    && covers(weights[1], c, a)
+                    && covers(weights[2], a, b))
+                {
+                    continue;
+                }
+
+                let [wa, wb, wc] = weights.map(|w| w / area);
+                let mix = |f: fn(&ScreenVertex) -> f32| wa * f(a) + wb * f(b) + wc * f(c);
+                let uv = [mix(|v| v.uv[0]), mix(|v| v.uv[1])];
+                let light = [
+                    mix(|v| v.light[0]),
+                    mix(|v| v.light[1]),
+                    mix(|v| v.light[2]),
+                    mix(|v| v.light[3]),
+                ];
+                let dark = [mix(|v| v.dark[0]), mix(|v| v.dark[1]), mix(|v| v.dark[2])];
+
+                let source = shade(texture.sample(uv, premultiplied), light, dark);
+                let pixel = &mut self.pixels[(y * self.width + x) as usize];
+                *pixel = blend(blend_mode, source, *pixel);
+            }
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `p`. Swapping `a` and `b` exactly negates the
+/// result, so triangles sharing an edge agree on which side of it a pixel lies.
+fn edge(a: [f32; 2], b: [f32; 2], p: [f32; 2]) -> f32 {
+    let raw =
+        |a: [f32; 2], b: [f32; 2]| (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0]);
+    if (a[0], a[1]) <= (b[0], b[1]) {
+        raw(a, b)
+    } else {
+        -raw(b, a)
+    }
+}
+
+/// Whether a pixel with edge weight `weight` against the edge from `from` to `to` is inside.
+/// Pixels exactly on an edge belong to only one of the two triangles sharing it, so translucent
+/// meshes are not blended twice along their seams.
+fn covers(weight: f32, from: &ScreenVertex, to: &ScreenVertex) -> bool {
+    let d = [
+        to.position[0] - from.position[0],
+        to.position[1] - from.position[1],
+    ];
+    weight > 0.0 || (weight == 0.0 && (d[1] > 0.0 || (d[1] == 0.0 && d[0] < 0.0)))
+}
+
+/// Tints a premultiplied texel with the light and dark colors of two color tinting, as the
+/// spine-runtimes shaders do. Without a dark color this is a plain multiplication by `light`.
+fn shade(texel: [f32; 4], light: [f32; 4], dark: [f32; 3]) -> [f32; 4] {
+    let alpha = light[3];
+    let channel = |i: usize| {
+        if alpha > 0.0 {
+            ((texel[3] - texel[i]) * dark[i] + texel[i] * light[i] / alpha) * alpha
+        } else {
+            0.0
+        }
+    };
+    [channel(0), channel(1), channel(2), texel[3] * alpha]
+}
+
+/// Blends a premultiplied `source` over `destination` with the blend functions spine-runtimes use
+/// for premultiplied alpha.
+fn blend(mode: BlendMode, source: [f32; 4], destination: [f32; 4]) -> [f32; 4] {
+    let alpha = source[3];
+    [0, 1, 2, 3].map(|i| {
+        let (s, d) = (source[i], destination[i]);
+        let out = match mode {
+            BlendMode::Normal => s + d * (1.0 - alpha),
+            BlendMode::Additive => s + d,
+            BlendMode::Multiply => s * d + d * (1.0 - alpha),
+            BlendMode::Screen => s + d * (1.0 - s),
+        };
+        out.clamp(0.0, 1.0)
+    })
+}
+
+/// Draws posed skeletons into images on the CPU, without a GPU or window.
+///
+/// Region and mesh attachments are drawn with bilinear texture filtering, two color tinting, the
+/// slot blend modes and clipping attachments. Textures are looked up by the texture id assigned
+/// while loading the `Atlas`, see `Rasterizer::load_atlas` and `Rasterizer::insert_texture`.
+pub struct Rasterizer {
+    /// Each texture and whether it stores premultiplied alpha.
+    textures: HashMap<u32, (Image, bool)>,
+    batcher: SkeletonBatcher,
+}
+
+impl Default for Rasterizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Rasterizer {
+    pub fn new() -> Self {
+        Self {
+            textures: HashMap::new(),
+            batcher: SkeletonBatcher::new(),
+        }
+    }
+
+    /// Registers the texture for atlas pages that were given `id` as their texture id, see
+    /// `AtlasPage::premultiplied_alpha`.
+    pub fn insert_texture(&mut self, id: u32, texture: Image, premultiplied_alpha: bool) {
+        self.textures.insert(id, (texture, premultiplied_alpha));
+    }
+
+    /// Loads an atlas, decoding every page as a PNG file and registering it under a new texture id,
+    /// with the premultiplied alpha of its page.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadAtlas` if the atlas cannot be loaded, or a
+    /// `SpineError::FailImage` if one of its pages cannot be decoded.
+    pub fn load_atlas<P>(&mut self, path: P) -> Result<Atlas, SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut next_id = self.textures.keys().max().map_or(1, |id| id + 1);
+        let mut images = Vec::new();
+        let mut error = None;
+
+        let atlas = Atlas::from_file(path, |_, page_path| match Image::from_png(page_path) {
+            Ok(image) => {
+                let id = next_id;
+                next_id += 1;
+                images.push(image);
+                id
+            }
+            Err(e) => {
+                error.get_or_insert(e);
+                0
+            }
+        })?;
+        if let Some(e) = error {
+            return Err(e);
+        }
+
+        for (page, image) in atlas.pages().iter().zip(images) {
+            self.insert_texture(page.texture_id(), image, page.premultiplied_alpha());
+        }
+        Ok(atlas)
+    }
+
+    /// Draws `skeleton` over a transparent `width` x `height` image. World transforms must be up
+    /// to date.
+    pub fn render(
+        &mut self,
+        skeleton: &Skeleton,
+        camera: Camera,
+        width: u32,
+        height: u32,
+    ) -> Image {
+        let mut image = Image::new(width, height);
+        self.draw(skeleton, camera, &mut image);
+        image
+    }
+
+    /// Draws `skeleton` over the existing contents of `image`. World transforms must be up to date.
+    pub fn draw(&mut self, skeleton: &Skeleton, camera: Camera, image: &mut Image) {
+        let mut target = Target::from_image(image);
+
+        for batch in self.batcher.batch(skeleton) {
+            let Some((texture, premultiplied_alpha)) = self.textures.get(&batch.texture_id) else {
+                spine_log!(debug, "no texture with id {}", batch.texture_id);
+                continue;
+            };
+
+            let vertices = batch
+                .vertices
+                .iter()
+                .map(|v| screen_vertex(v, camera, image.width, image.height))
+                .collect::<Vec<_>>();
+            for triangle in batch.indices.chunks_exact(3) {
+                target.fill_triangle(
+                    [0, 1, 2].map(|i| &vertices[triangle[i] as usize]),
+                    texture,
+                    *premultiplied_alpha,
+                    batch.blend_mode,
+                );
+            }
+        }
+
+        target.write_to(image);
+    }
+}
+
+fn screen_vertex(vertex: &WorldVertex, camera: Camera, width: u32, height: u32) -> ScreenVertex {
+    let light = vertex.light.premultiplied();
+    ScreenVertex {
+        position: camera.to_screen(vertex.position, width, height),
+        uv: vertex.uv,
+        light: [light.r, light.g, light.b, light.a],
+        dark: [vertex.dark.r, vertex.dark.g, vertex.dark.b],
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::{
+        animation::{AnimationState, AnimationStateData, TrackIndex},
+        skeleton::SkeletonData,
+        tests::{skeleton_data_from_json, TEST_CASES},
+    };
+    use std::path::PathBuf;
+
+    /// Loads the dragon posed at `time` seconds into its "flying" animation.
+    fn dragon(rasterizer: &mut Rasterizer, atlas: &str, time: f32) -> (SkeletonData, Skeleton) {
+        let test_case = &TEST_CASES[0];
+        let atlas = rasterizer
+            .load_atlas(test_case.atlas().with_file_name(atlas))
+            .unwrap();
+        let skeleton_data =
+            unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas) }.unwrap();
+        let animation_data = AnimationStateData::new(&skeleton_data);
+        let mut animation = AnimationState::new(&animation_data);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+
+        animation.set_by_name("flying", TrackIndex::zero(), true);
+        animation.update(time);
+        animation.apply(&mut skeleton);
+        skeleton.update_world_transforms();
+
+        (skeleton_data, skeleton)
+    }
+
+    /// The largest difference between any two channels of the premultiplied pixels of `a` and `b`.
+    fn max_difference(a: &Image, b: &Image) -> u8 {
+        assert_eq!((a.width, a.height), (b.width, b.height));
+        let premultiplied = |p: &[u8]| {
+            Color::from_rgba8(u32::from_be_bytes([p[0], p[1], p[2], p[3]])).to_premultiplied_rgba8()
+        };
+        a.pixels
+            .chunks_exact(4)
+            .zip(b.pixels.chunks_exact(4))
+            .flat_map(|(a, b)| {
+                let (a, b) = (
+                    premultiplied(a).to_be_bytes(),
+                    premultiplied(b).to_be_bytes(),
+                );
+                (0..4).map(move |i| a[i].abs_diff(b[i]))
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Compares `image` to the golden image `name`, or replaces the golden image when the
+    /// `SPINE_BLESS` environment variable is set.
+    fn assert_golden(image: &Image, name: &str) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("golden")
+            .join(name);
+        if std::env::var_os("SPINE_BLESS").is_some() {
+            image.write_png(&path).unwrap();
+        }
+
+        let golden = Image::from_png(&path).unwrap();
+        let difference = max_difference(image, &golden);
+        assert!(difference <= 2, "{} differs by {}", name, difference);
+    }
+
+    #[test]
+    fn dragon_golden_images() {
+        let mut rasterizer = Rasterizer::new();
+        let (_data, setup) = dragon(&mut rasterizer, "dragon.atlas", 0.0);
+        let camera = Camera::fit_skeleton(&setup, 200, 160);
+
+        for time in [0.0, 0.5] {
+            let (_data, skeleton) = dragon(&mut rasterizer, "dragon.atlas", time);
+            let image = rasterizer.render(&skeleton, camera, 200, 160);
+            assert_golden(&image, &format!("dragon-flying-{time:.1}.png"));
+        }
+    }
+
+    #[test]
+    fn premultiplied_textures() {
+        let mut rasterizer = Rasterizer::new();
+        let (_data, skeleton) = dragon(&mut rasterizer, "dragon.atlas", 0.25);
+        let camera = Camera::fit_skeleton(&skeleton, 200, 160);
+        let straight = rasterizer.render(&skeleton, camera, 200, 160);
+
+        let mut rasterizer = Rasterizer::new();
+        let (_data, skeleton) = dragon(&mut rasterizer, "dragon-pma.atlas", 0.25);
+        let premultiplied = rasterizer.render(&skeleton, camera, 200, 160);
+
+        assert!(straight.pixels.chunks_exact(4).any(|p| p[3] == 255));
+        assert!(max_difference(&straight, &premultiplied) <= 3);
+    }
+
+    #[test]
+    fn shared_edges_blend_once() {
+        let texture = Image::from_rgba8(1, 1, vec![255, 255, 255, 128]);
+        let corner = |x: f32, y: f32| ScreenVertex {
+            position: [x, y],
+            uv: [0.5, 0.5],
+            light: [1.0; 4],
+            dark: [0.0; 3],
+        };
+        // The diagonal runs exactly through pixel centers.
+        let quad = [
+            corner(0.5, 0.5),
+            corner(8.5, 0.5),
+            corner(8.5, 8.5),
+            corner(0.5, 8.5),
+        ];
+
+        let mut target = Target::from_image(&Image::new(10, 10));
+        for triangle in [[0, 1, 2], [2, 3, 0]] {
+            let vertices = triangle.map(|i| &quad[i]);
+            target.fill_triangle(vertices, &texture, false, BlendMode::Normal);
+        }
+        let mut image = Image::new(10, 10);
+        target.write_to(&mut image);
+
+        for y in 0..10 {
+            for x in 0..10 {
+                let expected = if (1..=8).contains(&x) && (1..=8).contains(&y) {
+                    128
+                } else {
+                    0
+                };
+                assert_eq!(expected, image.pixel(x, y)[3], "pixel {x}, {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn blend_modes() {
+        let source = [0.25, 0.5, 0.0, 0.5];
+        let destination = [0.5, 0.5, 1.0, 1.0];
+
+        assert_eq!(
+            [0.5, 0.75, 0.5, 1.0],
+            blend(BlendMode::Normal, source, destination)
+        );
+        assert_eq!(
+            [0.75, 1.0, 1.0, 1.0],
+            blend(BlendMode::Additive, source, destination)
+        );
+        assert_eq!(
+            [0.375, 0.5, 0.5, 1.0],
+            blend(BlendMode::Multiply, source, destination)
+        );
+        assert_eq!(
+            [0.625, 0.75, 1.0, 1.0],
+            blend(BlendMode::Screen, source, destination)
+        );
+
+        // Two color tinting maps the texture's black to the dark color.
+        assert_eq!(
+            [0.5, 0.0, 0.0, 1.0],
+            shade([0.0, 0.0, 0.0, 1.0], [1.0; 4], [0.5, 0.0, 0.0])
+        );
+    }
+
+    const CLIP_JSON: &str = r#"{
+        "skeleton": { "hash": "clip", "spine": "3.8.55" },
+        "bones": [ { "name": "root" } ],
+        "slots": [
+            { "name": "clip", "bone": "root", "attachment": "clip" },
+            { "name": "image", "bone": "root", "attachment": "chin" }
+        ],
+        "skins": [ {
+            "name": "default",
+            "attachments": {
+                "clip": { "clip": {
+                    "type": "clipping", "end": "image", "vertexCount": 4,
+                    "vertices": [ 0, -100, 200, -100, 200, 100, 0, 100 ]
+                } },
+                "image": { "chin": { "width": 214, "height": 146 } }
+            }
+        } ]
+    }"#;
+
+    #[test]
+    fn clipping() {
+        let mut rasterizer = Rasterizer::new();
+        // The test helper gives every page of the dragon atlas texture id 0.
+        rasterizer.insert_texture(
+            0,
+            Image::from_png(TEST_CASES[0].atlas().with_file_name("dragon.png")).unwrap(),
+            false,
+        );
+        let camera = Camera::new([0.0, 0.0], 1.0);
+        let coverage = |image: &Image, columns: std::ops::Range<u32>| {
+            columns
+                .flat_map(|x| (0..image.height).map(move |y| (x, y)))
+                .filter(|&(x, y)| image.pixel(x, y)[3] > 0)
+                .count()
+        };
+
+        let unclipped =
+            skeleton_data_from_json(&CLIP_JSON.replace(r#", "attachment": "clip""#, ""));
+        let mut skeleton = Skeleton::new(&unclipped);
+        skeleton.update_world_transforms();
+        let image = rasterizer.render(&skeleton, camera, 256, 256);
+        assert!(coverage(&image, 0..127) > 0);
+        assert!(coverage(&image, 129..256) > 0);
+
+        let clipped = skeleton_data_from_json(CLIP_JSON);
+        let mut skeleton = Skeleton::new(&clipped);
+        skeleton.update_world_transforms();
+        let image = rasterizer.render(&skeleton, camera, 256, 256);
+        assert_eq!(0, coverage(&image, 0..127));
+        assert!(coverage(&image, 129..256) > 0);
+    }
+}
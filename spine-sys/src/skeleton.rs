@@ -1,19 +1,42 @@
 #![allow(clippy::needless_pass_by_value)]
 
 use crate::{
-    animation::Animation, atlas::Atlas, enums::AttachmentType, enums::BlendMode, ffi, SpineError,
-    SpineMutPtr,
+    animation::{Animation, EventData},
+    atlas::Atlas,
+    color::Color,
+    enums::AttachmentType,
+    enums::BlendMode,
+    ffi, SpineError, SpineMutPtr,
 };
-use std::{convert::TryInto, ffi::*, marker::PhantomData, path::Path};
+use std::{
+    convert::{TryFrom, TryInto},
+    ffi::{CStr, CString},
+    marker::PhantomData,
+    os::raw::c_int,
+    path::Path,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BoneIndex(pub(crate) i32);
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SlotIndex(pub(crate) i32);
 
-pub struct BoneIndex(i32);
-pub struct SlotIndex(i32);
+const REGION_TRIANGLES: [u16; 6] = [0, 1, 2, 2, 3, 0];
+
+/// A single world space vertex of a slot's attachment, as consumed by renderers and `VertexEffect`s.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WorldVertex {
+    pub position: [f32; 2],
+    pub uv: [f32; 2],
+    pub light: Color,
+    pub dark: Color,
+}
 
 pub struct RegionAttachment<'a> {
     pub(crate) inner: *mut ffi::spRegionAttachment,
-    _lifetime: PhantomData<&'a ()>,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
 }
-impl<'a> RegionAttachment<'a> {
+impl RegionAttachment<'_> {
     /// Loads the 8 vertex positions for this attachments rendering into the provided `vertices`  buffer.
     ///
     /// # spine-c
@@ -49,7 +72,7 @@ impl<'a> RegionAttachment<'a> {
     pub fn texture_id(&self) -> u32 {
         // TODO: CLEAN THIS UP OMG
         unsafe {
-            let atlas_region = self.as_ref().rendererObject as *mut ffi::spAtlasRegion;
+            let atlas_region = self.as_ref().rendererObject.cast::<ffi::spAtlasRegion>();
             let page = (*atlas_region).page;
             (*page).rendererObject as u32
         }
@@ -59,9 +82,8 @@ impl<'a> RegionAttachment<'a> {
         self.as_ref().rotation
     }
 
-    pub fn color(&self) -> [f32; 4] {
-        let color = &self.as_ref().color;
-        [color.r, color.b, color.g, color.a]
+    pub fn color(&self) -> Color {
+        self.as_ref().color.into()
     }
 
     pub fn position(&self) -> [f32; 2] {
@@ -92,20 +114,114 @@ impl<'a> RegionAttachment<'a> {
     pub(crate) fn as_ref(&self) -> &ffi::spRegionAttachment {
         unsafe { self.inner.as_ref().unwrap() }
     }
-    pub(crate) fn as_mut(&mut self) -> &mut ffi::spRegionAttachment {
-        unsafe { self.inner.as_mut().unwrap() }
+}
+
+pub struct BoundingBoxAttachment<'a> {
+    pub(crate) inner: *mut ffi::spBoundingBoxAttachment,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
+}
+impl BoundingBoxAttachment<'_> {
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr(self.as_ref().super_.super_.name) }
+            .to_str()
+            .unwrap()
+    }
+
+    pub(crate) fn as_ref(&self) -> &ffi::spBoundingBoxAttachment {
+        unsafe { self.inner.as_ref().unwrap() }
+    }
+}
+
+pub struct PointAttachment<'a> {
+    pub(crate) inner: *mut ffi::spPointAttachment,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
+}
+impl PointAttachment<'_> {
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr(self.as_ref().super_.name) }
+            .to_str()
+            .unwrap()
+    }
+
+    /// The position of this point, relative to its bone.
+    pub fn position(&self) -> [f32; 2] {
+        let r = self.as_ref();
+        [r.x, r.y]
+    }
+
+    /// The rotation of this point in degrees, relative to its bone.
+    pub fn rotation(&self) -> f32 {
+        self.as_ref().rotation
+    }
+
+    /// The color of this point in the editor.
+    pub fn color(&self) -> Color {
+        self.as_ref().color.into()
+    }
+
+    /// Returns the world position of this point when attached to `bone`.
+    ///
+    /// # spine-c
+    /// Maps to calling `spPointAttachment_computeWorldPosition`
+    pub fn world_position(&self, bone: &Bone<'_>) -> [f32; 2] {
+        let mut position = [0.0; 2];
+        let [x, y] = &mut position;
+        unsafe { ffi::spPointAttachment_computeWorldPosition(self.inner, bone.inner, x, y) };
+
+        position
+    }
+
+    /// Returns the world rotation of this point in degrees when attached to `bone`.
+    ///
+    /// # spine-c
+    /// Maps to calling `spPointAttachment_computeWorldRotation`
+    pub fn world_rotation(&self, bone: &Bone<'_>) -> f32 {
+        unsafe { ffi::spPointAttachment_computeWorldRotation(self.inner, bone.inner) }
+    }
+
+    pub(crate) fn as_ref(&self) -> &ffi::spPointAttachment {
+        unsafe { self.inner.as_ref().unwrap() }
     }
 }
 
 pub struct Attachment<'a> {
     pub(crate) inner: *mut ffi::spAttachment,
-    _lifetime: PhantomData<&'a ()>,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
 }
-impl<'a> Attachment<'a> {
-    pub fn as_region_attachment(&mut self) -> RegionAttachment<'a> {
-        RegionAttachment {
-            inner: self.inner as *mut ffi::spRegionAttachment,
-            _lifetime: PhantomData::<&'a ()>::default(),
+impl Attachment<'_> {
+    /// Returns this attachment as a `RegionAttachment`, or `None` if it is of another type.
+    pub fn as_region_attachment(&self) -> Option<RegionAttachment<'_>> {
+        if self.kind() == AttachmentType::Region {
+            Some(RegionAttachment {
+                inner: self.inner.cast(),
+                _lifetime: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns this attachment as a `BoundingBoxAttachment`, or `None` if it is of another type.
+    pub fn as_bounding_box_attachment(&self) -> Option<BoundingBoxAttachment<'_>> {
+        if self.kind() == AttachmentType::BoundingBox {
+            Some(BoundingBoxAttachment {
+                inner: self.inner.cast(),
+                _lifetime: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns this attachment as a `PointAttachment`, or `None` if it is of another type.
+    pub fn as_point_attachment(&self) -> Option<PointAttachment<'_>> {
+        if self.kind() == AttachmentType::Point {
+            Some(PointAttachment {
+                inner: self.inner.cast(),
+                _lifetime: PhantomData,
+            })
+        } else {
+            None
         }
     }
 
@@ -122,17 +238,20 @@ impl<'a> Attachment<'a> {
     pub(crate) fn as_ref(&self) -> &ffi::spAttachment {
         unsafe { self.inner.as_ref().unwrap() }
     }
-    pub(crate) fn as_mut(&mut self) -> &mut ffi::spAttachment {
-        unsafe { self.inner.as_mut().unwrap() }
-    }
 }
 
 pub struct Slot<'a> {
     pub(crate) inner: *mut ffi::spSlot,
-    _lifetime: PhantomData<&'a ()>,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
 }
 impl<'a> Slot<'a> {
-    pub fn bone(&self) -> Option<Bone<'_>> {
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr((*self.as_ref().data).name) }
+            .to_str()
+            .unwrap()
+    }
+
+    pub fn bone(&self) -> Option<Bone<'a>> {
         let r = self.as_ref();
 
         if r.bone.is_null() {
@@ -140,14 +259,24 @@ impl<'a> Slot<'a> {
         } else {
             Some(Bone {
                 inner: r.bone,
-                _lifetime: PhantomData::<&'a ()>::default(),
+                _lifetime: PhantomData,
             })
         }
     }
 
-    pub fn color(&self) -> [f32; 4] {
-        let color = &self.as_ref().color;
-        [color.r, color.b, color.g, color.a]
+    pub fn color(&self) -> Color {
+        self.as_ref().color.into()
+    }
+
+    /// Whether this slot uses two color tinting, in which case `Slot::dark_color` is available.
+    pub fn has_two_color_tint(&self) -> bool {
+        !self.as_ref().darkColor.is_null()
+    }
+
+    /// The dark color used for two color tinting. Only the red, green and blue channels are used.
+    /// Returns `None` if the slot does not use two color tinting.
+    pub fn dark_color(&self) -> Option<Color> {
+        unsafe { self.as_ref().darkColor.as_ref() }.map(|color| (*color).into())
     }
 
     pub fn blend_mode(&self) -> BlendMode {
@@ -165,7 +294,7 @@ impl<'a> Slot<'a> {
         } else {
             Some(Attachment {
                 inner: r.attachment,
-                _lifetime: PhantomData::<&'a ()>::default(),
+                _lifetime: PhantomData,
             })
         }
     }
@@ -174,6 +303,122 @@ impl<'a> Slot<'a> {
         unsafe { SlotIndex((*self.as_ref().data).index) }
     }
 
+    /// Computes the world space vertices of the active attachment of this slot. Region attachments
+    /// produce 4 vertices and mesh attachments produce one vertex per mesh vertex; any other
+    /// attachment type produces no vertices.
+    ///
+    /// The light color of each vertex is the skeleton, slot and attachment colors multiplied together,
+    /// and the dark color is the slot's two color tint (or transparent black if none is set).
+    ///
+    /// # spine-c
+    /// Maps to calling `spRegionAttachment_computeWorldVertices` or `spVertexAttachment_computeWorldVertices`
+    pub fn world_vertices(&self) -> Vec<WorldVertex> {
+        let r = self.as_ref();
+        if r.attachment.is_null() || r.bone.is_null() {
+            return Vec::new();
+        }
+
+        let skeleton_color = Color::from(unsafe { (*(*r.bone).skeleton).color });
+        let dark = self
+            .dark_color()
+            .map_or(Color::TRANSPARENT, |dark| Color { a: 1.0, ..dark });
+        let tint = |color: ffi::spColor| skeleton_color * r.color.into() * color.into();
+
+        let (positions, uvs, light) = unsafe {
+            match AttachmentType::from((*r.attachment).type_) {
+                AttachmentType::Region => {
+                    let region = r.attachment.cast::<ffi::spRegionAttachment>();
+                    let mut positions = vec![0.0; 8];
+                    ffi::spRegionAttachment_computeWorldVertices(
+                        region,
+                        r.bone,
+                        positions.as_mut_ptr(),
+                        0,
+                        2,
+                    );
+                    (positions, &(&(*region).uvs)[..], tint((*region).color))
+                }
+                AttachmentType::Mesh | AttachmentType::LinkedMesh => {
+                    let mesh = r.attachment.cast::<ffi::spMeshAttachment>();
+                    let length = (*mesh).super_.worldVerticesLength;
+                    let mut positions = vec![0.0; length.try_into().unwrap()];
+                    ffi::spVertexAttachment_computeWorldVertices(
+                        mesh.cast(),
+                        self.inner,
+                        0,
+                        length,
+                        positions.as_mut_ptr(),
+                        0,
+                        2,
+                    );
+                    let uvs = std::slice::from_raw_parts((*mesh).uvs, positions.len());
+                    (positions, uvs, tint((*mesh).color))
+                }
+                _ => return Vec::new(),
+            }
+        };
+
+        positions
+            .chunks_exact(2)
+            .zip(uvs.chunks_exact(2))
+            .map(|(position, uv)| WorldVertex {
+                position: [position[0], position[1]],
+                uv: [uv[0], uv[1]],
+                light,
+                dark,
+            })
+            .collect()
+    }
+
+    /// Returns the triangle indices into the vertices produced by `Slot::world_vertices`.
+    pub fn triangles(&self) -> Vec<u16> {
+        let r = self.as_ref();
+        if r.attachment.is_null() {
+            return Vec::new();
+        }
+
+        unsafe {
+            match AttachmentType::from((*r.attachment).type_) {
+                AttachmentType::Region => REGION_TRIANGLES.to_vec(),
+                AttachmentType::Mesh | AttachmentType::LinkedMesh => {
+                    let mesh = r.attachment.cast::<ffi::spMeshAttachment>();
+                    std::slice::from_raw_parts(
+                        (*mesh).triangles,
+                        (*mesh).trianglesCount.try_into().unwrap(),
+                    )
+                    .to_vec()
+                }
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    /// Returns the texture id of the atlas page holding the image of the active region or mesh
+    /// attachment, as provided during `Atlas` loading.
+    pub fn texture_id(&self) -> Option<u32> {
+        let r = self.as_ref();
+        if r.attachment.is_null() {
+            return None;
+        }
+
+        unsafe {
+            let region = match AttachmentType::from((*r.attachment).type_) {
+                AttachmentType::Region => {
+                    (*r.attachment.cast::<ffi::spRegionAttachment>()).rendererObject
+                }
+                AttachmentType::Mesh | AttachmentType::LinkedMesh => {
+                    (*r.attachment.cast::<ffi::spMeshAttachment>()).rendererObject
+                }
+                _ => return None,
+            }
+            .cast::<ffi::spAtlasRegion>();
+
+            region
+                .as_ref()
+                .map(|region| (*region.page).rendererObject as u32)
+        }
+    }
+
     pub(crate) fn as_ref(&self) -> &ffi::spSlot {
         unsafe { self.inner.as_ref().unwrap() }
     }
@@ -182,15 +427,102 @@ impl<'a> Slot<'a> {
     }
 }
 
+spine_handle_mut!(
+    /// A `Slot` that can also be modified, borrowed from a `&mut Skeleton`.
+    SlotMut,
+    Slot,
+    ffi::spSlot
+);
+impl SlotMut<'_> {
+    pub fn set_color(&mut self, color: Color) {
+        self.as_mut().color = color.into();
+    }
+
+    /// Sets the dark color for two color tinting. This has no effect if the slot does not use two
+    /// color tinting, since spine-c only allocates a dark color for slots set up with one.
+    pub fn set_dark_color(&mut self, color: Color) {
+        if let Some(dark) = unsafe { self.as_mut().darkColor.as_mut() } {
+            *dark = color.into();
+        }
+    }
+}
+
 pub struct Bone<'a> {
     pub(crate) inner: *mut ffi::spBone,
-    _lifetime: PhantomData<&'a ()>,
+    pub(crate) _lifetime: PhantomData<&'a ()>,
 }
-impl<'a> Bone<'a> {
+impl Bone<'_> {
     pub fn index(&self) -> BoneIndex {
         unsafe { BoneIndex((*self.as_ref().data).index) }
     }
 
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr((*self.as_ref().data).name) }
+            .to_str()
+            .unwrap()
+    }
+
+    pub fn length(&self) -> f32 {
+        unsafe { (*self.as_ref().data).length }
+    }
+
+    /// The local position of this bone, relative to its parent.
+    pub fn position(&self) -> [f32; 2] {
+        let r = self.as_ref();
+        [r.x, r.y]
+    }
+
+    /// The local rotation of this bone in degrees, relative to its parent.
+    pub fn rotation(&self) -> f32 {
+        self.as_ref().rotation
+    }
+
+    pub fn world_position(&self) -> [f32; 2] {
+        let r = self.as_ref();
+        [r.worldX, r.worldY]
+    }
+
+    /// # spine-c
+    /// Maps to calling `spBone_getWorldRotationX`
+    pub fn world_rotation(&self) -> f32 {
+        unsafe { ffi::spBone_getWorldRotationX(self.inner) }
+    }
+
+    /// # spine-c
+    /// Maps to calling `spBone_getWorldScaleX` and `spBone_getWorldScaleY`
+    pub fn world_scale(&self) -> [f32; 2] {
+        unsafe {
+            [
+                ffi::spBone_getWorldScaleX(self.inner),
+                ffi::spBone_getWorldScaleY(self.inner),
+            ]
+        }
+    }
+
+    /// Converts a world space position into the local space of this bone.
+    ///
+    /// # spine-c
+    /// Maps to calling `spBone_worldToLocal`
+    pub fn world_to_local(&self, world: [f32; 2]) -> [f32; 2] {
+        let mut local = [0.0; 2];
+        let [x, y] = &mut local;
+        unsafe { ffi::spBone_worldToLocal(self.inner, world[0], world[1], x, y) };
+
+        local
+    }
+
+    /// Converts a position in the local space of this bone into world space.
+    ///
+    /// # spine-c
+    /// Maps to calling `spBone_localToWorld`
+    pub fn local_to_world(&self, local: [f32; 2]) -> [f32; 2] {
+        let mut world = [0.0; 2];
+        let [x, y] = &mut world;
+        unsafe { ffi::spBone_localToWorld(self.inner, local[0], local[1], x, y) };
+
+        world
+    }
+
     pub(crate) fn as_ref(&self) -> &ffi::spBone {
         unsafe { self.inner.as_ref().unwrap() }
     }
@@ -199,6 +531,24 @@ impl<'a> Bone<'a> {
     }
 }
 
+spine_handle_mut!(
+    /// A `Bone` that can also be modified, borrowed from a `&mut Skeleton`.
+    BoneMut,
+    Bone,
+    ffi::spBone
+);
+impl BoneMut<'_> {
+    pub fn set_position(&mut self, position: [f32; 2]) {
+        let r = self.as_mut();
+        r.x = position[0];
+        r.y = position[1];
+    }
+
+    pub fn set_rotation(&mut self, rotation: f32) {
+        self.as_mut().rotation = rotation;
+    }
+}
+
 pub struct BoneData<'a> {
     pub(crate) inner: &'a ffi::spBoneData,
 }
@@ -206,17 +556,170 @@ impl<'a> BoneData<'a> {
     pub fn name(&self) -> &str {
         unsafe { CStr::from_ptr(self.inner.name) }.to_str().unwrap()
     }
+
+    pub fn index(&self) -> BoneIndex {
+        BoneIndex(self.inner.index)
+    }
+
+    /// The parent bone, or `None` for the root bone.
+    pub fn parent(&self) -> Option<BoneData<'a>> {
+        unsafe { self.inner.parent.as_ref() }.map(|inner| BoneData { inner })
+    }
+
+    pub fn length(&self) -> f32 {
+        self.inner.length
+    }
+
+    /// The setup pose position, relative to the parent bone.
+    pub fn position(&self) -> [f32; 2] {
+        [self.inner.x, self.inner.y]
+    }
+
+    /// The setup pose rotation in degrees, relative to the parent bone.
+    pub fn rotation(&self) -> f32 {
+        self.inner.rotation
+    }
+
+    pub fn scale(&self) -> [f32; 2] {
+        [self.inner.scaleX, self.inner.scaleY]
+    }
+
+    pub fn shear(&self) -> [f32; 2] {
+        [self.inner.shearX, self.inner.shearY]
+    }
+
+    /// Whether this bone is only active when a skin containing it is set.
+    pub fn skin_required(&self) -> bool {
+        self.inner.skinRequired != 0
+    }
 }
-impl<'a> std::fmt::Debug for BoneData<'a> {
+impl std::fmt::Debug for BoneData<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "Bone {{ name: {} }}", self.name())
     }
 }
 
+/// The setup pose of a slot, shared by every `Skeleton` of a `SkeletonData`.
+pub struct SlotData<'a> {
+    pub(crate) inner: &'a ffi::spSlotData,
+}
+impl<'a> SlotData<'a> {
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr(self.inner.name) }.to_str().unwrap()
+    }
+
+    pub fn index(&self) -> SlotIndex {
+        SlotIndex(self.inner.index)
+    }
+
+    pub fn bone(&self) -> BoneData<'a> {
+        BoneData {
+            inner: unsafe { &*self.inner.boneData },
+        }
+    }
+
+    /// The name of the attachment visible in the setup pose.
+    pub fn attachment_name(&self) -> Option<&str> {
+        if self.inner.attachmentName.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(self.inner.attachmentName) }
+                    .to_str()
+                    .unwrap(),
+            )
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        self.inner.color.into()
+    }
+
+    /// The setup pose dark color, if this slot uses two color tinting.
+    pub fn dark_color(&self) -> Option<Color> {
+        unsafe { self.inner.darkColor.as_ref() }.map(|color| (*color).into())
+    }
+
+    pub fn blend_mode(&self) -> BlendMode {
+        self.inner.blendMode.into()
+    }
+}
+impl std::fmt::Debug for SlotData<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Slot {{ name: {} }}", self.name())
+    }
+}
+
+/// A named set of attachments, plus the bones and constraints only active while it is set.
+///
+/// # spine-c
+/// Wraps `spSkin`
+pub struct Skin<'a> {
+    pub(crate) inner: &'a ffi::spSkin,
+    pub(crate) data: &'a ffi::spSkeletonData,
+}
+impl<'a> Skin<'a> {
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr(self.inner.name) }.to_str().unwrap()
+    }
+
+    /// Every attachment of this skin, ordered by slot and then by name.
+    ///
+    /// # spine-c
+    /// Maps to calling `spSkin_getAttachments`
+    pub fn attachments(&self) -> Vec<SkinAttachment<'a>> {
+        let mut attachments = Vec::new();
+        let mut entry = unsafe { ffi::spSkin_getAttachments(self.inner) };
+        while let Some(e) = unsafe { entry.as_ref() } {
+            let slot = unsafe { &**self.data.slots.add(e.slotIndex.try_into().unwrap()) };
+            attachments.push(SkinAttachment {
+                slot: SlotData { inner: slot },
+                name: unsafe { CStr::from_ptr(e.name) }.to_str().unwrap(),
+                attachment: Attachment {
+                    inner: e.attachment,
+                    _lifetime: PhantomData,
+                },
+            });
+            entry = e.next;
+        }
+
+        attachments.sort_by(|a, b| (a.slot.inner.index, a.name).cmp(&(b.slot.inner.index, b.name)));
+        attachments
+    }
+
+    /// The bones that are only active while this skin is set.
+    pub fn bones(&self) -> Vec<BoneData<'a>> {
+        let bones = unsafe { &*self.inner.bones };
+        (0..bones.size.try_into().unwrap())
+            .map(|n| BoneData {
+                inner: unsafe { &**bones.items.add(n) },
+            })
+            .collect()
+    }
+}
+
+/// An attachment of a `Skin`, with the slot and name it is stored under.
+pub struct SkinAttachment<'a> {
+    pub slot: SlotData<'a>,
+    /// The name the slot refers to the attachment by, which can differ from `Attachment::name`.
+    pub name: &'a str,
+    pub attachment: Attachment<'a>,
+}
+
+/// A posable instance of a `SkeletonData`.
+///
+/// A skeleton keeps its `SkeletonData` alive, and is `Send` but not `Sync`: each thread animates
+/// its own skeletons, while the data is shared between them.
 pub struct Skeleton {
     pub(crate) inner: SpineMutPtr<ffi::spSkeleton>,
+    pub(crate) data: SpineMutPtr<ffi::spSkeletonData>,
+    pub(crate) atlas: SpineMutPtr<ffi::spAtlas>,
 }
 
+// SAFETY: a skeleton exclusively owns its spine-c instance state, and only reads its shared
+// `SkeletonData`, which is `Sync`.
+unsafe impl Send for Skeleton {}
+
 impl Skeleton {
     pub fn new(data: &SkeletonData) -> Self {
         Self {
@@ -224,6 +727,8 @@ impl Skeleton {
                 unsafe { ffi::spSkeleton_create(data.inner.as_mut_ptr()) },
                 Some(ffi::spSkeleton_dispose),
             ),
+            data: data.inner.clone(),
+            atlas: data.atlas.clone(),
         }
     }
 
@@ -236,7 +741,7 @@ impl Skeleton {
                 let inner = *(inner_ref.slots.add(n));
                 converted.push(Slot {
                     inner,
-                    _lifetime: PhantomData::<&'_ ()>::default(),
+                    _lifetime: PhantomData::<&'_ ()>,
                 });
             }
         }
@@ -253,7 +758,7 @@ impl Skeleton {
                 let inner = *(inner_ref.drawOrder.add(n));
                 converted.push(Slot {
                     inner,
-                    _lifetime: PhantomData::<&'_ ()>::default(),
+                    _lifetime: PhantomData::<&'_ ()>,
                 });
             }
         }
@@ -270,7 +775,7 @@ impl Skeleton {
                 let inner = *(inner_ref.bones.add(n));
                 converted.push(Bone {
                     inner,
-                    _lifetime: PhantomData::<&'_ ()>::default(),
+                    _lifetime: PhantomData::<&'_ ()>,
                 });
             }
         }
@@ -278,28 +783,83 @@ impl Skeleton {
         converted
     }
 
+    pub fn find_bone(&self, name: &str) -> Option<Bone<'_>> {
+        self.find_bone_ptr(name).map(|inner| Bone {
+            inner,
+            _lifetime: PhantomData::<&'_ ()>,
+        })
+    }
+
+    /// Finds a bone by name, for modifying it.
+    pub fn find_bone_mut(&mut self, name: &str) -> Option<BoneMut<'_>> {
+        self.find_bone_ptr(name).map(BoneMut::new)
+    }
+
+    fn find_bone_ptr(&self, name: &str) -> Option<*mut ffi::spBone> {
+        let c_name = CString::new(name).unwrap();
+        let inner = unsafe { ffi::spSkeleton_findBone(self.inner.as_ptr(), c_name.as_ptr()) };
+
+        if inner.is_null() {
+            spine_log!(debug, "no bone named {:?}", name);
+            None
+        } else {
+            Some(inner)
+        }
+    }
+
+    /// Returns the bone at `index` for modifying it.
+    ///
+    /// # Panics
+    /// If `index` does not refer to a bone of this skeleton.
+    pub fn bone_at_mut(&mut self, index: BoneIndex) -> BoneMut<'_> {
+        BoneMut::new(self.bone_ptr(index))
+    }
+
+    pub(crate) fn bone_ptr(&self, index: BoneIndex) -> *mut ffi::spBone {
+        unsafe { bone_ptr(self.inner.as_mut_ptr(), index) }
+    }
+
     pub fn bone_index(&self, name: &str) -> BoneIndex {
-        let name = CString::new(name).unwrap();
-        BoneIndex(unsafe { ffi::spSkeleton_findBoneIndex(self.inner.as_mut_ptr(), name.as_ptr()) })
+        let c_name = CString::new(name).unwrap();
+        let index =
+            unsafe { ffi::spSkeleton_findBoneIndex(self.inner.as_mut_ptr(), c_name.as_ptr()) };
+        if index < 0 {
+            spine_log!(warn, "no bone named {:?}", name);
+        }
+        BoneIndex(index)
     }
 
     pub fn slot(&self, name: &str) -> Option<Slot<'_>> {
-        let name = CString::new(name).unwrap();
-        let inner = unsafe { ffi::spSkeleton_findSlot(self.inner.as_mut_ptr(), name.as_ptr()) };
+        self.find_slot_ptr(name).map(|inner| Slot {
+            inner,
+            _lifetime: PhantomData::<&'_ ()>,
+        })
+    }
+
+    /// Finds a slot by name, for modifying it.
+    pub fn slot_mut(&mut self, name: &str) -> Option<SlotMut<'_>> {
+        self.find_slot_ptr(name).map(SlotMut::new)
+    }
+
+    fn find_slot_ptr(&self, name: &str) -> Option<*mut ffi::spSlot> {
+        let c_name = CString::new(name).unwrap();
+        let inner = unsafe { ffi::spSkeleton_findSlot(self.inner.as_mut_ptr(), c_name.as_ptr()) };
 
         if inner.is_null() {
+            spine_log!(debug, "no slot named {:?}", name);
             None
         } else {
-            Some(Slot {
-                inner,
-                _lifetime: PhantomData::<&'_ ()>::default(),
-            })
+            Some(inner)
         }
     }
 
-    pub fn color(&self) -> [f32; 4] {
-        let color = &self.inner.as_ref().color;
-        [color.r, color.b, color.g, color.a]
+    /// The color tinting every slot of the skeleton.
+    pub fn color(&self) -> Color {
+        self.inner.as_ref().color.into()
+    }
+
+    pub fn set_color(&mut self, color: Color) {
+        self.inner.as_mut().color = color.into();
     }
 
     pub fn position(&self) -> [f32; 2] {
@@ -307,18 +867,81 @@ impl Skeleton {
         [r.x, r.y]
     }
 
+    /// Moves the skeleton root in world space. Takes effect on the next
+    /// `Skeleton::update_world_transforms`.
+    pub fn set_position(&mut self, position: [f32; 2]) {
+        let r = self.inner.as_mut();
+        r.x = position[0];
+        r.y = position[1];
+    }
+
     pub fn scale(&self) -> [f32; 2] {
         let r = self.inner.as_ref();
         [r.scaleX, r.scaleY]
     }
 
+    /// Scales the whole skeleton. A negative scale mirrors it on that axis, for example
+    /// `[-1.0, 1.0]` to face a character left. Takes effect on the next
+    /// `Skeleton::update_world_transforms`.
+    pub fn set_scale(&mut self, scale: [f32; 2]) {
+        let r = self.inner.as_mut();
+        r.scaleX = scale[0];
+        r.scaleY = scale[1];
+    }
+
+    /// Mirrors the skeleton horizontally and/or vertically, keeping the current scale magnitude.
+    pub fn set_flip(&mut self, flip_x: bool, flip_y: bool) {
+        let [x, y] = self.scale();
+        let sign = |flip: bool| if flip { -1.0 } else { 1.0 };
+        self.set_scale([x.abs() * sign(flip_x), y.abs() * sign(flip_y)]);
+    }
+
+    /// Looks up the `PointAttachment` named `name` for the slot named `slot`, in the current skin or
+    /// the default skin, and returns its world position and rotation in degrees. World transforms
+    /// must have been updated with `Skeleton::update_world_transforms` beforehand.
+    ///
+    /// Returns `None` if the slot or a point attachment with that name does not exist.
+    ///
+    /// # spine-c
+    /// Maps to calling `spSkeleton_getAttachmentForSlotName` and `spPointAttachment_computeWorld*`
+    pub fn point_world_transform(&self, slot: &str, name: &str) -> Option<([f32; 2], f32)> {
+        let bone = self.slot(slot)?.bone()?;
+
+        let slot = CString::new(slot).unwrap();
+        let name = CString::new(name).unwrap();
+        let inner = unsafe {
+            ffi::spSkeleton_getAttachmentForSlotName(
+                self.inner.as_ptr(),
+                slot.as_ptr(),
+                name.as_ptr(),
+            )
+        };
+        if inner.is_null() {
+            spine_log!(debug, "no attachment named {:?} in slot {:?}", name, slot);
+            return None;
+        }
+
+        let attachment = Attachment {
+            inner,
+            _lifetime: PhantomData,
+        };
+        let point = attachment.as_point_attachment()?;
+
+        Some((point.world_position(&bone), point.world_rotation(&bone)))
+    }
+
     fn time(&self) -> f32 {
         self.inner.as_ref().time
     }
 
     pub fn slot_index(&self, name: &str) -> SlotIndex {
-        let name = CString::new(name).unwrap();
-        SlotIndex(unsafe { ffi::spSkeleton_findSlotIndex(self.inner.as_mut_ptr(), name.as_ptr()) })
+        let c_name = CString::new(name).unwrap();
+        let index =
+            unsafe { ffi::spSkeleton_findSlotIndex(self.inner.as_mut_ptr(), c_name.as_ptr()) };
+        if index < 0 {
+            spine_log!(warn, "no slot named {:?}", name);
+        }
+        SlotIndex(index)
     }
 
     pub fn reset(&mut self) {
@@ -333,6 +956,23 @@ impl Skeleton {
         unsafe { ffi::spSkeleton_setSlotsToSetupPose(self.inner.as_mut_ptr()) }
     }
 
+    /// Sets the skin used to look up attachments, returning false if there is no skin named `name`.
+    ///
+    /// Attachments from the previous skin stay visible until the slots are reset with
+    /// `Skeleton::reset_slots` or changed by an animation.
+    ///
+    /// # spine-c
+    /// Maps to calling `spSkeleton_setSkinByName`
+    pub fn set_skin_by_name(&mut self, name: &str) -> bool {
+        let c_name = CString::new(name).unwrap();
+        let found =
+            unsafe { ffi::spSkeleton_setSkinByName(self.inner.as_mut_ptr(), c_name.as_ptr()) } != 0;
+        if !found {
+            spine_log!(warn, "no skin named {:?}", name);
+        }
+        found
+    }
+
     pub fn update_cache(&mut self) {
         unsafe { ffi::spSkeleton_updateCache(self.inner.as_mut_ptr()) }
     }
@@ -346,14 +986,86 @@ impl Skeleton {
     }
 }
 
+/// Returns the bone at `index` of `skeleton`.
+///
+/// # Panics
+/// If `index` does not refer to a bone of `skeleton`.
+pub(crate) unsafe fn bone_ptr(
+    skeleton: *mut ffi::spSkeleton,
+    index: BoneIndex,
+) -> *mut ffi::spBone {
+    let skeleton = &*skeleton;
+    assert!(
+        (0..skeleton.bonesCount).contains(&index.0),
+        "bone index {} out of range",
+        index.0
+    );
+    *skeleton.bones.add(index.0.try_into().unwrap())
+}
+
+/// Returns the slot at `index` of `skeleton`.
+///
+/// # Panics
+/// If `index` does not refer to a slot of `skeleton`.
+pub(crate) unsafe fn slot_ptr(
+    skeleton: *mut ffi::spSkeleton,
+    index: SlotIndex,
+) -> *mut ffi::spSlot {
+    let skeleton = &*skeleton;
+    assert!(
+        (0..skeleton.slotsCount).contains(&index.0),
+        "slot index {} out of range",
+        index.0
+    );
+    *skeleton.slots.add(index.0.try_into().unwrap())
+}
+
+/// Sets whether world space y points down for every skeleton, for renderers with a top-left origin.
+///
+/// # Safety
+/// This is global spine-c state, read without synchronization whenever world transforms are
+/// computed. It must be set at startup, while no other thread uses spine, before any world
+/// transforms are computed.
+///
+/// # spine-c
+/// Maps to calling `spBone_setYDown`
+pub unsafe fn set_y_down(y_down: bool) {
+    ffi::spBone_setYDown(c_int::from(y_down));
+}
+
+/// # spine-c
+/// Maps to calling `spBone_isYDown`
+pub fn is_y_down() -> bool {
+    unsafe { ffi::spBone_isYDown() != 0 }
+}
+
+/// The setup pose, skins and animations of a skeleton, loaded once and shared by every `Skeleton`
+/// created from it.
+///
+/// Skeleton data is `Send + Sync`: spine-c only reads it while creating, animating and posing
+/// skeletons, so a single instance can be shared across threads (for example in an `Arc`) while
+/// each thread updates its own `Skeleton` and `AnimationState`. For the same reason its setters
+/// panic while skeletons or animation states of the data exist.
 pub struct SkeletonData {
     pub(crate) inner: SpineMutPtr<ffi::spSkeletonData>,
     pub(crate) atlas: SpineMutPtr<ffi::spAtlas>,
 }
 
+// SAFETY: the only mutations go through `&mut self` while nothing else shares the data, see
+// `SkeletonData::as_mut`, and spine-c never writes to skeleton data after loading. Loading itself
+// is serialized by `GLOBAL_STATE`.
+unsafe impl Send for SkeletonData {}
+unsafe impl Sync for SkeletonData {}
+
 impl SkeletonData {
+    /// The spine-c data, for changing it while no skeleton or animation state can read it.
+    ///
+    /// # Panics
+    /// If a `Skeleton`, `AnimationStateData` or `AnimationState` of this data exists.
     pub(crate) fn as_mut(&mut self) -> &mut ffi::spSkeletonData {
-        self.inner.as_mut()
+        self.inner
+            .get_mut()
+            .expect("skeleton data cannot change while skeletons or animation states use it")
     }
     pub(crate) fn as_ref(&self) -> &ffi::spSkeletonData {
         self.inner.as_ref()
@@ -367,7 +1079,8 @@ impl SkeletonData {
                 let inner = *(self.as_ref().animations.add(n));
                 converted.push(Animation {
                     inner,
-                    _lifetime: PhantomData::<&'a ()>::default(),
+                    data: self.as_ref(),
+                    _lifetime: PhantomData::<&'a ()>,
                 });
             }
         }
@@ -375,7 +1088,7 @@ impl SkeletonData {
         converted
     }
 
-    pub fn bones<'a>(&'a self) -> Vec<BoneData<'a>> {
+    pub fn bones(&self) -> Vec<BoneData<'_>> {
         let mut converted = Vec::with_capacity(self.as_ref().bonesCount.try_into().unwrap());
 
         for n in 0..self.as_ref().bonesCount.try_into().unwrap() {
@@ -390,6 +1103,60 @@ impl SkeletonData {
         converted
     }
 
+    /// The version of the Spine editor this data was exported from.
+    pub fn version(&self) -> Option<&str> {
+        let version = self.as_ref().version;
+        if version.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(version) }.to_str().unwrap())
+        }
+    }
+
+    /// The hash the Spine editor assigned to this export.
+    pub fn hash(&self) -> Option<&str> {
+        let hash = self.as_ref().hash;
+        if hash.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(hash) }.to_str().unwrap())
+        }
+    }
+
+    pub fn slots(&self) -> Vec<SlotData<'_>> {
+        let r = self.as_ref();
+        (0..r.slotsCount.try_into().unwrap())
+            .map(|n| SlotData {
+                inner: unsafe { &**r.slots.add(n) },
+            })
+            .collect()
+    }
+
+    pub fn skins(&self) -> Vec<Skin<'_>> {
+        let r = self.as_ref();
+        (0..r.skinsCount.try_into().unwrap())
+            .map(|n| Skin {
+                inner: unsafe { &**r.skins.add(n) },
+                data: r,
+            })
+            .collect()
+    }
+
+    /// The skin holding attachments that are not in any named skin.
+    pub fn default_skin(&self) -> Option<Skin<'_>> {
+        let r = self.as_ref();
+        unsafe { r.defaultSkin.as_ref() }.map(|inner| Skin { inner, data: r })
+    }
+
+    pub fn events(&self) -> Vec<EventData<'_>> {
+        let r = self.as_ref();
+        (0..r.eventsCount.try_into().unwrap())
+            .map(|n| EventData {
+                inner: unsafe { &**r.events.add(n) },
+            })
+            .collect()
+    }
+
     pub fn strings(&self) -> Vec<&str> {
         let mut converted = Vec::new();
 
@@ -416,23 +1183,73 @@ impl SkeletonData {
         (r.width, r.height)
     }
 
+    /// # Panics
+    /// If a `Skeleton`, `AnimationStateData` or `AnimationState` of this data exists.
     pub fn set_position(&mut self, position: (f32, f32)) {
-        let r = self.inner.as_mut();
+        let r = self.as_mut();
         r.x = position.0;
         r.y = position.1;
     }
 
+    /// # Panics
+    /// If a `Skeleton`, `AnimationStateData` or `AnimationState` of this data exists.
     pub fn set_dimensions(&mut self, dimensions: (f32, f32)) {
-        let r = self.inner.as_mut();
+        let r = self.as_mut();
         r.width = dimensions.0;
         r.height = dimensions.1;
     }
 
+    /// Loads a `SkeletonData` instance from the provided JSON file path.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadSkeleton` instance, with a text message detailing why loading failed.
+    pub fn from_json_file<P>(path: P, atlas: Atlas) -> Result<Self, SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let path_str_c = CString::new(path.as_ref().to_str().ok_or_else(|| {
+            SpineError::FailLoadSkeleton("Failed to convert path to string".to_owned())
+        })?)
+        .map_err(|e| {
+            SpineError::FailLoadSkeleton(format!("Failed to convert path to string: {e:?}"))
+        })?;
+
+        let _guard = crate::lock_global_state();
+        let start = std::time::Instant::now();
+        unsafe {
+            let json = ffi::spSkeletonJson_create(atlas.inner.as_mut_ptr());
+            if json.is_null() {
+                return Err(SpineError::FailLoadSkeleton(
+                    "failed to begin json data load".to_owned(),
+                ));
+            }
+            let data = ffi::spSkeletonJson_readSkeletonDataFile(json, path_str_c.as_ptr());
+            if data.is_null() {
+                let error = CStr::from_ptr((*json).error).to_string_lossy().into_owned();
+                ffi::spSkeletonJson_dispose(json);
+                return Err(SpineError::FailLoadSkeleton(error));
+            }
+
+            ffi::spSkeletonJson_dispose(json);
+
+            let skeleton_data = Self {
+                inner: SpineMutPtr::new(data, Some(ffi::spSkeletonData_dispose)),
+                atlas: atlas.inner,
+            };
+            skeleton_data.log_loaded(&path.as_ref().display(), start.elapsed());
+            Ok(skeleton_data)
+        }
+    }
+
     /// Loads a `SkeletonData` instance from the provided binary file path.
     ///
+    /// # Safety
+    /// spine-c does not bounds check while reading, so the file must be a complete Spine 3.8
+    /// binary export, see `from_binary_bytes`.
+    ///
     /// # Errors
     /// Returns a `SpineError::FailLoadSkeleton` instance, with a text message detailing why loading failed.
-    pub fn from_binary_file<P>(path: P, atlas: Atlas) -> Result<Self, SpineError>
+    pub unsafe fn from_binary_file<P>(path: P, atlas: Atlas) -> Result<Self, SpineError>
     where
         P: AsRef<Path>,
     {
@@ -440,9 +1257,11 @@ impl SkeletonData {
             SpineError::FailLoadSkeleton("Failed to convert path to string".to_owned())
         })?)
         .map_err(|e| {
-            SpineError::FailLoadSkeleton(format!("Failed to convert path to string: {:?}", e))
+            SpineError::FailLoadSkeleton(format!("Failed to convert path to string: {e:?}"))
         })?;
 
+        let _guard = crate::lock_global_state();
+        let start = std::time::Instant::now();
         unsafe {
             let binary_data = ffi::spSkeletonBinary_create(atlas.inner.as_mut_ptr());
             if binary_data.is_null() {
@@ -452,34 +1271,140 @@ impl SkeletonData {
             }
             let data = ffi::spSkeletonBinary_readSkeletonDataFile(binary_data, path_str_c.as_ptr());
             if data.is_null() {
+                let error = if (*binary_data).error.is_null() {
+                    "Failed to read binary file".to_owned()
+                } else {
+                    CStr::from_ptr((*binary_data).error)
+                        .to_string_lossy()
+                        .into_owned()
+                };
                 ffi::spSkeletonBinary_dispose(binary_data);
+                return Err(SpineError::FailLoadSkeleton(error));
+            }
+
+            ffi::spSkeletonBinary_dispose(binary_data);
+
+            let skeleton_data = Self {
+                inner: SpineMutPtr::new(data, Some(ffi::spSkeletonData_dispose)),
+                atlas: atlas.inner,
+            };
+            skeleton_data.log_loaded(&path.as_ref().display(), start.elapsed());
+            Ok(skeleton_data)
+        }
+    }
+
+    /// Loads a `SkeletonData` instance from the contents of a JSON file.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadSkeleton` instance, with a text message detailing why loading failed.
+    pub fn from_json_str(json: &str, atlas: Atlas) -> Result<Self, SpineError> {
+        let json_c = CString::new(json).map_err(|e| {
+            SpineError::FailLoadSkeleton(format!("Failed to convert json to string: {e:?}"))
+        })?;
+
+        let _guard = crate::lock_global_state();
+        let start = std::time::Instant::now();
+        unsafe {
+            let json = ffi::spSkeletonJson_create(atlas.inner.as_mut_ptr());
+            if json.is_null() {
                 return Err(SpineError::FailLoadSkeleton(
-                    CStr::from_ptr((*binary_data).error)
-                        .to_str()
-                        .map_err(|e| {
-                            SpineError::FailLoadSkeleton(format!(
-                                "Failed to retrieve skeleton loading error: {:?}",
-                                e
-                            ))
-                        })?
-                        .to_owned(),
+                    "failed to begin json data load".to_owned(),
                 ));
             }
+            let data = ffi::spSkeletonJson_readSkeletonData(json, json_c.as_ptr());
+            if data.is_null() {
+                let error = CStr::from_ptr((*json).error).to_string_lossy().into_owned();
+                ffi::spSkeletonJson_dispose(json);
+                return Err(SpineError::FailLoadSkeleton(error));
+            }
+
+            ffi::spSkeletonJson_dispose(json);
+
+            let skeleton_data = Self {
+                inner: SpineMutPtr::new(data, Some(ffi::spSkeletonData_dispose)),
+                atlas: atlas.inner,
+            };
+            skeleton_data.log_loaded(&"json data", start.elapsed());
+            Ok(skeleton_data)
+        }
+    }
+
+    /// Loads a `SkeletonData` instance from the contents of a binary file.
+    ///
+    /// # Safety
+    /// spine-c does not bounds check while reading, nor the bone, slot and other indices it reads,
+    /// so `binary` must be a complete Spine 3.8 binary export, as written by the editor or
+    /// `format::skeleton::to_binary`. Truncated or corrupt data reads out of bounds. Prefer JSON
+    /// for untrusted sources, as spine-c resolves its references by name and reports missing ones.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadSkeleton` instance, with a text message detailing why loading failed.
+    pub unsafe fn from_binary_bytes(binary: &[u8], atlas: Atlas) -> Result<Self, SpineError> {
+        if binary.is_empty() {
+            return Err(SpineError::FailLoadSkeleton(
+                "Unable to read empty skeleton data".to_owned(),
+            ));
+        }
+        let length = std::os::raw::c_int::try_from(binary.len())
+            .map_err(|_| SpineError::FailLoadSkeleton("Skeleton is too large".to_owned()))?;
+
+        let _guard = crate::lock_global_state();
+        let start = std::time::Instant::now();
+        unsafe {
+            let binary_data = ffi::spSkeletonBinary_create(atlas.inner.as_mut_ptr());
+            if binary_data.is_null() {
+                return Err(SpineError::FailLoadSkeleton(
+                    "failed to begin binary data load".to_owned(),
+                ));
+            }
+            let data = ffi::spSkeletonBinary_readSkeletonData(binary_data, binary.as_ptr(), length);
+            if data.is_null() {
+                let error = if (*binary_data).error.is_null() {
+                    "Failed to read binary data".to_owned()
+                } else {
+                    CStr::from_ptr((*binary_data).error)
+                        .to_string_lossy()
+                        .into_owned()
+                };
+                ffi::spSkeletonBinary_dispose(binary_data);
+                return Err(SpineError::FailLoadSkeleton(error));
+            }
 
             ffi::spSkeletonBinary_dispose(binary_data);
 
-            Ok(Self {
+            let skeleton_data = Self {
                 inner: SpineMutPtr::new(data, Some(ffi::spSkeletonData_dispose)),
                 atlas: atlas.inner,
-            })
+            };
+            skeleton_data.log_loaded(&"binary data", start.elapsed());
+            Ok(skeleton_data)
         }
     }
+
+    fn log_loaded(&self, source: &dyn std::fmt::Display, elapsed: std::time::Duration) {
+        let r = self.inner.as_ref();
+        spine_log!(
+            info,
+            "loaded skeleton {} in {:?}: {} bones, {} slots, {} skins, {} animations, {} events, \
+             {} ik, {} transform and {} path constraints",
+            source,
+            elapsed,
+            r.bonesCount,
+            r.slotsCount,
+            r.skinsCount,
+            r.animationsCount,
+            r.eventsCount,
+            r.ikConstraintsCount,
+            r.transformConstraintsCount,
+            r.pathConstraintsCount
+        );
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::TEST_CASES;
+    use crate::tests::{skeleton_data_from_json, TEST_CASES};
 
     #[test]
     fn load_skeleton() {
@@ -495,7 +1420,8 @@ mod tests {
 
         assert_eq!(2, load);
 
-        let skeleton_data = SkeletonData::from_binary_file(test_case.binary(), atlas).unwrap();
+        let skeleton_data =
+            unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas) }.unwrap();
         let _skeleton = Skeleton::new(&skeleton_data);
         println!("bones = {:?}", skeleton_data.bones());
     }
@@ -515,9 +1441,196 @@ mod tests {
 
             assert_eq!(2, load);
 
-            SkeletonData::from_binary_file(test_case.binary(), atlas).unwrap()
+            unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas) }.unwrap()
         };
         let _skeleton = Skeleton::new(&skeleton_data);
         println!("bones = {:?}", skeleton_data.bones());
     }
+
+    #[test]
+    fn load_skeleton_from_memory() {
+        let test_case = &TEST_CASES[0];
+        let atlas = || Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        let from_file =
+            unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas()) }.unwrap();
+
+        let json = std::fs::read_to_string(test_case.json()).unwrap();
+        let from_json = SkeletonData::from_json_str(&json, atlas()).unwrap();
+        let binary = std::fs::read(test_case.binary()).unwrap();
+        let from_binary = unsafe { SkeletonData::from_binary_bytes(&binary, atlas()) }.unwrap();
+
+        for data in [&from_json, &from_binary] {
+            assert_eq!(from_file.bones().len(), data.bones().len());
+            assert_eq!(from_file.slots().len(), data.slots().len());
+            assert_eq!(from_file.animations().len(), data.animations().len());
+        }
+
+        assert!(SkeletonData::from_json_str("{ \"bones\": [", atlas()).is_err());
+        assert!(unsafe { SkeletonData::from_binary_bytes(&[], atlas()) }.is_err());
+    }
+
+    #[test]
+    fn setup_data() {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        let skeleton_data = SkeletonData::from_json_file(test_case.json(), atlas).unwrap();
+
+        assert_eq!(Some("3.8.55"), skeleton_data.version());
+        assert!(skeleton_data.hash().is_some());
+        assert_eq!(33, skeleton_data.bones().len());
+        assert!(skeleton_data.events().is_empty());
+        assert!(skeleton_data.ik_constraints().is_empty());
+
+        let center = &skeleton_data.bones()[1];
+        assert_eq!("center", center.name());
+        assert_eq!("root", center.parent().unwrap().name());
+        assert!(skeleton_data.bones()[0].parent().is_none());
+
+        let slots = skeleton_data.slots();
+        assert_eq!(31, slots.len());
+        let chin = slots.iter().find(|slot| slot.name() == "chin").unwrap();
+        assert_eq!("chin", chin.bone().name());
+        assert_eq!(Some("chin"), chin.attachment_name());
+
+        let skins = skeleton_data.skins();
+        assert_eq!(1, skins.len());
+        assert_eq!("default", skeleton_data.default_skin().unwrap().name());
+        let attachments = skins[0].attachments();
+        assert!(attachments
+            .iter()
+            .any(|a| a.slot.name() == "chin" && a.name == "chin"));
+
+        let flying = &skeleton_data.animations()[0];
+        assert_eq!(27, flying.bones().len());
+        assert!(flying.bones().iter().any(|bone| bone.name() == "center"));
+
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        assert!(SkeletonData::from_json_file(test_case.binary(), atlas).is_err());
+
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        assert!(skeleton.set_skin_by_name("default"));
+        assert!(!skeleton.set_skin_by_name("missing"));
+    }
+
+    #[test]
+    fn point_world_transform() {
+        let skeleton_data = skeleton_data_from_json(
+            r#"{
+            "skeleton": { "hash": "points", "spine": "3.8.55" },
+            "bones": [ { "name": "root" },
+                       { "name": "gun", "parent": "root", "x": 50, "y": 20, "rotation": 90 } ],
+            "slots": [ { "name": "muzzle", "bone": "gun", "attachment": "muzzle" } ],
+            "skins": [ { "name": "default", "attachments": {
+                "muzzle": { "muzzle": { "type": "point", "x": 10, "rotation": 45 } }
+            } } ]
+        }"#,
+        );
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        let (position, rotation) = skeleton.point_world_transform("muzzle", "muzzle").unwrap();
+        assert!((position[0] - 50.0).abs() < 0.001);
+        assert!((position[1] - 30.0).abs() < 0.001);
+        assert!((rotation - 135.0).abs() < 0.001);
+
+        let slot = skeleton.slot("muzzle").unwrap();
+        let attachment = slot.active_attachment().unwrap();
+        let point = attachment.as_point_attachment().unwrap();
+        assert_eq!("muzzle", point.name());
+        assert!((point.rotation() - 45.0).abs() < 0.001);
+
+        assert!(skeleton
+            .point_world_transform("muzzle", "missing")
+            .is_none());
+        assert!(skeleton
+            .point_world_transform("missing", "muzzle")
+            .is_none());
+    }
+
+    #[test]
+    fn root_transform() {
+        let skeleton_data = skeleton_data_from_json(
+            r#"{
+            "skeleton": { "hash": "root", "spine": "3.8.55" },
+            "bones": [ { "name": "root" }, { "name": "hand", "parent": "root", "x": 50, "y": 20 } ]
+        }"#,
+        );
+        let mut skeleton = Skeleton::new(&skeleton_data);
+
+        skeleton.set_position([100.0, -10.0]);
+        skeleton.set_scale([2.0, 3.0]);
+        skeleton.update_world_transforms();
+        let hand = skeleton.find_bone("hand").unwrap().world_position();
+        assert!((hand[0] - 200.0).abs() < 0.001);
+        assert!((hand[1] - 50.0).abs() < 0.001);
+
+        skeleton.set_flip(true, false);
+        assert!((skeleton.scale()[0] + 2.0).abs() < f32::EPSILON);
+        skeleton.update_world_transforms();
+        let hand = skeleton.find_bone("hand").unwrap().world_position();
+        assert!(hand[0].abs() < 0.001);
+        assert!((hand[1] - 50.0).abs() < 0.001);
+
+        skeleton.set_color(Color::new(0.1, 0.2, 0.3, 0.4));
+        assert_eq!(Color::new(0.1, 0.2, 0.3, 0.4), skeleton.color());
+    }
+
+    /// A tinted slot with a distinct value in every channel, next to a slot without colors.
+    #[test]
+    fn color_channel_order() {
+        let skeleton_data = skeleton_data_from_json(
+            r#"{
+                "skeleton": { "hash": "colors", "spine": "3.8.55" },
+                "bones": [ { "name": "root" } ],
+                "slots": [
+                    { "name": "tinted", "bone": "root", "attachment": "head", "color": "ffe380c0", "dark": "2a5c7f" },
+                    { "name": "plain", "bone": "root", "attachment": "head" }
+                ],
+                "skins": [ { "name": "default", "attachments": {
+                    "tinted": { "head": { "width": 10, "height": 10, "color": "ff00ffff" } },
+                    "plain": { "head": { "width": 10, "height": 10 } }
+                } } ]
+            }"#,
+        );
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        let slot = skeleton.slot("tinted").unwrap();
+        let color = slot.color();
+        assert_eq!(Color::from_rgba8(0xffe3_80c0), color);
+        assert_eq!(
+            Color::new(1.0, 227.0 / 255.0, 128.0 / 255.0, 192.0 / 255.0),
+            color
+        );
+
+        // Only the red, green and blue channels of the dark color are read.
+        assert!(slot.has_two_color_tint());
+        let dark = Color {
+            a: 1.0,
+            ..slot.dark_color().unwrap()
+        };
+        assert_eq!(Color::from_rgba8(0x2a5c_7fff), dark);
+
+        let attachment = slot.active_attachment().unwrap();
+        let region = attachment.as_region_attachment().unwrap();
+        assert_eq!(Color::new(1.0, 0.0, 1.0, 1.0), region.color());
+
+        // The attachment keeps the slot's red, blue and alpha and clears its green.
+        let vertices = slot.world_vertices();
+        assert_eq!(4, vertices.len());
+        assert_eq!(Color::new(1.0, 0.0, color.b, color.a), vertices[0].light);
+        assert_eq!(dark, vertices[0].dark);
+
+        let plain = skeleton.slot("plain").unwrap();
+        assert!(plain.dark_color().is_none());
+        assert_eq!(Color::WHITE, plain.color());
+        assert_eq!(Color::TRANSPARENT, plain.world_vertices()[0].dark);
+
+        skeleton.set_color(Color::new(0.5, 1.0, 1.0, 1.0));
+        let slot = skeleton.slot("tinted").unwrap();
+        assert_eq!(
+            Color::new(0.5, 0.0, color.b, color.a),
+            slot.world_vertices()[0].light
+        );
+    }
 }
@@ -0,0 +1,983 @@
+//! Plain copies of skeleton data, skeleton poses and animation states, for debugging, saving game
+//! state and replicating characters across the network.
+//!
+//! Every type here derives `Serialize` and `Deserialize` with the `serde` feature. Bones, slots,
+//! skins and animations are referenced by name, so a snapshot taken from one `Skeleton` or
+//! `AnimationState` applies to any other of the same `SkeletonData`, including one loaded in
+//! another process.
+
+use crate::{
+    animation::AnimationState,
+    color::Color,
+    enums::{BlendMode, MixBlend, TransformMode},
+    ffi,
+    skeleton::{Skeleton, SkeletonData, SlotIndex},
+    SpineError,
+};
+use std::{
+    convert::{TryFrom, TryInto},
+    ffi::{CStr, CString},
+    os::raw::c_int,
+    ptr,
+};
+
+/// A copy of the setup pose and the names of everything in a `SkeletonData`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkeletonDataSnapshot {
+    pub hash: Option<String>,
+    pub version: Option<String>,
+    pub bones: Vec<BoneDataSnapshot>,
+    pub slots: Vec<SlotDataSnapshot>,
+    pub skins: Vec<SkinSnapshot>,
+    pub ik_constraints: Vec<String>,
+    pub transform_constraints: Vec<String>,
+    pub path_constraints: Vec<String>,
+    pub events: Vec<String>,
+    pub animations: Vec<AnimationSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoneDataSnapshot {
+    pub name: String,
+    /// The parent bone, or `None` for the root bone.
+    pub parent: Option<String>,
+    pub length: f32,
+    pub setup: LocalTransform,
+    pub transform_mode: TransformMode,
+    /// Whether this bone is only active when a skin containing it is set.
+    pub skin_required: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlotDataSnapshot {
+    pub name: String,
+    pub bone: String,
+    pub color: Color,
+    /// Only red, green and blue are used, so the alpha is always 1.
+    pub dark_color: Option<Color>,
+    /// The name of the attachment visible in the setup pose.
+    pub attachment: Option<String>,
+    pub blend_mode: BlendMode,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkinSnapshot {
+    pub name: String,
+    /// The bones that are only active while this skin is set.
+    pub bones: Vec<String>,
+    /// Every attachment of this skin as `(slot, name)`, ordered by slot and then by name.
+    pub attachments: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationSnapshot {
+    pub name: String,
+    pub duration: f32,
+}
+
+/// A bone transform relative to its parent, with rotation and shear in degrees.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalTransform {
+    pub x: f32,
+    pub y: f32,
+    pub rotation: f32,
+    pub scale_x: f32,
+    pub scale_y: f32,
+    pub shear_x: f32,
+    pub shear_y: f32,
+}
+
+/// A bone's world transform: the 2x2 matrix `[a b; c d]` followed by the world position.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorldTransform {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// A copy of the pose of a `Skeleton`, taken with `Skeleton::pose_snapshot`.
+///
+/// Constraint mixes and mesh deformation are not part of the pose: they are set by the animations
+/// applied before the next `Skeleton::update_world_transforms`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoseSnapshot {
+    /// The active skin, or `None` if only the default skin is used.
+    pub skin: Option<String>,
+    pub position: [f32; 2],
+    pub scale: [f32; 2],
+    pub color: Color,
+    pub bones: Vec<BonePose>,
+    pub slots: Vec<SlotPose>,
+    /// Slot names in the order they are drawn.
+    pub draw_order: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BonePose {
+    pub name: String,
+    pub local: LocalTransform,
+    /// The local transform constraints last applied, which the world transform was computed from.
+    pub applied: LocalTransform,
+    pub world: WorldTransform,
+}
+
+impl BonePose {
+    fn read(name: &str, b: &ffi::spBone) -> Self {
+        Self {
+            name: name.to_owned(),
+            local: LocalTransform {
+                x: b.x,
+                y: b.y,
+                rotation: b.rotation,
+                scale_x: b.scaleX,
+                scale_y: b.scaleY,
+                shear_x: b.shearX,
+                shear_y: b.shearY,
+            },
+            applied: LocalTransform {
+                x: b.ax,
+                y: b.ay,
+                rotation: b.arotation,
+                scale_x: b.ascaleX,
+                scale_y: b.ascaleY,
+                shear_x: b.ashearX,
+                shear_y: b.ashearY,
+            },
+            world: WorldTransform {
+                a: b.a,
+                b: b.b,
+                c: b.c,
+                d: b.d,
+                x: b.worldX,
+                y: b.worldY,
+            },
+        }
+    }
+
+    fn write(&self, b: &mut ffi::spBone) {
+        let local = self.local;
+        (b.x, b.y, b.rotation) = (local.x, local.y, local.rotation);
+        (b.scaleX, b.scaleY) = (local.scale_x, local.scale_y);
+        (b.shearX, b.shearY) = (local.shear_x, local.shear_y);
+        let applied = self.applied;
+        (b.ax, b.ay, b.arotation) = (applied.x, applied.y, applied.rotation);
+        (b.ascaleX, b.ascaleY) = (applied.scale_x, applied.scale_y);
+        (b.ashearX, b.ashearY) = (applied.shear_x, applied.shear_y);
+        b.appliedValid = 1;
+        let world = self.world;
+        (b.a, b.b, b.c, b.d) = (world.a, world.b, world.c, world.d);
+        (b.worldX, b.worldY) = (world.x, world.y);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlotPose {
+    pub name: String,
+    pub color: Color,
+    /// Only red, green and blue are used, so the alpha is always 1.
+    pub dark_color: Option<Color>,
+    /// The name the skin stores the visible attachment under.
+    pub attachment: Option<String>,
+}
+
+/// A copy of every track of an `AnimationState`, taken with `AnimationState::save`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationStateSnapshot {
+    pub time_scale: f32,
+    /// One entry per track index, `None` for a track with nothing playing.
+    pub tracks: Vec<Option<TrackSnapshot>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrackSnapshot {
+    pub current: TrackEntrySnapshot,
+    /// The entries `current` is still mixing from, newest first.
+    pub mixing_from: Vec<TrackEntrySnapshot>,
+    /// The entries queued after `current`, in the order they play.
+    pub queued: Vec<TrackEntrySnapshot>,
+}
+
+/// The playback state of one track entry. Times are in seconds.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// The unsafe code only copies to and from spine-c; no field carries an invariant.
+#[allow(clippy::unsafe_derive_deserialize)]
+pub struct TrackEntrySnapshot {
+    /// The animation name, or `None` for the empty animation spine-c mixes in and out of.
+    pub animation: Option<String>,
+    pub looping: bool,
+    pub hold_previous: bool,
+    pub event_threshold: f32,
+    pub attachment_threshold: f32,
+    pub draw_order_threshold: f32,
+    pub animation_start: f32,
+    pub animation_end: f32,
+    pub animation_last: f32,
+    pub next_animation_last: f32,
+    pub delay: f32,
+    pub track_time: f32,
+    pub track_last: f32,
+    pub next_track_last: f32,
+    pub track_end: f32,
+    pub time_scale: f32,
+    pub alpha: f32,
+    pub mix_time: f32,
+    pub mix_duration: f32,
+    pub interrupt_alpha: f32,
+    pub total_alpha: f32,
+    pub mix_blend: MixBlend,
+    /// The direction each rotate timeline was mixing in, so a restored mix keeps turning the same
+    /// way instead of taking the shortest path.
+    pub timelines_rotation: Vec<f32>,
+}
+impl TrackEntrySnapshot {
+    fn read(e: &ffi::spTrackEntry, data: &ffi::spSkeletonData) -> Self {
+        let count = data.animationsCount.try_into().unwrap();
+        let animations = unsafe { std::slice::from_raw_parts(data.animations, count) };
+        let animation = animations.contains(&e.animation).then(|| {
+            unsafe { CStr::from_ptr((*e.animation).name) }
+                .to_str()
+                .unwrap()
+                .to_owned()
+        });
+        let timelines_rotation = if e.timelinesRotationCount == 0 {
+            Vec::new()
+        } else {
+            let count = e.timelinesRotationCount.try_into().unwrap();
+            unsafe { std::slice::from_raw_parts(e.timelinesRotation, count) }.to_vec()
+        };
+
+        Self {
+            animation,
+            looping: e.loop_ != 0,
+            hold_previous: e.holdPrevious != 0,
+            event_threshold: e.eventThreshold,
+            attachment_threshold: e.attachmentThreshold,
+            draw_order_threshold: e.drawOrderThreshold,
+            animation_start: e.animationStart,
+            animation_end: e.animationEnd,
+            animation_last: e.animationLast,
+            next_animation_last: e.nextAnimationLast,
+            delay: e.delay,
+            track_time: e.trackTime,
+            track_last: e.trackLast,
+            next_track_last: e.nextTrackLast,
+            track_end: e.trackEnd,
+            time_scale: e.timeScale,
+            alpha: e.alpha,
+            mix_time: e.mixTime,
+            mix_duration: e.mixDuration,
+            interrupt_alpha: e.interruptAlpha,
+            total_alpha: e.totalAlpha,
+            mix_blend: e.mixBlend.into(),
+            timelines_rotation,
+        }
+    }
+
+    fn write(&self, e: &mut ffi::spTrackEntry) {
+        e.loop_ = c_int::from(self.looping);
+        e.holdPrevious = c_int::from(self.hold_previous);
+        e.eventThreshold = self.event_threshold;
+        e.attachmentThreshold = self.attachment_threshold;
+        e.drawOrderThreshold = self.draw_order_threshold;
+        e.animationStart = self.animation_start;
+        e.animationEnd = self.animation_end;
+        e.animationLast = self.animation_last;
+        e.nextAnimationLast = self.next_animation_last;
+        e.delay = self.delay;
+        e.trackTime = self.track_time;
+        e.trackLast = self.track_last;
+        e.nextTrackLast = self.next_track_last;
+        e.trackEnd = self.track_end;
+        e.timeScale = self.time_scale;
+        e.alpha = self.alpha;
+        e.mixTime = self.mix_time;
+        e.mixDuration = self.mix_duration;
+        e.interruptAlpha = self.interrupt_alpha;
+        e.totalAlpha = self.total_alpha;
+        e.mixBlend = self.mix_blend as ffi::spMixBlend;
+
+        // spine-c frees this array itself, so it has to come from spine-c's allocator.
+        unsafe { ffi::_spFree(e.timelinesRotation.cast()) };
+        e.timelinesRotation = ptr::null_mut();
+        e.timelinesRotationCount = 0;
+        if !self.timelines_rotation.is_empty() {
+            let count = self.timelines_rotation.len();
+            unsafe {
+                e.timelinesRotation = ffi::_spCalloc(
+                    count,
+                    std::mem::size_of::<f32>(),
+                    concat!(file!(), "\0").as_ptr().cast(),
+                    line!().try_into().unwrap(),
+                )
+                .cast();
+                ptr::copy_nonoverlapping(
+                    self.timelines_rotation.as_ptr(),
+                    e.timelinesRotation,
+                    count,
+                );
+            }
+            e.timelinesRotationCount = count.try_into().unwrap();
+        }
+    }
+}
+
+impl SkeletonData {
+    /// Copies the setup pose and the names of the bones, slots, skins, constraints, events and
+    /// animations of this data.
+    pub fn to_snapshot(&self) -> SkeletonDataSnapshot {
+        SkeletonDataSnapshot {
+            hash: self.hash().map(String::from),
+            version: self.version().map(String::from),
+            bones: self
+                .bones()
+                .iter()
+                .map(|bone| {
+                    let [x, y] = bone.position();
+                    let [scale_x, scale_y] = bone.scale();
+                    let [shear_x, shear_y] = bone.shear();
+                    BoneDataSnapshot {
+                        name: bone.name().to_owned(),
+                        parent: bone.parent().map(|parent| parent.name().to_owned()),
+                        length: bone.length(),
+                        setup: LocalTransform {
+                            x,
+                            y,
+                            rotation: bone.rotation(),
+                            scale_x,
+                            scale_y,
+                            shear_x,
+                            shear_y,
+                        },
+                        transform_mode: bone.inner.transformMode.into(),
+                        skin_required: bone.skin_required(),
+                    }
+                })
+                .collect(),
+            slots: self
+                .slots()
+                .iter()
+                .map(|slot| SlotDataSnapshot {
+                    name: slot.name().to_owned(),
+                    bone: slot.bone().name().to_owned(),
+                    color: slot.color(),
+                    dark_color: slot.dark_color().map(opaque),
+                    attachment: slot.attachment_name().map(String::from),
+                    blend_mode: slot.blend_mode(),
+                })
+                .collect(),
+            skins: self
+                .skins()
+                .iter()
+                .map(|skin| SkinSnapshot {
+                    name: skin.name().to_owned(),
+                    bones: skin
+                        .bones()
+                        .iter()
+                        .map(|bone| bone.name().to_owned())
+                        .collect(),
+                    attachments: skin
+                        .attachments()
+                        .iter()
+                        .map(|entry| (entry.slot.name().to_owned(), entry.name.to_owned()))
+                        .collect(),
+                })
+                .collect(),
+            ik_constraints: self
+                .ik_constraints()
+                .iter()
+                .map(|c| c.name().to_owned())
+                .collect(),
+            transform_constraints: self
+                .transform_constraints()
+                .iter()
+                .map(|c| c.name().to_owned())
+                .collect(),
+            path_constraints: self
+                .path_constraints()
+                .iter()
+                .map(|c| c.name().to_owned())
+                .collect(),
+            events: self.events().iter().map(|e| e.name().to_owned()).collect(),
+            animations: self
+                .animations()
+                .iter()
+                .map(|animation| AnimationSnapshot {
+                    name: animation.name().to_owned(),
+                    duration: animation.duration(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Skeleton {
+    /// Copies the current pose of this skeleton: the local, applied and world transform of every
+    /// bone, the color and attachment of every slot, the draw order and the active skin.
+    pub fn pose_snapshot(&self) -> PoseSnapshot {
+        let r = self.inner.as_ref();
+        let skin = unsafe { r.skin.as_ref() }.map(|skin| {
+            unsafe { CStr::from_ptr(skin.name) }
+                .to_str()
+                .unwrap()
+                .to_owned()
+        });
+
+        PoseSnapshot {
+            skin,
+            position: self.position(),
+            scale: self.scale(),
+            color: self.color(),
+            bones: self
+                .bone()
+                .iter()
+                .map(|bone| BonePose::read(bone.name(), bone.as_ref()))
+                .collect(),
+            slots: self
+                .slots()
+                .iter()
+                .map(|slot| SlotPose {
+                    name: slot.name().to_owned(),
+                    color: slot.color(),
+                    dark_color: slot.dark_color().map(opaque),
+                    attachment: self.attachment_key(slot.index(), slot.as_ref().attachment),
+                })
+                .collect(),
+            draw_order: self
+                .draw_slots()
+                .iter()
+                .map(|slot| slot.name().to_owned())
+                .collect(),
+        }
+    }
+
+    /// Restores a pose taken with `Skeleton::pose_snapshot`, from this or another skeleton of the
+    /// same `SkeletonData`. World transforms are restored too, so the skeleton can be drawn without
+    /// calling `Skeleton::update_world_transforms` first.
+    ///
+    /// Bones and slots the snapshot leaves out keep their current pose.
+    ///
+    /// # Errors
+    /// Returns `SpineError::InvalidSnapshot` if the snapshot names a skin, bone, slot or attachment
+    /// this skeleton does not have, or if its draw order does not list every slot exactly once. The
+    /// skeleton is left untouched in that case.
+    pub fn apply_pose_snapshot(&mut self, snapshot: &PoseSnapshot) -> Result<(), SpineError> {
+        let invalid = |what: &str, name: &str| {
+            SpineError::InvalidSnapshot(format!("no {what} named {name:?} in this skeleton"))
+        };
+
+        // Resolve every name before touching the skeleton, so a bad snapshot changes nothing.
+        let skin = match &snapshot.skin {
+            Some(name) => {
+                let c_name = CString::new(name.as_str()).unwrap();
+                let skin =
+                    unsafe { ffi::spSkeletonData_findSkin(self.data.as_ptr(), c_name.as_ptr()) };
+                if skin.is_null() {
+                    return Err(invalid("skin", name));
+                }
+                skin
+            }
+            None => ptr::null_mut(),
+        };
+        let bones = snapshot
+            .bones
+            .iter()
+            .map(|pose| {
+                self.find_bone(&pose.name)
+                    .map(|bone| (bone.inner, pose))
+                    .ok_or_else(|| invalid("bone", &pose.name))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let slots = snapshot
+            .slots
+            .iter()
+            .map(|pose| {
+                self.slot(&pose.name)
+                    .map(|slot| (slot.inner, slot.index(), pose))
+                    .ok_or_else(|| invalid("slot", &pose.name))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let draw_order = snapshot
+            .draw_order
+            .iter()
+            .map(|name| {
+                self.slot(name)
+                    .map(|slot| slot.inner)
+                    .ok_or_else(|| invalid("slot", name))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let slots_count: usize = self.inner.as_ref().slotsCount.try_into().unwrap();
+        let mut listed = draw_order.clone();
+        listed.sort_unstable();
+        listed.dedup();
+        if draw_order.len() != slots_count || listed.len() != slots_count {
+            return Err(SpineError::InvalidSnapshot(format!(
+                "the draw order lists {} slots out of {slots_count}",
+                listed.len()
+            )));
+        }
+
+        let default_skin = unsafe { (*self.data.as_ptr()).defaultSkin };
+        let mut attachments = Vec::with_capacity(slots.len());
+        for (slot, index, pose) in slots {
+            let attachment = match &pose.attachment {
+                Some(name) => {
+                    let c_name = CString::new(name.as_str()).unwrap();
+                    [skin, default_skin]
+                        .iter()
+                        .copied()
+                        .filter(|skin| !skin.is_null())
+                        .map(|skin| unsafe {
+                            ffi::spSkin_getAttachment(skin, index.0, c_name.as_ptr())
+                        })
+                        .find(|attachment| !attachment.is_null())
+                        .ok_or_else(|| invalid("attachment", name))?
+                }
+                None => ptr::null_mut(),
+            };
+            attachments.push((slot, pose, attachment));
+        }
+
+        unsafe { ffi::spSkeleton_setSkin(self.inner.as_mut_ptr(), skin) };
+        let r = self.inner.as_mut();
+        r.x = snapshot.position[0];
+        r.y = snapshot.position[1];
+        r.scaleX = snapshot.scale[0];
+        r.scaleY = snapshot.scale[1];
+        r.color = snapshot.color.into();
+        for (n, slot) in draw_order.into_iter().enumerate() {
+            unsafe { *r.drawOrder.add(n) = slot };
+        }
+
+        for (bone, pose) in bones {
+            pose.write(unsafe { &mut *bone });
+        }
+
+        for (slot, pose, attachment) in attachments {
+            let s = unsafe { &mut *slot };
+            s.color = pose.color.into();
+            if let (Some(dark), Some(color)) = (unsafe { s.darkColor.as_mut() }, pose.dark_color) {
+                *dark = color.into();
+            }
+            if s.attachment != attachment {
+                unsafe { ffi::spSlot_setAttachment(slot, attachment) };
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The name the active or default skin stores `attachment` under for the slot at `index`.
+    fn attachment_key(
+        &self,
+        index: SlotIndex,
+        attachment: *mut ffi::spAttachment,
+    ) -> Option<String> {
+        let attachment = unsafe { attachment.as_ref() }?;
+
+        // The attachment's own name is almost always its key, which saves walking the skins.
+        let name = unsafe { CStr::from_ptr(attachment.name) };
+        let found = unsafe {
+            ffi::spSkeleton_getAttachmentForSlotIndex(self.inner.as_ptr(), index.0, name.as_ptr())
+        };
+        if ptr::eq(found, attachment) {
+            return Some(name.to_str().unwrap().to_owned());
+        }
+
+        let r = self.inner.as_ref();
+        let data = unsafe { &*r.data };
+        [r.skin, data.defaultSkin]
+            .iter()
+            .copied()
+            .filter_map(|skin| unsafe { skin.as_ref() })
+            .find_map(|skin| {
+                let mut entry = unsafe { ffi::spSkin_getAttachments(skin) };
+                while let Some(e) = unsafe { entry.as_ref() } {
+                    if e.slotIndex == index.0 && ptr::eq(e.attachment, attachment) {
+                        return Some(
+                            unsafe { CStr::from_ptr(e.name) }
+                                .to_str()
+                                .unwrap()
+                                .to_owned(),
+                        );
+                    }
+                    entry = e.next;
+                }
+                None
+            })
+            .or_else(|| Some(name.to_str().unwrap().to_owned()))
+    }
+}
+
+impl AnimationState {
+    /// Copies the state of every track: the animations playing, mixing out and queued, with their
+    /// times, mix progress, loop flags, time scales and alphas.
+    pub fn save(&self) -> AnimationStateSnapshot {
+        let r = self.inner.as_ref();
+        let data = unsafe { &*self.parent.as_ref().skeletonData };
+        let chain = |mut entry: *mut ffi::spTrackEntry,
+                     link: fn(&ffi::spTrackEntry) -> *mut ffi::spTrackEntry| {
+            let mut entries = Vec::new();
+            while let Some(e) = unsafe { entry.as_ref() } {
+                entries.push(TrackEntrySnapshot::read(e, data));
+                entry = link(e);
+            }
+            entries
+        };
+
+        AnimationStateSnapshot {
+            time_scale: r.timeScale,
+            tracks: (0..r.tracksCount.try_into().unwrap())
+                .map(|n| {
+                    let current = unsafe { (*r.tracks.add(n)).as_ref() }?;
+                    Some(TrackSnapshot {
+                        current: TrackEntrySnapshot::read(current, data),
+                        mixing_from: chain(current.mixingFrom, |e| e.mixingFrom),
+                        queued: chain(current.next, |e| e.next),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Replaces every track with the ones saved in `snapshot`, from this or another animation state
+    /// of the same `SkeletonData`. Applying both states to skeletons in the same pose then gives
+    /// bit-identical results, frame after frame.
+    ///
+    /// Track entry listeners see the replaced entries end and the restored ones start.
+    ///
+    /// # Errors
+    /// Returns `SpineError::InvalidSnapshot` if the snapshot names an animation the skeleton data
+    /// does not have, or has timeline rotations that are neither empty nor two per timeline of
+    /// their animation. The animation state is left untouched in that case.
+    pub fn restore(&mut self, snapshot: &AnimationStateSnapshot) -> Result<(), SpineError> {
+        let data = unsafe { &*self.parent.as_ref().skeletonData };
+        let find = |entry: &TrackEntrySnapshot| {
+            let (animation, timelines) = match &entry.animation {
+                Some(name) => {
+                    let c_name = CString::new(name.as_str()).unwrap();
+                    let animation =
+                        unsafe { ffi::spSkeletonData_findAnimation(data, c_name.as_ptr()) };
+                    if animation.is_null() {
+                        return Err(SpineError::InvalidSnapshot(format!(
+                            "no animation named {name:?} in this skeleton data"
+                        )));
+                    }
+                    (animation, unsafe { (*animation).timelinesCount })
+                }
+                None => (ptr::null_mut(), 0),
+            };
+            // spine-c writes two rotations per timeline into this array unless it is empty.
+            let rotations = entry.timelines_rotation.len();
+            if rotations != 0 && rotations != usize::try_from(timelines).unwrap() * 2 {
+                return Err(SpineError::InvalidSnapshot(format!(
+                    "{rotations} timeline rotations for an animation with {timelines} timelines"
+                )));
+            }
+            Ok(animation)
+        };
+        // Resolve every name before clearing the tracks, so a bad snapshot changes nothing.
+        let tracks = snapshot
+            .tracks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, track)| Some((c_int::try_from(index).unwrap(), track.as_ref()?)))
+            .map(|(index, track)| {
+                let mixing = track.mixing_from.iter().rev().chain([&track.current]);
+                let mixing = mixing
+                    .map(|entry| Ok((find(entry)?, entry)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let queued = track
+                    .queued
+                    .iter()
+                    .map(|entry| Ok((find(entry)?, entry)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((index, mixing, queued))
+            })
+            .collect::<Result<Vec<_>, SpineError>>()?;
+
+        let state = self.inner.as_mut_ptr();
+        unsafe { ffi::spAnimationState_clearTracks(state) };
+        self.inner.as_mut().timeScale = snapshot.time_scale;
+
+        for (index, mixing, queued) in tracks {
+            let mut entries: Vec<(*mut ffi::spTrackEntry, &TrackEntrySnapshot)> =
+                Vec::with_capacity(mixing.len() + queued.len());
+            // Setting each animation in turn rebuilds the chain of entries mixing out, oldest first.
+            for (animation, entry) in mixing {
+                if let Some(&(previous, _)) = entries.last() {
+                    // spine-c replaces an entry that was never applied instead of mixing from it.
+                    unsafe { (*previous).nextTrackLast = 0.0 };
+                }
+                let created = unsafe {
+                    if animation.is_null() {
+                        ffi::spAnimationState_setEmptyAnimation(state, index, 0.0)
+                    } else {
+                        ffi::spAnimationState_setAnimation(
+                            state,
+                            index,
+                            animation,
+                            c_int::from(entry.looping),
+                        )
+                    }
+                };
+                entries.push((created, entry));
+            }
+            for (animation, entry) in queued {
+                let created = unsafe {
+                    if animation.is_null() {
+                        ffi::spAnimationState_addEmptyAnimation(state, index, 0.0, entry.delay)
+                    } else {
+                        ffi::spAnimationState_addAnimation(
+                            state,
+                            index,
+                            animation,
+                            c_int::from(entry.looping),
+                            entry.delay,
+                        )
+                    }
+                };
+                entries.push((created, entry));
+            }
+
+            for (created, entry) in entries {
+                entry.write(unsafe { &mut *created });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Dark colors only use red, green and blue. spine-c's JSON loader leaves the alpha at -1 where
+/// the binary loader sets 1, so snapshots always record 1.
+fn opaque(color: Color) -> Color {
+    Color { a: 1.0, ..color }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        animation::{AnimationState, AnimationStateData, TrackIndex},
+        atlas::Atlas,
+        format::skeleton::tests::FEATURES,
+        tests::{skeleton_data_from_json, TEST_CASES},
+    };
+
+    fn dragon() -> SkeletonData {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas) }.unwrap()
+    }
+
+    #[test]
+    fn data_snapshot() {
+        let data = skeleton_data_from_json(FEATURES);
+        let snapshot = data.to_snapshot();
+
+        assert_eq!(Some("features"), snapshot.hash.as_deref());
+        let arm = &snapshot.bones[1];
+        assert_eq!(Some("root"), arm.parent.as_deref());
+        assert_eq!(TransformMode::NoScale, arm.transform_mode);
+        assert!(arm.skin_required);
+        let body = &snapshot.slots[0];
+        assert_eq!(Some("body"), body.attachment.as_deref());
+        assert_eq!(BlendMode::Additive, body.blend_mode);
+        assert_eq!(Some(1.0), body.dark_color.map(|color| color.a));
+        let armored = snapshot.skins.iter().find(|s| s.name == "armored").unwrap();
+        assert_eq!(vec!["arm".to_owned()], armored.bones);
+        assert_eq!(vec!["reach".to_owned()], snapshot.ik_constraints);
+        assert_eq!(vec!["step".to_owned(), "shot".to_owned()], snapshot.events);
+    }
+
+    #[test]
+    fn pose_round_trip() {
+        let data = dragon();
+        let state_data = AnimationStateData::new(&data);
+        let mut state = AnimationState::new(&state_data);
+        let mut source = Skeleton::new(&data);
+        state.set_by_name("flying", TrackIndex::zero(), true);
+        state.update(0.37);
+        state.apply(&mut source);
+        source.set_position([12.0, -3.0]);
+        source.update_world_transforms();
+
+        let snapshot = source.pose_snapshot();
+        let mut target = Skeleton::new(&data);
+        target.apply_pose_snapshot(&snapshot).unwrap();
+        assert_eq!(snapshot, target.pose_snapshot());
+
+        target.update_world_transforms();
+        assert_eq!(snapshot, target.pose_snapshot());
+    }
+
+    #[test]
+    fn pose_keeps_skin_and_attachment_keys() {
+        let data = skeleton_data_from_json(FEATURES);
+        let mut source = Skeleton::new(&data);
+        assert!(source.set_skin_by_name("armored"));
+        source.reset_slots();
+        let order = unsafe { std::slice::from_raw_parts_mut(source.inner.as_mut().drawOrder, 6) };
+        order.reverse();
+
+        let snapshot = source.pose_snapshot();
+        assert_eq!(Some("armored"), snapshot.skin.as_deref());
+        // The body attachment is named "torso" but stored under "body".
+        assert_eq!(Some("body"), snapshot.slots[0].attachment.as_deref());
+        assert_eq!("hitbox", snapshot.draw_order[0]);
+
+        let mut target = Skeleton::new(&data);
+        target.apply_pose_snapshot(&snapshot).unwrap();
+        assert_eq!(snapshot, target.pose_snapshot());
+    }
+
+    #[test]
+    fn invalid_pose_is_rejected() {
+        let data = dragon();
+        let mut skeleton = Skeleton::new(&data);
+        skeleton.update_world_transforms();
+        let before = skeleton.pose_snapshot();
+
+        let mut snapshot = before.clone();
+        snapshot.bones[0].local.x = 100.0;
+        snapshot.slots[0].attachment = Some("missing".to_owned());
+        assert!(skeleton.apply_pose_snapshot(&snapshot).is_err());
+
+        let mut snapshot = before.clone();
+        snapshot.draw_order.pop();
+        assert!(skeleton.apply_pose_snapshot(&snapshot).is_err());
+        assert_eq!(before, skeleton.pose_snapshot());
+    }
+
+    /// Two bones with rotating animations, so mixes exercise spine-c's rotation direction state.
+    const MIXING: &str = r#"{
+        "skeleton": { "hash": "mixing", "spine": "3.8.99" },
+        "bones": [ { "name": "root" }, { "name": "arm", "parent": "root", "length": 20 } ],
+        "animations": {
+            "wave": { "bones": { "arm": { "rotate": [ { "angle": 0 }, { "time": 0.5, "angle": 170 }, { "time": 1, "angle": -170 } ] } } },
+            "turn": { "bones": {
+                "arm": { "rotate": [ { "angle": 90 }, { "time": 1, "angle": -90 } ], "translate": [ { "x": 0 }, { "time": 1, "x": 30 } ] },
+                "root": { "scale": [ { "x": 1 }, { "time": 0.7, "x": 2, "y": 0.5 } ] }
+            } }
+        }
+    }"#;
+
+    fn step(state: &mut AnimationState, skeleton: &mut Skeleton) {
+        state.update(1.0 / 30.0);
+        state.apply(skeleton);
+        skeleton.update_world_transforms();
+    }
+
+    #[test]
+    fn animation_state_restores_exactly() {
+        let data = skeleton_data_from_json(MIXING);
+        let mut state_data = AnimationStateData::new(&data);
+        state_data.set_default_mix(0.4);
+        let mut state = AnimationState::new(&state_data);
+        let mut skeleton = Skeleton::new(&data);
+
+        state.set_by_name("wave", TrackIndex::zero(), true);
+        for _ in 0..10 {
+            step(&mut state, &mut skeleton);
+        }
+        state.set_by_name("turn", TrackIndex::zero(), false);
+        for _ in 0..3 {
+            step(&mut state, &mut skeleton);
+        }
+        // Interrupt the mix, so two entries are mixing out, and queue the wave to follow.
+        state.set_by_name("wave", TrackIndex::zero(), true);
+        state.add_by_name("turn", TrackIndex::zero(), false, 0.5);
+        step(&mut state, &mut skeleton);
+
+        let saved = state.save();
+        let track = saved.tracks[0].as_ref().unwrap();
+        assert_eq!(2, track.mixing_from.len());
+        assert_eq!(Some("turn"), track.queued[0].animation.as_deref());
+        assert!(!track.mixing_from[0].timelines_rotation.is_empty());
+
+        // Restore into a state that is playing something else.
+        let mut restored = AnimationState::new(&state_data);
+        restored.set_by_name("turn", TrackIndex::zero(), true);
+        restored.restore(&saved).unwrap();
+        assert_eq!(saved, restored.save());
+        let mut replica = Skeleton::new(&data);
+        replica
+            .apply_pose_snapshot(&skeleton.pose_snapshot())
+            .unwrap();
+
+        for _ in 0..60 {
+            step(&mut state, &mut skeleton);
+            step(&mut restored, &mut replica);
+            assert_eq!(skeleton.pose_snapshot(), replica.pose_snapshot());
+        }
+        assert_eq!(state.save(), restored.save());
+    }
+
+    #[test]
+    fn animation_state_rejects_unknown_animations() {
+        let data = skeleton_data_from_json(MIXING);
+        let state_data = AnimationStateData::new(&data);
+        let mut state = AnimationState::new(&state_data);
+        state.set_by_name("wave", TrackIndex::zero(), true);
+        let before = state.save();
+
+        let mut snapshot = before.clone();
+        snapshot.tracks[0].as_mut().unwrap().current.animation = Some("missing".to_owned());
+        assert!(state.restore(&snapshot).is_err());
+        assert_eq!(before, state.save());
+    }
+
+    #[test]
+    fn animation_state_rejects_truncated_rotations() {
+        let data = skeleton_data_from_json(MIXING);
+        let mut state_data = AnimationStateData::new(&data);
+        state_data.set_default_mix(0.4);
+        let mut state = AnimationState::new(&state_data);
+        let mut skeleton = Skeleton::new(&data);
+        state.set_by_name("wave", TrackIndex::zero(), true);
+        step(&mut state, &mut skeleton);
+        state.set_by_name("turn", TrackIndex::zero(), false);
+        step(&mut state, &mut skeleton);
+        let before = state.save();
+
+        let mut snapshot = before.clone();
+        let track = snapshot.tracks[0].as_mut().unwrap();
+        track.mixing_from[0].timelines_rotation.truncate(1);
+        assert!(matches!(
+            state.restore(&snapshot),
+            Err(SpineError::InvalidSnapshot(_))
+        ));
+        assert_eq!(before, state.save());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn pose_serializes() {
+        let data = dragon();
+        let mut skeleton = Skeleton::new(&data);
+        skeleton.update_world_transforms();
+        let snapshot = skeleton.pose_snapshot();
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(
+            snapshot,
+            serde_json::from_str::<PoseSnapshot>(&json).unwrap()
+        );
+    }
+}
@@ -25,7 +25,7 @@ fn bindgen_test_layout_spEventData() {
         concat!("Alignment of ", stringify!(spEventData))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventData>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spEventData, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -35,7 +35,7 @@ fn bindgen_test_layout_spEventData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventData>())).intValue as *const _ as usize },
+        ::std::mem::offset_of!(spEventData, intValue),
         8usize,
         concat!(
             "Offset of field: ",
@@ -45,7 +45,7 @@ fn bindgen_test_layout_spEventData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventData>())).floatValue as *const _ as usize },
+        ::std::mem::offset_of!(spEventData, floatValue),
         12usize,
         concat!(
             "Offset of field: ",
@@ -55,7 +55,7 @@ fn bindgen_test_layout_spEventData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventData>())).stringValue as *const _ as usize },
+        ::std::mem::offset_of!(spEventData, stringValue),
         16usize,
         concat!(
             "Offset of field: ",
@@ -65,7 +65,7 @@ fn bindgen_test_layout_spEventData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventData>())).audioPath as *const _ as usize },
+        ::std::mem::offset_of!(spEventData, audioPath),
         24usize,
         concat!(
             "Offset of field: ",
@@ -75,7 +75,7 @@ fn bindgen_test_layout_spEventData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventData>())).volume as *const _ as usize },
+        ::std::mem::offset_of!(spEventData, volume),
         32usize,
         concat!(
             "Offset of field: ",
@@ -85,7 +85,7 @@ fn bindgen_test_layout_spEventData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventData>())).balance as *const _ as usize },
+        ::std::mem::offset_of!(spEventData, balance),
         36usize,
         concat!(
             "Offset of field: ",
@@ -125,7 +125,7 @@ fn bindgen_test_layout_spEvent() {
         concat!("Alignment of ", stringify!(spEvent))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEvent>())).data as *const _ as usize },
+        ::std::mem::offset_of!(spEvent, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -135,7 +135,7 @@ fn bindgen_test_layout_spEvent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEvent>())).time as *const _ as usize },
+        ::std::mem::offset_of!(spEvent, time),
         8usize,
         concat!(
             "Offset of field: ",
@@ -145,7 +145,7 @@ fn bindgen_test_layout_spEvent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEvent>())).intValue as *const _ as usize },
+        ::std::mem::offset_of!(spEvent, intValue),
         12usize,
         concat!(
             "Offset of field: ",
@@ -155,7 +155,7 @@ fn bindgen_test_layout_spEvent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEvent>())).floatValue as *const _ as usize },
+        ::std::mem::offset_of!(spEvent, floatValue),
         16usize,
         concat!(
             "Offset of field: ",
@@ -165,7 +165,7 @@ fn bindgen_test_layout_spEvent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEvent>())).stringValue as *const _ as usize },
+        ::std::mem::offset_of!(spEvent, stringValue),
         24usize,
         concat!(
             "Offset of field: ",
@@ -175,7 +175,7 @@ fn bindgen_test_layout_spEvent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEvent>())).volume as *const _ as usize },
+        ::std::mem::offset_of!(spEvent, volume),
         32usize,
         concat!(
             "Offset of field: ",
@@ -185,7 +185,7 @@ fn bindgen_test_layout_spEvent() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEvent>())).balance as *const _ as usize },
+        ::std::mem::offset_of!(spEvent, balance),
         36usize,
         concat!(
             "Offset of field: ",
@@ -231,7 +231,7 @@ fn bindgen_test_layout_spAttachment() {
         concat!("Alignment of ", stringify!(spAttachment))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachment>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spAttachment, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -241,7 +241,7 @@ fn bindgen_test_layout_spAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachment>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(spAttachment, type_),
         8usize,
         concat!(
             "Offset of field: ",
@@ -251,7 +251,7 @@ fn bindgen_test_layout_spAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachment>())).vtable as *const _ as usize },
+        ::std::mem::offset_of!(spAttachment, vtable),
         16usize,
         concat!(
             "Offset of field: ",
@@ -261,7 +261,7 @@ fn bindgen_test_layout_spAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachment>())).refCount as *const _ as usize },
+        ::std::mem::offset_of!(spAttachment, refCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -271,7 +271,7 @@ fn bindgen_test_layout_spAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachment>())).attachmentLoader as *const _ as usize },
+        ::std::mem::offset_of!(spAttachment, attachmentLoader),
         32usize,
         concat!(
             "Offset of field: ",
@@ -308,7 +308,7 @@ fn bindgen_test_layout_spAnimation() {
         concat!("Alignment of ", stringify!(spAnimation))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimation>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spAnimation, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -318,7 +318,7 @@ fn bindgen_test_layout_spAnimation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimation>())).duration as *const _ as usize },
+        ::std::mem::offset_of!(spAnimation, duration),
         8usize,
         concat!(
             "Offset of field: ",
@@ -328,7 +328,7 @@ fn bindgen_test_layout_spAnimation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimation>())).timelinesCount as *const _ as usize },
+        ::std::mem::offset_of!(spAnimation, timelinesCount),
         12usize,
         concat!(
             "Offset of field: ",
@@ -338,7 +338,7 @@ fn bindgen_test_layout_spAnimation() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimation>())).timelines as *const _ as usize },
+        ::std::mem::offset_of!(spAnimation, timelines),
         16usize,
         concat!(
             "Offset of field: ",
@@ -417,7 +417,7 @@ fn bindgen_test_layout_spTimeline() {
         concat!("Alignment of ", stringify!(spTimeline))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTimeline>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(spTimeline, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -427,7 +427,7 @@ fn bindgen_test_layout_spTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTimeline>())).vtable as *const _ as usize },
+        ::std::mem::offset_of!(spTimeline, vtable),
         8usize,
         concat!(
             "Offset of field: ",
@@ -475,7 +475,7 @@ fn bindgen_test_layout_spCurveTimeline() {
         concat!("Alignment of ", stringify!(spCurveTimeline))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spCurveTimeline>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spCurveTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -485,7 +485,7 @@ fn bindgen_test_layout_spCurveTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spCurveTimeline>())).curves as *const _ as usize },
+        ::std::mem::offset_of!(spCurveTimeline, curves),
         16usize,
         concat!(
             "Offset of field: ",
@@ -545,7 +545,7 @@ fn bindgen_test_layout_spBaseTimeline() {
         concat!("Alignment of ", stringify!(spBaseTimeline))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBaseTimeline>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spBaseTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -555,7 +555,7 @@ fn bindgen_test_layout_spBaseTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBaseTimeline>())).framesCount as *const _ as usize },
+        ::std::mem::offset_of!(spBaseTimeline, framesCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -565,7 +565,7 @@ fn bindgen_test_layout_spBaseTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBaseTimeline>())).frames as *const _ as usize },
+        ::std::mem::offset_of!(spBaseTimeline, frames),
         32usize,
         concat!(
             "Offset of field: ",
@@ -575,7 +575,7 @@ fn bindgen_test_layout_spBaseTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBaseTimeline>())).boneIndex as *const _ as usize },
+        ::std::mem::offset_of!(spBaseTimeline, boneIndex),
         40usize,
         concat!(
             "Offset of field: ",
@@ -665,7 +665,7 @@ fn bindgen_test_layout_spColorTimeline() {
         concat!("Alignment of ", stringify!(spColorTimeline))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spColorTimeline>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spColorTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -675,7 +675,7 @@ fn bindgen_test_layout_spColorTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spColorTimeline>())).framesCount as *const _ as usize },
+        ::std::mem::offset_of!(spColorTimeline, framesCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -685,7 +685,7 @@ fn bindgen_test_layout_spColorTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spColorTimeline>())).frames as *const _ as usize },
+        ::std::mem::offset_of!(spColorTimeline, frames),
         32usize,
         concat!(
             "Offset of field: ",
@@ -695,7 +695,7 @@ fn bindgen_test_layout_spColorTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spColorTimeline>())).slotIndex as *const _ as usize },
+        ::std::mem::offset_of!(spColorTimeline, slotIndex),
         40usize,
         concat!(
             "Offset of field: ",
@@ -741,7 +741,7 @@ fn bindgen_test_layout_spTwoColorTimeline() {
         concat!("Alignment of ", stringify!(spTwoColorTimeline))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTwoColorTimeline>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spTwoColorTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -751,7 +751,7 @@ fn bindgen_test_layout_spTwoColorTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTwoColorTimeline>())).framesCount as *const _ as usize },
+        ::std::mem::offset_of!(spTwoColorTimeline, framesCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -761,7 +761,7 @@ fn bindgen_test_layout_spTwoColorTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTwoColorTimeline>())).frames as *const _ as usize },
+        ::std::mem::offset_of!(spTwoColorTimeline, frames),
         32usize,
         concat!(
             "Offset of field: ",
@@ -771,7 +771,7 @@ fn bindgen_test_layout_spTwoColorTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTwoColorTimeline>())).slotIndex as *const _ as usize },
+        ::std::mem::offset_of!(spTwoColorTimeline, slotIndex),
         40usize,
         concat!(
             "Offset of field: ",
@@ -821,7 +821,7 @@ fn bindgen_test_layout_spAttachmentTimeline() {
         concat!("Alignment of ", stringify!(spAttachmentTimeline))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachmentTimeline>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spAttachmentTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -831,9 +831,7 @@ fn bindgen_test_layout_spAttachmentTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spAttachmentTimeline>())).framesCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spAttachmentTimeline, framesCount),
         16usize,
         concat!(
             "Offset of field: ",
@@ -843,7 +841,7 @@ fn bindgen_test_layout_spAttachmentTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachmentTimeline>())).frames as *const _ as usize },
+        ::std::mem::offset_of!(spAttachmentTimeline, frames),
         24usize,
         concat!(
             "Offset of field: ",
@@ -853,7 +851,7 @@ fn bindgen_test_layout_spAttachmentTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachmentTimeline>())).slotIndex as *const _ as usize },
+        ::std::mem::offset_of!(spAttachmentTimeline, slotIndex),
         32usize,
         concat!(
             "Offset of field: ",
@@ -863,9 +861,7 @@ fn bindgen_test_layout_spAttachmentTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spAttachmentTimeline>())).attachmentNames as *const _ as usize
-        },
+        ::std::mem::offset_of!(spAttachmentTimeline, attachmentNames),
         40usize,
         concat!(
             "Offset of field: ",
@@ -909,7 +905,7 @@ fn bindgen_test_layout_spEventTimeline() {
         concat!("Alignment of ", stringify!(spEventTimeline))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventTimeline>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spEventTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -919,7 +915,7 @@ fn bindgen_test_layout_spEventTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventTimeline>())).framesCount as *const _ as usize },
+        ::std::mem::offset_of!(spEventTimeline, framesCount),
         16usize,
         concat!(
             "Offset of field: ",
@@ -929,7 +925,7 @@ fn bindgen_test_layout_spEventTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventTimeline>())).frames as *const _ as usize },
+        ::std::mem::offset_of!(spEventTimeline, frames),
         24usize,
         concat!(
             "Offset of field: ",
@@ -939,7 +935,7 @@ fn bindgen_test_layout_spEventTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spEventTimeline>())).events as *const _ as usize },
+        ::std::mem::offset_of!(spEventTimeline, events),
         32usize,
         concat!(
             "Offset of field: ",
@@ -981,7 +977,7 @@ fn bindgen_test_layout_spDrawOrderTimeline() {
         concat!("Alignment of ", stringify!(spDrawOrderTimeline))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDrawOrderTimeline>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spDrawOrderTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -991,7 +987,7 @@ fn bindgen_test_layout_spDrawOrderTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDrawOrderTimeline>())).framesCount as *const _ as usize },
+        ::std::mem::offset_of!(spDrawOrderTimeline, framesCount),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1001,7 +997,7 @@ fn bindgen_test_layout_spDrawOrderTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDrawOrderTimeline>())).frames as *const _ as usize },
+        ::std::mem::offset_of!(spDrawOrderTimeline, frames),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1011,7 +1007,7 @@ fn bindgen_test_layout_spDrawOrderTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDrawOrderTimeline>())).drawOrders as *const _ as usize },
+        ::std::mem::offset_of!(spDrawOrderTimeline, drawOrders),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1021,7 +1017,7 @@ fn bindgen_test_layout_spDrawOrderTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDrawOrderTimeline>())).slotsCount as *const _ as usize },
+        ::std::mem::offset_of!(spDrawOrderTimeline, slotsCount),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1069,7 +1065,7 @@ fn bindgen_test_layout_spDeformTimeline() {
         concat!("Alignment of ", stringify!(spDeformTimeline))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDeformTimeline>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spDeformTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1079,7 +1075,7 @@ fn bindgen_test_layout_spDeformTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDeformTimeline>())).framesCount as *const _ as usize },
+        ::std::mem::offset_of!(spDeformTimeline, framesCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1089,7 +1085,7 @@ fn bindgen_test_layout_spDeformTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDeformTimeline>())).frames as *const _ as usize },
+        ::std::mem::offset_of!(spDeformTimeline, frames),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1099,9 +1095,7 @@ fn bindgen_test_layout_spDeformTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spDeformTimeline>())).frameVerticesCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spDeformTimeline, frameVerticesCount),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1111,7 +1105,7 @@ fn bindgen_test_layout_spDeformTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDeformTimeline>())).frameVertices as *const _ as usize },
+        ::std::mem::offset_of!(spDeformTimeline, frameVertices),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1121,7 +1115,7 @@ fn bindgen_test_layout_spDeformTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDeformTimeline>())).slotIndex as *const _ as usize },
+        ::std::mem::offset_of!(spDeformTimeline, slotIndex),
         56usize,
         concat!(
             "Offset of field: ",
@@ -1131,7 +1125,7 @@ fn bindgen_test_layout_spDeformTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spDeformTimeline>())).attachment as *const _ as usize },
+        ::std::mem::offset_of!(spDeformTimeline, attachment),
         64usize,
         concat!(
             "Offset of field: ",
@@ -1177,7 +1171,7 @@ fn bindgen_test_layout_spIkConstraintTimeline() {
         concat!("Alignment of ", stringify!(spIkConstraintTimeline))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintTimeline>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1187,9 +1181,7 @@ fn bindgen_test_layout_spIkConstraintTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spIkConstraintTimeline>())).framesCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spIkConstraintTimeline, framesCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1199,7 +1191,7 @@ fn bindgen_test_layout_spIkConstraintTimeline() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintTimeline>())).frames as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintTimeline, frames),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1209,10 +1201,7 @@ fn bindgen_test_layout_spIkConstraintTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spIkConstraintTimeline>())).ikConstraintIndex as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(spIkConstraintTimeline, ikConstraintIndex),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1261,9 +1250,7 @@ fn bindgen_test_layout_spTransformConstraintTimeline() {
         concat!("Alignment of ", stringify!(spTransformConstraintTimeline))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintTimeline>())).super_ as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1273,10 +1260,7 @@ fn bindgen_test_layout_spTransformConstraintTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintTimeline>())).framesCount as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintTimeline, framesCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1286,9 +1270,7 @@ fn bindgen_test_layout_spTransformConstraintTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintTimeline>())).frames as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintTimeline, frames),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1298,10 +1280,7 @@ fn bindgen_test_layout_spTransformConstraintTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintTimeline>())).transformConstraintIndex
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintTimeline, transformConstraintIndex),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1352,9 +1331,7 @@ fn bindgen_test_layout_spPathConstraintPositionTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintPositionTimeline>())).super_ as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintPositionTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1364,10 +1341,7 @@ fn bindgen_test_layout_spPathConstraintPositionTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintPositionTimeline>())).framesCount as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintPositionTimeline, framesCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1377,9 +1351,7 @@ fn bindgen_test_layout_spPathConstraintPositionTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintPositionTimeline>())).frames as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintPositionTimeline, frames),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1389,10 +1361,7 @@ fn bindgen_test_layout_spPathConstraintPositionTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintPositionTimeline>())).pathConstraintIndex
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintPositionTimeline, pathConstraintIndex),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1437,9 +1406,7 @@ fn bindgen_test_layout_spPathConstraintSpacingTimeline() {
         concat!("Alignment of ", stringify!(spPathConstraintSpacingTimeline))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintSpacingTimeline>())).super_ as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintSpacingTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1449,10 +1416,7 @@ fn bindgen_test_layout_spPathConstraintSpacingTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintSpacingTimeline>())).framesCount as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintSpacingTimeline, framesCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1462,9 +1426,7 @@ fn bindgen_test_layout_spPathConstraintSpacingTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintSpacingTimeline>())).frames as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintSpacingTimeline, frames),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1474,10 +1436,7 @@ fn bindgen_test_layout_spPathConstraintSpacingTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintSpacingTimeline>())).pathConstraintIndex
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintSpacingTimeline, pathConstraintIndex),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1522,9 +1481,7 @@ fn bindgen_test_layout_spPathConstraintMixTimeline() {
         concat!("Alignment of ", stringify!(spPathConstraintMixTimeline))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintMixTimeline>())).super_ as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintMixTimeline, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1534,9 +1491,7 @@ fn bindgen_test_layout_spPathConstraintMixTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintMixTimeline>())).framesCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintMixTimeline, framesCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1546,9 +1501,7 @@ fn bindgen_test_layout_spPathConstraintMixTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintMixTimeline>())).frames as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintMixTimeline, frames),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1558,10 +1511,7 @@ fn bindgen_test_layout_spPathConstraintMixTimeline() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintMixTimeline>())).pathConstraintIndex as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintMixTimeline, pathConstraintIndex),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1621,7 +1571,7 @@ fn bindgen_test_layout_spBoneData() {
         concat!("Alignment of ", stringify!(spBoneData))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).index as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, index),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1631,7 +1581,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1641,7 +1591,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).parent as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, parent),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1651,7 +1601,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).length as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, length),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1661,7 +1611,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).x as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, x),
         28usize,
         concat!(
             "Offset of field: ",
@@ -1671,7 +1621,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).y as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, y),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1681,7 +1631,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).rotation as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, rotation),
         36usize,
         concat!(
             "Offset of field: ",
@@ -1691,7 +1641,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).scaleX as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, scaleX),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1701,7 +1651,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).scaleY as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, scaleY),
         44usize,
         concat!(
             "Offset of field: ",
@@ -1711,7 +1661,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).shearX as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, shearX),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1721,7 +1671,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).shearY as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, shearY),
         52usize,
         concat!(
             "Offset of field: ",
@@ -1731,7 +1681,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).transformMode as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, transformMode),
         56usize,
         concat!(
             "Offset of field: ",
@@ -1741,7 +1691,7 @@ fn bindgen_test_layout_spBoneData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneData>())).skinRequired as *const _ as usize },
+        ::std::mem::offset_of!(spBoneData, skinRequired),
         60usize,
         concat!(
             "Offset of field: ",
@@ -1782,7 +1732,7 @@ fn bindgen_test_layout_spColor() {
         concat!("Alignment of ", stringify!(spColor))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spColor>())).r as *const _ as usize },
+        ::std::mem::offset_of!(spColor, r),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1792,7 +1742,7 @@ fn bindgen_test_layout_spColor() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spColor>())).g as *const _ as usize },
+        ::std::mem::offset_of!(spColor, g),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1802,7 +1752,7 @@ fn bindgen_test_layout_spColor() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spColor>())).b as *const _ as usize },
+        ::std::mem::offset_of!(spColor, b),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1812,7 +1762,7 @@ fn bindgen_test_layout_spColor() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spColor>())).a as *const _ as usize },
+        ::std::mem::offset_of!(spColor, a),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1872,7 +1822,7 @@ fn bindgen_test_layout_spSlotData() {
         concat!("Alignment of ", stringify!(spSlotData))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlotData>())).index as *const _ as usize },
+        ::std::mem::offset_of!(spSlotData, index),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1882,7 +1832,7 @@ fn bindgen_test_layout_spSlotData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlotData>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spSlotData, name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1892,7 +1842,7 @@ fn bindgen_test_layout_spSlotData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlotData>())).boneData as *const _ as usize },
+        ::std::mem::offset_of!(spSlotData, boneData),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1902,7 +1852,7 @@ fn bindgen_test_layout_spSlotData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlotData>())).attachmentName as *const _ as usize },
+        ::std::mem::offset_of!(spSlotData, attachmentName),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1912,7 +1862,7 @@ fn bindgen_test_layout_spSlotData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlotData>())).color as *const _ as usize },
+        ::std::mem::offset_of!(spSlotData, color),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1922,7 +1872,7 @@ fn bindgen_test_layout_spSlotData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlotData>())).darkColor as *const _ as usize },
+        ::std::mem::offset_of!(spSlotData, darkColor),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1932,7 +1882,7 @@ fn bindgen_test_layout_spSlotData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlotData>())).blendMode as *const _ as usize },
+        ::std::mem::offset_of!(spSlotData, blendMode),
         56usize,
         concat!(
             "Offset of field: ",
@@ -1987,7 +1937,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         concat!("Alignment of ", stringify!(spIkConstraintData))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1997,7 +1947,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).order as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, order),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2007,7 +1957,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).skinRequired as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, skinRequired),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2017,7 +1967,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).bonesCount as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, bonesCount),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2027,7 +1977,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).bones as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, bones),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2037,7 +1987,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).target as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, target),
         32usize,
         concat!(
             "Offset of field: ",
@@ -2047,9 +1997,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spIkConstraintData>())).bendDirection as *const _ as usize
-        },
+        ::std::mem::offset_of!(spIkConstraintData, bendDirection),
         40usize,
         concat!(
             "Offset of field: ",
@@ -2059,7 +2007,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).compress as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, compress),
         44usize,
         concat!(
             "Offset of field: ",
@@ -2069,7 +2017,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).stretch as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, stretch),
         48usize,
         concat!(
             "Offset of field: ",
@@ -2079,7 +2027,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).uniform as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, uniform),
         52usize,
         concat!(
             "Offset of field: ",
@@ -2089,7 +2037,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).mix as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, mix),
         56usize,
         concat!(
             "Offset of field: ",
@@ -2099,7 +2047,7 @@ fn bindgen_test_layout_spIkConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintData>())).softness as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintData, softness),
         60usize,
         concat!(
             "Offset of field: ",
@@ -2152,7 +2100,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         concat!("Alignment of ", stringify!(spTransformConstraintData))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraintData>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraintData, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2162,7 +2110,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraintData>())).order as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraintData, order),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2172,9 +2120,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).skinRequired as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, skinRequired),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2184,9 +2130,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).bonesCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, bonesCount),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2196,7 +2140,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraintData>())).bones as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraintData, bones),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2206,9 +2150,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).target as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, target),
         32usize,
         concat!(
             "Offset of field: ",
@@ -2218,9 +2160,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).rotateMix as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, rotateMix),
         40usize,
         concat!(
             "Offset of field: ",
@@ -2230,9 +2170,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).translateMix as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, translateMix),
         44usize,
         concat!(
             "Offset of field: ",
@@ -2242,9 +2180,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).scaleMix as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, scaleMix),
         48usize,
         concat!(
             "Offset of field: ",
@@ -2254,9 +2190,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).shearMix as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, shearMix),
         52usize,
         concat!(
             "Offset of field: ",
@@ -2266,10 +2200,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).offsetRotation as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, offsetRotation),
         56usize,
         concat!(
             "Offset of field: ",
@@ -2279,9 +2210,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).offsetX as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, offsetX),
         60usize,
         concat!(
             "Offset of field: ",
@@ -2291,9 +2220,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).offsetY as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, offsetY),
         64usize,
         concat!(
             "Offset of field: ",
@@ -2303,9 +2230,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).offsetScaleX as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, offsetScaleX),
         68usize,
         concat!(
             "Offset of field: ",
@@ -2315,9 +2240,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).offsetScaleY as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, offsetScaleY),
         72usize,
         concat!(
             "Offset of field: ",
@@ -2327,9 +2250,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).offsetShearY as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, offsetShearY),
         76usize,
         concat!(
             "Offset of field: ",
@@ -2339,9 +2260,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintData>())).relative as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintData, relative),
         80usize,
         concat!(
             "Offset of field: ",
@@ -2351,7 +2270,7 @@ fn bindgen_test_layout_spTransformConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraintData>())).local as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraintData, local),
         84usize,
         concat!(
             "Offset of field: ",
@@ -2411,7 +2330,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         concat!("Alignment of ", stringify!(spPathConstraintData))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintData>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintData, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2421,7 +2340,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintData>())).order as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintData, order),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2431,9 +2350,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintData>())).skinRequired as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintData, skinRequired),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2443,7 +2360,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintData>())).bonesCount as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintData, bonesCount),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2453,7 +2370,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintData>())).bones as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintData, bones),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2463,7 +2380,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintData>())).target as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintData, target),
         32usize,
         concat!(
             "Offset of field: ",
@@ -2473,9 +2390,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintData>())).positionMode as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintData, positionMode),
         40usize,
         concat!(
             "Offset of field: ",
@@ -2485,9 +2400,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintData>())).spacingMode as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintData, spacingMode),
         44usize,
         concat!(
             "Offset of field: ",
@@ -2497,7 +2410,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintData>())).rotateMode as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintData, rotateMode),
         48usize,
         concat!(
             "Offset of field: ",
@@ -2507,9 +2420,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintData>())).offsetRotation as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintData, offsetRotation),
         52usize,
         concat!(
             "Offset of field: ",
@@ -2519,7 +2430,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintData>())).position as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintData, position),
         56usize,
         concat!(
             "Offset of field: ",
@@ -2529,7 +2440,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintData>())).spacing as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintData, spacing),
         60usize,
         concat!(
             "Offset of field: ",
@@ -2539,7 +2450,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintData>())).rotateMix as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintData, rotateMix),
         64usize,
         concat!(
             "Offset of field: ",
@@ -2549,9 +2460,7 @@ fn bindgen_test_layout_spPathConstraintData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintData>())).translateMix as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintData, translateMix),
         68usize,
         concat!(
             "Offset of field: ",
@@ -2589,7 +2498,7 @@ fn bindgen_test_layout_spFloatArray() {
         concat!("Alignment of ", stringify!(spFloatArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spFloatArray>())).size as *const _ as usize },
+        ::std::mem::offset_of!(spFloatArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2599,7 +2508,7 @@ fn bindgen_test_layout_spFloatArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spFloatArray>())).capacity as *const _ as usize },
+        ::std::mem::offset_of!(spFloatArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2609,7 +2518,7 @@ fn bindgen_test_layout_spFloatArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spFloatArray>())).items as *const _ as usize },
+        ::std::mem::offset_of!(spFloatArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2686,7 +2595,7 @@ fn bindgen_test_layout_spIntArray() {
         concat!("Alignment of ", stringify!(spIntArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIntArray>())).size as *const _ as usize },
+        ::std::mem::offset_of!(spIntArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2696,7 +2605,7 @@ fn bindgen_test_layout_spIntArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIntArray>())).capacity as *const _ as usize },
+        ::std::mem::offset_of!(spIntArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2706,7 +2615,7 @@ fn bindgen_test_layout_spIntArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIntArray>())).items as *const _ as usize },
+        ::std::mem::offset_of!(spIntArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2783,7 +2692,7 @@ fn bindgen_test_layout_spShortArray() {
         concat!("Alignment of ", stringify!(spShortArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spShortArray>())).size as *const _ as usize },
+        ::std::mem::offset_of!(spShortArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2793,7 +2702,7 @@ fn bindgen_test_layout_spShortArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spShortArray>())).capacity as *const _ as usize },
+        ::std::mem::offset_of!(spShortArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2803,7 +2712,7 @@ fn bindgen_test_layout_spShortArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spShortArray>())).items as *const _ as usize },
+        ::std::mem::offset_of!(spShortArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2883,7 +2792,7 @@ fn bindgen_test_layout_spUnsignedShortArray() {
         concat!("Alignment of ", stringify!(spUnsignedShortArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spUnsignedShortArray>())).size as *const _ as usize },
+        ::std::mem::offset_of!(spUnsignedShortArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2893,7 +2802,7 @@ fn bindgen_test_layout_spUnsignedShortArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spUnsignedShortArray>())).capacity as *const _ as usize },
+        ::std::mem::offset_of!(spUnsignedShortArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2903,7 +2812,7 @@ fn bindgen_test_layout_spUnsignedShortArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spUnsignedShortArray>())).items as *const _ as usize },
+        ::std::mem::offset_of!(spUnsignedShortArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2994,7 +2903,7 @@ fn bindgen_test_layout_spArrayFloatArray() {
         concat!("Alignment of ", stringify!(spArrayFloatArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spArrayFloatArray>())).size as *const _ as usize },
+        ::std::mem::offset_of!(spArrayFloatArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3004,7 +2913,7 @@ fn bindgen_test_layout_spArrayFloatArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spArrayFloatArray>())).capacity as *const _ as usize },
+        ::std::mem::offset_of!(spArrayFloatArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3014,7 +2923,7 @@ fn bindgen_test_layout_spArrayFloatArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spArrayFloatArray>())).items as *const _ as usize },
+        ::std::mem::offset_of!(spArrayFloatArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3096,7 +3005,7 @@ fn bindgen_test_layout_spArrayShortArray() {
         concat!("Alignment of ", stringify!(spArrayShortArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spArrayShortArray>())).size as *const _ as usize },
+        ::std::mem::offset_of!(spArrayShortArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3106,7 +3015,7 @@ fn bindgen_test_layout_spArrayShortArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spArrayShortArray>())).capacity as *const _ as usize },
+        ::std::mem::offset_of!(spArrayShortArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3116,7 +3025,7 @@ fn bindgen_test_layout_spArrayShortArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spArrayShortArray>())).items as *const _ as usize },
+        ::std::mem::offset_of!(spArrayShortArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3198,7 +3107,7 @@ fn bindgen_test_layout_spBoneDataArray() {
         concat!("Alignment of ", stringify!(spBoneDataArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneDataArray>())).size as *const _ as usize },
+        ::std::mem::offset_of!(spBoneDataArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3208,7 +3117,7 @@ fn bindgen_test_layout_spBoneDataArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneDataArray>())).capacity as *const _ as usize },
+        ::std::mem::offset_of!(spBoneDataArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3218,7 +3127,7 @@ fn bindgen_test_layout_spBoneDataArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoneDataArray>())).items as *const _ as usize },
+        ::std::mem::offset_of!(spBoneDataArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3298,7 +3207,7 @@ fn bindgen_test_layout_spIkConstraintDataArray() {
         concat!("Alignment of ", stringify!(spIkConstraintDataArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintDataArray>())).size as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintDataArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3308,9 +3217,7 @@ fn bindgen_test_layout_spIkConstraintDataArray() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spIkConstraintDataArray>())).capacity as *const _ as usize
-        },
+        ::std::mem::offset_of!(spIkConstraintDataArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3320,7 +3227,7 @@ fn bindgen_test_layout_spIkConstraintDataArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraintDataArray>())).items as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraintDataArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3415,9 +3322,7 @@ fn bindgen_test_layout_spTransformConstraintDataArray() {
         concat!("Alignment of ", stringify!(spTransformConstraintDataArray))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintDataArray>())).size as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintDataArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3427,9 +3332,7 @@ fn bindgen_test_layout_spTransformConstraintDataArray() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintDataArray>())).capacity as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintDataArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3439,9 +3342,7 @@ fn bindgen_test_layout_spTransformConstraintDataArray() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraintDataArray>())).items as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraintDataArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3536,7 +3437,7 @@ fn bindgen_test_layout_spPathConstraintDataArray() {
         concat!("Alignment of ", stringify!(spPathConstraintDataArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintDataArray>())).size as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintDataArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3546,9 +3447,7 @@ fn bindgen_test_layout_spPathConstraintDataArray() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spPathConstraintDataArray>())).capacity as *const _ as usize
-        },
+        ::std::mem::offset_of!(spPathConstraintDataArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3558,7 +3457,7 @@ fn bindgen_test_layout_spPathConstraintDataArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraintDataArray>())).items as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraintDataArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3655,7 +3554,7 @@ fn bindgen_test_layout_spSkin() {
         concat!("Alignment of ", stringify!(spSkin))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkin>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spSkin, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3665,7 +3564,7 @@ fn bindgen_test_layout_spSkin() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkin>())).bones as *const _ as usize },
+        ::std::mem::offset_of!(spSkin, bones),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3675,7 +3574,7 @@ fn bindgen_test_layout_spSkin() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkin>())).ikConstraints as *const _ as usize },
+        ::std::mem::offset_of!(spSkin, ikConstraints),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3685,7 +3584,7 @@ fn bindgen_test_layout_spSkin() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkin>())).transformConstraints as *const _ as usize },
+        ::std::mem::offset_of!(spSkin, transformConstraints),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3695,7 +3594,7 @@ fn bindgen_test_layout_spSkin() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkin>())).pathConstraints as *const _ as usize },
+        ::std::mem::offset_of!(spSkin, pathConstraints),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3727,7 +3626,7 @@ fn bindgen_test_layout__Entry() {
         concat!("Alignment of ", stringify!(_Entry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_Entry>())).slotIndex as *const _ as usize },
+        ::std::mem::offset_of!(_Entry, slotIndex),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3737,7 +3636,7 @@ fn bindgen_test_layout__Entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_Entry>())).name as *const _ as usize },
+        ::std::mem::offset_of!(_Entry, name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3747,7 +3646,7 @@ fn bindgen_test_layout__Entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_Entry>())).attachment as *const _ as usize },
+        ::std::mem::offset_of!(_Entry, attachment),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3757,7 +3656,7 @@ fn bindgen_test_layout__Entry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_Entry>())).next as *const _ as usize },
+        ::std::mem::offset_of!(_Entry, next),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3786,7 +3685,7 @@ fn bindgen_test_layout__SkinHashTableEntry() {
         concat!("Alignment of ", stringify!(_SkinHashTableEntry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_SkinHashTableEntry>())).entry as *const _ as usize },
+        ::std::mem::offset_of!(_SkinHashTableEntry, entry),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3796,7 +3695,7 @@ fn bindgen_test_layout__SkinHashTableEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_SkinHashTableEntry>())).next as *const _ as usize },
+        ::std::mem::offset_of!(_SkinHashTableEntry, next),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3826,7 +3725,7 @@ fn bindgen_test_layout__spSkin() {
         concat!("Alignment of ", stringify!(_spSkin))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_spSkin>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(_spSkin, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3836,7 +3735,7 @@ fn bindgen_test_layout__spSkin() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_spSkin>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(_spSkin, entries),
         40usize,
         concat!(
             "Offset of field: ",
@@ -3846,7 +3745,7 @@ fn bindgen_test_layout__spSkin() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_spSkin>())).entriesHashTable as *const _ as usize },
+        ::std::mem::offset_of!(_spSkin, entriesHashTable),
         48usize,
         concat!(
             "Offset of field: ",
@@ -3950,7 +3849,7 @@ fn bindgen_test_layout_spSkeletonData() {
         concat!("Alignment of ", stringify!(spSkeletonData))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).version as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, version),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3960,7 +3859,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).hash as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, hash),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3970,7 +3869,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).x as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, x),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3980,7 +3879,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).y as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, y),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3990,7 +3889,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).width as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, width),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4000,7 +3899,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).height as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, height),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4010,7 +3909,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).stringsCount as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, stringsCount),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4020,7 +3919,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).strings as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, strings),
         40usize,
         concat!(
             "Offset of field: ",
@@ -4030,7 +3929,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).bonesCount as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, bonesCount),
         48usize,
         concat!(
             "Offset of field: ",
@@ -4040,7 +3939,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).bones as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, bones),
         56usize,
         concat!(
             "Offset of field: ",
@@ -4050,7 +3949,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).slotsCount as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, slotsCount),
         64usize,
         concat!(
             "Offset of field: ",
@@ -4060,7 +3959,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).slots as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, slots),
         72usize,
         concat!(
             "Offset of field: ",
@@ -4070,7 +3969,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).skinsCount as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, skinsCount),
         80usize,
         concat!(
             "Offset of field: ",
@@ -4080,7 +3979,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).skins as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, skins),
         88usize,
         concat!(
             "Offset of field: ",
@@ -4090,7 +3989,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).defaultSkin as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, defaultSkin),
         96usize,
         concat!(
             "Offset of field: ",
@@ -4100,7 +3999,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).eventsCount as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, eventsCount),
         104usize,
         concat!(
             "Offset of field: ",
@@ -4110,7 +4009,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).events as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, events),
         112usize,
         concat!(
             "Offset of field: ",
@@ -4120,7 +4019,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).animationsCount as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, animationsCount),
         120usize,
         concat!(
             "Offset of field: ",
@@ -4130,7 +4029,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).animations as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, animations),
         128usize,
         concat!(
             "Offset of field: ",
@@ -4140,9 +4039,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeletonData>())).ikConstraintsCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spSkeletonData, ikConstraintsCount),
         136usize,
         concat!(
             "Offset of field: ",
@@ -4152,7 +4049,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).ikConstraints as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, ikConstraints),
         144usize,
         concat!(
             "Offset of field: ",
@@ -4162,10 +4059,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeletonData>())).transformConstraintsCount as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(spSkeletonData, transformConstraintsCount),
         152usize,
         concat!(
             "Offset of field: ",
@@ -4175,9 +4069,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeletonData>())).transformConstraints as *const _ as usize
-        },
+        ::std::mem::offset_of!(spSkeletonData, transformConstraints),
         160usize,
         concat!(
             "Offset of field: ",
@@ -4187,9 +4079,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeletonData>())).pathConstraintsCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spSkeletonData, pathConstraintsCount),
         168usize,
         concat!(
             "Offset of field: ",
@@ -4199,7 +4089,7 @@ fn bindgen_test_layout_spSkeletonData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonData>())).pathConstraints as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonData, pathConstraints),
         176usize,
         concat!(
             "Offset of field: ",
@@ -4295,9 +4185,7 @@ fn bindgen_test_layout_spAnimationStateData() {
         concat!("Alignment of ", stringify!(spAnimationStateData))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spAnimationStateData>())).skeletonData as *const _ as usize
-        },
+        ::std::mem::offset_of!(spAnimationStateData, skeletonData),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4307,7 +4195,7 @@ fn bindgen_test_layout_spAnimationStateData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimationStateData>())).defaultMix as *const _ as usize },
+        ::std::mem::offset_of!(spAnimationStateData, defaultMix),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4317,7 +4205,7 @@ fn bindgen_test_layout_spAnimationStateData() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimationStateData>())).entries as *const _ as usize },
+        ::std::mem::offset_of!(spAnimationStateData, entries),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4393,7 +4281,7 @@ fn bindgen_test_layout_spTrackEntryArray() {
         concat!("Alignment of ", stringify!(spTrackEntryArray))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntryArray>())).size as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntryArray, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4403,7 +4291,7 @@ fn bindgen_test_layout_spTrackEntryArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntryArray>())).capacity as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntryArray, capacity),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4413,7 +4301,7 @@ fn bindgen_test_layout_spTrackEntryArray() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntryArray>())).items as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntryArray, items),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4525,7 +4413,7 @@ fn bindgen_test_layout_spTrackEntry() {
         concat!("Alignment of ", stringify!(spTrackEntry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).animation as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, animation),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4535,7 +4423,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).next as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, next),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4545,7 +4433,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).mixingFrom as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, mixingFrom),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4555,7 +4443,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).mixingTo as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, mixingTo),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4565,7 +4453,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).listener as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, listener),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4575,7 +4463,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).trackIndex as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, trackIndex),
         40usize,
         concat!(
             "Offset of field: ",
@@ -4585,7 +4473,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).loop_ as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, loop_),
         44usize,
         concat!(
             "Offset of field: ",
@@ -4595,7 +4483,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).holdPrevious as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, holdPrevious),
         48usize,
         concat!(
             "Offset of field: ",
@@ -4605,7 +4493,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).eventThreshold as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, eventThreshold),
         52usize,
         concat!(
             "Offset of field: ",
@@ -4615,9 +4503,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTrackEntry>())).attachmentThreshold as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTrackEntry, attachmentThreshold),
         56usize,
         concat!(
             "Offset of field: ",
@@ -4627,7 +4513,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).drawOrderThreshold as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, drawOrderThreshold),
         60usize,
         concat!(
             "Offset of field: ",
@@ -4637,7 +4523,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).animationStart as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, animationStart),
         64usize,
         concat!(
             "Offset of field: ",
@@ -4647,7 +4533,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).animationEnd as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, animationEnd),
         68usize,
         concat!(
             "Offset of field: ",
@@ -4657,7 +4543,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).animationLast as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, animationLast),
         72usize,
         concat!(
             "Offset of field: ",
@@ -4667,7 +4553,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).nextAnimationLast as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, nextAnimationLast),
         76usize,
         concat!(
             "Offset of field: ",
@@ -4677,7 +4563,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).delay as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, delay),
         80usize,
         concat!(
             "Offset of field: ",
@@ -4687,7 +4573,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).trackTime as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, trackTime),
         84usize,
         concat!(
             "Offset of field: ",
@@ -4697,7 +4583,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).trackLast as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, trackLast),
         88usize,
         concat!(
             "Offset of field: ",
@@ -4707,7 +4593,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).nextTrackLast as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, nextTrackLast),
         92usize,
         concat!(
             "Offset of field: ",
@@ -4717,7 +4603,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).trackEnd as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, trackEnd),
         96usize,
         concat!(
             "Offset of field: ",
@@ -4727,7 +4613,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).timeScale as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, timeScale),
         100usize,
         concat!(
             "Offset of field: ",
@@ -4737,7 +4623,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).alpha as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, alpha),
         104usize,
         concat!(
             "Offset of field: ",
@@ -4747,7 +4633,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).mixTime as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, mixTime),
         108usize,
         concat!(
             "Offset of field: ",
@@ -4757,7 +4643,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).mixDuration as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, mixDuration),
         112usize,
         concat!(
             "Offset of field: ",
@@ -4767,7 +4653,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).interruptAlpha as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, interruptAlpha),
         116usize,
         concat!(
             "Offset of field: ",
@@ -4777,7 +4663,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).totalAlpha as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, totalAlpha),
         120usize,
         concat!(
             "Offset of field: ",
@@ -4787,7 +4673,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).mixBlend as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, mixBlend),
         124usize,
         concat!(
             "Offset of field: ",
@@ -4797,7 +4683,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).timelineMode as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, timelineMode),
         128usize,
         concat!(
             "Offset of field: ",
@@ -4807,7 +4693,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).timelineHoldMix as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, timelineHoldMix),
         136usize,
         concat!(
             "Offset of field: ",
@@ -4817,7 +4703,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).timelinesRotation as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, timelinesRotation),
         144usize,
         concat!(
             "Offset of field: ",
@@ -4827,9 +4713,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTrackEntry>())).timelinesRotationCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTrackEntry, timelinesRotationCount),
         152usize,
         concat!(
             "Offset of field: ",
@@ -4839,7 +4723,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).rendererObject as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, rendererObject),
         160usize,
         concat!(
             "Offset of field: ",
@@ -4849,7 +4733,7 @@ fn bindgen_test_layout_spTrackEntry() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTrackEntry>())).userData as *const _ as usize },
+        ::std::mem::offset_of!(spTrackEntry, userData),
         168usize,
         concat!(
             "Offset of field: ",
@@ -4883,7 +4767,7 @@ fn bindgen_test_layout_spAnimationState() {
         concat!("Alignment of ", stringify!(spAnimationState))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimationState>())).data as *const _ as usize },
+        ::std::mem::offset_of!(spAnimationState, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4893,7 +4777,7 @@ fn bindgen_test_layout_spAnimationState() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimationState>())).tracksCount as *const _ as usize },
+        ::std::mem::offset_of!(spAnimationState, tracksCount),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4903,7 +4787,7 @@ fn bindgen_test_layout_spAnimationState() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimationState>())).tracks as *const _ as usize },
+        ::std::mem::offset_of!(spAnimationState, tracks),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4913,7 +4797,7 @@ fn bindgen_test_layout_spAnimationState() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimationState>())).listener as *const _ as usize },
+        ::std::mem::offset_of!(spAnimationState, listener),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4923,7 +4807,7 @@ fn bindgen_test_layout_spAnimationState() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimationState>())).timeScale as *const _ as usize },
+        ::std::mem::offset_of!(spAnimationState, timeScale),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4933,7 +4817,7 @@ fn bindgen_test_layout_spAnimationState() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimationState>())).rendererObject as *const _ as usize },
+        ::std::mem::offset_of!(spAnimationState, rendererObject),
         40usize,
         concat!(
             "Offset of field: ",
@@ -4943,7 +4827,7 @@ fn bindgen_test_layout_spAnimationState() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAnimationState>())).userData as *const _ as usize },
+        ::std::mem::offset_of!(spAnimationState, userData),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5099,7 +4983,7 @@ fn bindgen_test_layout_spAtlasPage() {
         concat!("Alignment of ", stringify!(spAtlasPage))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).atlas as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, atlas),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5109,7 +4993,7 @@ fn bindgen_test_layout_spAtlasPage() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5119,7 +5003,7 @@ fn bindgen_test_layout_spAtlasPage() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).format as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, format),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5129,7 +5013,7 @@ fn bindgen_test_layout_spAtlasPage() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).minFilter as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, minFilter),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5139,7 +5023,7 @@ fn bindgen_test_layout_spAtlasPage() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).magFilter as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, magFilter),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5149,7 +5033,7 @@ fn bindgen_test_layout_spAtlasPage() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).uWrap as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, uWrap),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5159,7 +5043,7 @@ fn bindgen_test_layout_spAtlasPage() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).vWrap as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, vWrap),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5169,7 +5053,7 @@ fn bindgen_test_layout_spAtlasPage() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).rendererObject as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, rendererObject),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5179,7 +5063,7 @@ fn bindgen_test_layout_spAtlasPage() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).width as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, width),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5189,7 +5073,7 @@ fn bindgen_test_layout_spAtlasPage() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).height as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, height),
         52usize,
         concat!(
             "Offset of field: ",
@@ -5199,7 +5083,7 @@ fn bindgen_test_layout_spAtlasPage() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasPage>())).next as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasPage, next),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5256,7 +5140,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         concat!("Alignment of ", stringify!(spAtlasRegion))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).name as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5266,7 +5150,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).x as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, x),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5276,7 +5160,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).y as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, y),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5286,7 +5170,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).width as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, width),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5296,7 +5180,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).height as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, height),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5306,7 +5190,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).u as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, u),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5316,7 +5200,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).v as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, v),
         28usize,
         concat!(
             "Offset of field: ",
@@ -5326,7 +5210,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).u2 as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, u2),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5336,7 +5220,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).v2 as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, v2),
         36usize,
         concat!(
             "Offset of field: ",
@@ -5346,7 +5230,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).offsetX as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, offsetX),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5356,7 +5240,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).offsetY as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, offsetY),
         44usize,
         concat!(
             "Offset of field: ",
@@ -5366,7 +5250,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).originalWidth as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, originalWidth),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5376,7 +5260,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).originalHeight as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, originalHeight),
         52usize,
         concat!(
             "Offset of field: ",
@@ -5386,7 +5270,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).index as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, index),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5396,7 +5280,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).rotate as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, rotate),
         60usize,
         concat!(
             "Offset of field: ",
@@ -5406,7 +5290,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).degrees as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, degrees),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5416,7 +5300,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).flip as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, flip),
         68usize,
         concat!(
             "Offset of field: ",
@@ -5426,7 +5310,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).splits as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, splits),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5436,7 +5320,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).pads as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, pads),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5446,7 +5330,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).page as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, page),
         88usize,
         concat!(
             "Offset of field: ",
@@ -5456,7 +5340,7 @@ fn bindgen_test_layout_spAtlasRegion() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasRegion>())).next as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasRegion, next),
         96usize,
         concat!(
             "Offset of field: ",
@@ -5492,7 +5376,7 @@ fn bindgen_test_layout_spAtlas() {
         concat!("Alignment of ", stringify!(spAtlas))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlas>())).pages as *const _ as usize },
+        ::std::mem::offset_of!(spAtlas, pages),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5502,7 +5386,7 @@ fn bindgen_test_layout_spAtlas() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlas>())).regions as *const _ as usize },
+        ::std::mem::offset_of!(spAtlas, regions),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5512,7 +5396,7 @@ fn bindgen_test_layout_spAtlas() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlas>())).rendererObject as *const _ as usize },
+        ::std::mem::offset_of!(spAtlas, rendererObject),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5565,7 +5449,7 @@ fn bindgen_test_layout_spAttachmentLoader() {
         concat!("Alignment of ", stringify!(spAttachmentLoader))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachmentLoader>())).error1 as *const _ as usize },
+        ::std::mem::offset_of!(spAttachmentLoader, error1),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5575,7 +5459,7 @@ fn bindgen_test_layout_spAttachmentLoader() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachmentLoader>())).error2 as *const _ as usize },
+        ::std::mem::offset_of!(spAttachmentLoader, error2),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5585,7 +5469,7 @@ fn bindgen_test_layout_spAttachmentLoader() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAttachmentLoader>())).vtable as *const _ as usize },
+        ::std::mem::offset_of!(spAttachmentLoader, vtable),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5638,7 +5522,7 @@ fn bindgen_test_layout_spAtlasAttachmentLoader() {
         concat!("Alignment of ", stringify!(spAtlasAttachmentLoader))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasAttachmentLoader>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasAttachmentLoader, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5648,7 +5532,7 @@ fn bindgen_test_layout_spAtlasAttachmentLoader() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spAtlasAttachmentLoader>())).atlas as *const _ as usize },
+        ::std::mem::offset_of!(spAtlasAttachmentLoader, atlas),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5706,7 +5590,7 @@ fn bindgen_test_layout_spBone() {
         concat!("Alignment of ", stringify!(spBone))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).data as *const _ as usize },
+        ::std::mem::offset_of!(spBone, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5716,7 +5600,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).skeleton as *const _ as usize },
+        ::std::mem::offset_of!(spBone, skeleton),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5726,7 +5610,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).parent as *const _ as usize },
+        ::std::mem::offset_of!(spBone, parent),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5736,7 +5620,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).childrenCount as *const _ as usize },
+        ::std::mem::offset_of!(spBone, childrenCount),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5746,7 +5630,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).children as *const _ as usize },
+        ::std::mem::offset_of!(spBone, children),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5756,17 +5640,17 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).x as *const _ as usize },
+        ::std::mem::offset_of!(spBone, x),
         40usize,
         concat!("Offset of field: ", stringify!(spBone), "::", stringify!(x))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).y as *const _ as usize },
+        ::std::mem::offset_of!(spBone, y),
         44usize,
         concat!("Offset of field: ", stringify!(spBone), "::", stringify!(y))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).rotation as *const _ as usize },
+        ::std::mem::offset_of!(spBone, rotation),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5776,7 +5660,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).scaleX as *const _ as usize },
+        ::std::mem::offset_of!(spBone, scaleX),
         52usize,
         concat!(
             "Offset of field: ",
@@ -5786,7 +5670,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).scaleY as *const _ as usize },
+        ::std::mem::offset_of!(spBone, scaleY),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5796,7 +5680,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).shearX as *const _ as usize },
+        ::std::mem::offset_of!(spBone, shearX),
         60usize,
         concat!(
             "Offset of field: ",
@@ -5806,7 +5690,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).shearY as *const _ as usize },
+        ::std::mem::offset_of!(spBone, shearY),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5816,7 +5700,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).ax as *const _ as usize },
+        ::std::mem::offset_of!(spBone, ax),
         68usize,
         concat!(
             "Offset of field: ",
@@ -5826,7 +5710,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).ay as *const _ as usize },
+        ::std::mem::offset_of!(spBone, ay),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5836,7 +5720,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).arotation as *const _ as usize },
+        ::std::mem::offset_of!(spBone, arotation),
         76usize,
         concat!(
             "Offset of field: ",
@@ -5846,7 +5730,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).ascaleX as *const _ as usize },
+        ::std::mem::offset_of!(spBone, ascaleX),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5856,7 +5740,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).ascaleY as *const _ as usize },
+        ::std::mem::offset_of!(spBone, ascaleY),
         84usize,
         concat!(
             "Offset of field: ",
@@ -5866,7 +5750,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).ashearX as *const _ as usize },
+        ::std::mem::offset_of!(spBone, ashearX),
         88usize,
         concat!(
             "Offset of field: ",
@@ -5876,7 +5760,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).ashearY as *const _ as usize },
+        ::std::mem::offset_of!(spBone, ashearY),
         92usize,
         concat!(
             "Offset of field: ",
@@ -5886,7 +5770,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).appliedValid as *const _ as usize },
+        ::std::mem::offset_of!(spBone, appliedValid),
         96usize,
         concat!(
             "Offset of field: ",
@@ -5896,17 +5780,17 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).a as *const _ as usize },
+        ::std::mem::offset_of!(spBone, a),
         100usize,
         concat!("Offset of field: ", stringify!(spBone), "::", stringify!(a))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).b as *const _ as usize },
+        ::std::mem::offset_of!(spBone, b),
         104usize,
         concat!("Offset of field: ", stringify!(spBone), "::", stringify!(b))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).worldX as *const _ as usize },
+        ::std::mem::offset_of!(spBone, worldX),
         108usize,
         concat!(
             "Offset of field: ",
@@ -5916,17 +5800,17 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).c as *const _ as usize },
+        ::std::mem::offset_of!(spBone, c),
         112usize,
         concat!("Offset of field: ", stringify!(spBone), "::", stringify!(c))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).d as *const _ as usize },
+        ::std::mem::offset_of!(spBone, d),
         116usize,
         concat!("Offset of field: ", stringify!(spBone), "::", stringify!(d))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).worldY as *const _ as usize },
+        ::std::mem::offset_of!(spBone, worldY),
         120usize,
         concat!(
             "Offset of field: ",
@@ -5936,7 +5820,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).sorted as *const _ as usize },
+        ::std::mem::offset_of!(spBone, sorted),
         124usize,
         concat!(
             "Offset of field: ",
@@ -5946,7 +5830,7 @@ fn bindgen_test_layout_spBone() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBone>())).active as *const _ as usize },
+        ::std::mem::offset_of!(spBone, active),
         128usize,
         concat!(
             "Offset of field: ",
@@ -6057,7 +5941,7 @@ fn bindgen_test_layout_spSlot() {
         concat!("Alignment of ", stringify!(spSlot))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlot>())).data as *const _ as usize },
+        ::std::mem::offset_of!(spSlot, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6067,7 +5951,7 @@ fn bindgen_test_layout_spSlot() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlot>())).bone as *const _ as usize },
+        ::std::mem::offset_of!(spSlot, bone),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6077,7 +5961,7 @@ fn bindgen_test_layout_spSlot() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlot>())).color as *const _ as usize },
+        ::std::mem::offset_of!(spSlot, color),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6087,7 +5971,7 @@ fn bindgen_test_layout_spSlot() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlot>())).darkColor as *const _ as usize },
+        ::std::mem::offset_of!(spSlot, darkColor),
         32usize,
         concat!(
             "Offset of field: ",
@@ -6097,7 +5981,7 @@ fn bindgen_test_layout_spSlot() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlot>())).attachment as *const _ as usize },
+        ::std::mem::offset_of!(spSlot, attachment),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6107,7 +5991,7 @@ fn bindgen_test_layout_spSlot() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlot>())).deformCapacity as *const _ as usize },
+        ::std::mem::offset_of!(spSlot, deformCapacity),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6117,7 +6001,7 @@ fn bindgen_test_layout_spSlot() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlot>())).deformCount as *const _ as usize },
+        ::std::mem::offset_of!(spSlot, deformCount),
         52usize,
         concat!(
             "Offset of field: ",
@@ -6127,7 +6011,7 @@ fn bindgen_test_layout_spSlot() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSlot>())).deform as *const _ as usize },
+        ::std::mem::offset_of!(spSlot, deform),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6191,7 +6075,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         concat!("Alignment of ", stringify!(spRegionAttachment))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6201,7 +6085,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).path as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, path),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6211,7 +6095,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).x as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, x),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6221,7 +6105,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).y as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, y),
         52usize,
         concat!(
             "Offset of field: ",
@@ -6231,7 +6115,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).scaleX as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, scaleX),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6241,7 +6125,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).scaleY as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, scaleY),
         60usize,
         concat!(
             "Offset of field: ",
@@ -6251,7 +6135,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).rotation as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, rotation),
         64usize,
         concat!(
             "Offset of field: ",
@@ -6261,7 +6145,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).width as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, width),
         68usize,
         concat!(
             "Offset of field: ",
@@ -6271,7 +6155,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).height as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, height),
         72usize,
         concat!(
             "Offset of field: ",
@@ -6281,7 +6165,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).color as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, color),
         76usize,
         concat!(
             "Offset of field: ",
@@ -6291,9 +6175,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spRegionAttachment>())).rendererObject as *const _ as usize
-        },
+        ::std::mem::offset_of!(spRegionAttachment, rendererObject),
         96usize,
         concat!(
             "Offset of field: ",
@@ -6303,9 +6185,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spRegionAttachment>())).regionOffsetX as *const _ as usize
-        },
+        ::std::mem::offset_of!(spRegionAttachment, regionOffsetX),
         104usize,
         concat!(
             "Offset of field: ",
@@ -6315,9 +6195,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spRegionAttachment>())).regionOffsetY as *const _ as usize
-        },
+        ::std::mem::offset_of!(spRegionAttachment, regionOffsetY),
         108usize,
         concat!(
             "Offset of field: ",
@@ -6327,7 +6205,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).regionWidth as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, regionWidth),
         112usize,
         concat!(
             "Offset of field: ",
@@ -6337,7 +6215,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).regionHeight as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, regionHeight),
         116usize,
         concat!(
             "Offset of field: ",
@@ -6347,9 +6225,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spRegionAttachment>())).regionOriginalWidth as *const _ as usize
-        },
+        ::std::mem::offset_of!(spRegionAttachment, regionOriginalWidth),
         120usize,
         concat!(
             "Offset of field: ",
@@ -6359,9 +6235,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spRegionAttachment>())).regionOriginalHeight as *const _ as usize
-        },
+        ::std::mem::offset_of!(spRegionAttachment, regionOriginalHeight),
         124usize,
         concat!(
             "Offset of field: ",
@@ -6371,7 +6245,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).offset as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, offset),
         128usize,
         concat!(
             "Offset of field: ",
@@ -6381,7 +6255,7 @@ fn bindgen_test_layout_spRegionAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spRegionAttachment>())).uvs as *const _ as usize },
+        ::std::mem::offset_of!(spRegionAttachment, uvs),
         160usize,
         concat!(
             "Offset of field: ",
@@ -6443,7 +6317,7 @@ fn bindgen_test_layout_spVertexAttachment() {
         concat!("Alignment of ", stringify!(spVertexAttachment))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spVertexAttachment>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spVertexAttachment, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6453,7 +6327,7 @@ fn bindgen_test_layout_spVertexAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spVertexAttachment>())).bonesCount as *const _ as usize },
+        ::std::mem::offset_of!(spVertexAttachment, bonesCount),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6463,7 +6337,7 @@ fn bindgen_test_layout_spVertexAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spVertexAttachment>())).bones as *const _ as usize },
+        ::std::mem::offset_of!(spVertexAttachment, bones),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6473,9 +6347,7 @@ fn bindgen_test_layout_spVertexAttachment() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spVertexAttachment>())).verticesCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spVertexAttachment, verticesCount),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6485,7 +6357,7 @@ fn bindgen_test_layout_spVertexAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spVertexAttachment>())).vertices as *const _ as usize },
+        ::std::mem::offset_of!(spVertexAttachment, vertices),
         64usize,
         concat!(
             "Offset of field: ",
@@ -6495,9 +6367,7 @@ fn bindgen_test_layout_spVertexAttachment() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spVertexAttachment>())).worldVerticesLength as *const _ as usize
-        },
+        ::std::mem::offset_of!(spVertexAttachment, worldVerticesLength),
         72usize,
         concat!(
             "Offset of field: ",
@@ -6507,9 +6377,7 @@ fn bindgen_test_layout_spVertexAttachment() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spVertexAttachment>())).deformAttachment as *const _ as usize
-        },
+        ::std::mem::offset_of!(spVertexAttachment, deformAttachment),
         80usize,
         concat!(
             "Offset of field: ",
@@ -6519,7 +6387,7 @@ fn bindgen_test_layout_spVertexAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spVertexAttachment>())).id as *const _ as usize },
+        ::std::mem::offset_of!(spVertexAttachment, id),
         88usize,
         concat!(
             "Offset of field: ",
@@ -6589,7 +6457,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         concat!("Alignment of ", stringify!(spMeshAttachment))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6599,7 +6467,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).rendererObject as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, rendererObject),
         96usize,
         concat!(
             "Offset of field: ",
@@ -6609,7 +6477,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionOffsetX as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionOffsetX),
         104usize,
         concat!(
             "Offset of field: ",
@@ -6619,7 +6487,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionOffsetY as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionOffsetY),
         108usize,
         concat!(
             "Offset of field: ",
@@ -6629,7 +6497,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionWidth as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionWidth),
         112usize,
         concat!(
             "Offset of field: ",
@@ -6639,7 +6507,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionHeight as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionHeight),
         116usize,
         concat!(
             "Offset of field: ",
@@ -6649,9 +6517,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spMeshAttachment>())).regionOriginalWidth as *const _ as usize
-        },
+        ::std::mem::offset_of!(spMeshAttachment, regionOriginalWidth),
         120usize,
         concat!(
             "Offset of field: ",
@@ -6661,9 +6527,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spMeshAttachment>())).regionOriginalHeight as *const _ as usize
-        },
+        ::std::mem::offset_of!(spMeshAttachment, regionOriginalHeight),
         124usize,
         concat!(
             "Offset of field: ",
@@ -6673,7 +6537,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionU as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionU),
         128usize,
         concat!(
             "Offset of field: ",
@@ -6683,7 +6547,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionV as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionV),
         132usize,
         concat!(
             "Offset of field: ",
@@ -6693,7 +6557,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionU2 as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionU2),
         136usize,
         concat!(
             "Offset of field: ",
@@ -6703,7 +6567,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionV2 as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionV2),
         140usize,
         concat!(
             "Offset of field: ",
@@ -6713,7 +6577,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionRotate as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionRotate),
         144usize,
         concat!(
             "Offset of field: ",
@@ -6723,7 +6587,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionDegrees as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionDegrees),
         148usize,
         concat!(
             "Offset of field: ",
@@ -6733,7 +6597,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).path as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, path),
         152usize,
         concat!(
             "Offset of field: ",
@@ -6743,7 +6607,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).regionUVs as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, regionUVs),
         160usize,
         concat!(
             "Offset of field: ",
@@ -6753,7 +6617,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).uvs as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, uvs),
         168usize,
         concat!(
             "Offset of field: ",
@@ -6763,7 +6627,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).trianglesCount as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, trianglesCount),
         176usize,
         concat!(
             "Offset of field: ",
@@ -6773,7 +6637,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).triangles as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, triangles),
         184usize,
         concat!(
             "Offset of field: ",
@@ -6783,7 +6647,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).color as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, color),
         192usize,
         concat!(
             "Offset of field: ",
@@ -6793,7 +6657,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).hullLength as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, hullLength),
         208usize,
         concat!(
             "Offset of field: ",
@@ -6803,7 +6667,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).parentMesh as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, parentMesh),
         216usize,
         concat!(
             "Offset of field: ",
@@ -6813,7 +6677,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).edgesCount as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, edgesCount),
         224usize,
         concat!(
             "Offset of field: ",
@@ -6823,7 +6687,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).edges as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, edges),
         232usize,
         concat!(
             "Offset of field: ",
@@ -6833,7 +6697,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).width as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, width),
         240usize,
         concat!(
             "Offset of field: ",
@@ -6843,7 +6707,7 @@ fn bindgen_test_layout_spMeshAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spMeshAttachment>())).height as *const _ as usize },
+        ::std::mem::offset_of!(spMeshAttachment, height),
         244usize,
         concat!(
             "Offset of field: ",
@@ -6886,7 +6750,7 @@ fn bindgen_test_layout_spBoundingBoxAttachment() {
         concat!("Alignment of ", stringify!(spBoundingBoxAttachment))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spBoundingBoxAttachment>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spBoundingBoxAttachment, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6920,7 +6784,7 @@ fn bindgen_test_layout_spClippingAttachment() {
         concat!("Alignment of ", stringify!(spClippingAttachment))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spClippingAttachment>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spClippingAttachment, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6930,7 +6794,7 @@ fn bindgen_test_layout_spClippingAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spClippingAttachment>())).endSlot as *const _ as usize },
+        ::std::mem::offset_of!(spClippingAttachment, endSlot),
         96usize,
         concat!(
             "Offset of field: ",
@@ -6970,7 +6834,7 @@ fn bindgen_test_layout_spPointAttachment() {
         concat!("Alignment of ", stringify!(spPointAttachment))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPointAttachment>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spPointAttachment, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6980,7 +6844,7 @@ fn bindgen_test_layout_spPointAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPointAttachment>())).x as *const _ as usize },
+        ::std::mem::offset_of!(spPointAttachment, x),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6990,7 +6854,7 @@ fn bindgen_test_layout_spPointAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPointAttachment>())).y as *const _ as usize },
+        ::std::mem::offset_of!(spPointAttachment, y),
         44usize,
         concat!(
             "Offset of field: ",
@@ -7000,7 +6864,7 @@ fn bindgen_test_layout_spPointAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPointAttachment>())).rotation as *const _ as usize },
+        ::std::mem::offset_of!(spPointAttachment, rotation),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7010,7 +6874,7 @@ fn bindgen_test_layout_spPointAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPointAttachment>())).color as *const _ as usize },
+        ::std::mem::offset_of!(spPointAttachment, color),
         52usize,
         concat!(
             "Offset of field: ",
@@ -7064,7 +6928,7 @@ fn bindgen_test_layout_spIkConstraint() {
         concat!("Alignment of ", stringify!(spIkConstraint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraint>())).data as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraint, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7074,7 +6938,7 @@ fn bindgen_test_layout_spIkConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraint>())).bonesCount as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraint, bonesCount),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7084,7 +6948,7 @@ fn bindgen_test_layout_spIkConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraint>())).bones as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraint, bones),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7094,7 +6958,7 @@ fn bindgen_test_layout_spIkConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraint>())).target as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraint, target),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7104,7 +6968,7 @@ fn bindgen_test_layout_spIkConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraint>())).bendDirection as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraint, bendDirection),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7114,7 +6978,7 @@ fn bindgen_test_layout_spIkConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraint>())).compress as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraint, compress),
         36usize,
         concat!(
             "Offset of field: ",
@@ -7124,7 +6988,7 @@ fn bindgen_test_layout_spIkConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraint>())).stretch as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraint, stretch),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7134,7 +6998,7 @@ fn bindgen_test_layout_spIkConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraint>())).mix as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraint, mix),
         44usize,
         concat!(
             "Offset of field: ",
@@ -7144,7 +7008,7 @@ fn bindgen_test_layout_spIkConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraint>())).softness as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraint, softness),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7154,7 +7018,7 @@ fn bindgen_test_layout_spIkConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spIkConstraint>())).active as *const _ as usize },
+        ::std::mem::offset_of!(spIkConstraint, active),
         52usize,
         concat!(
             "Offset of field: ",
@@ -7225,7 +7089,7 @@ fn bindgen_test_layout_spTransformConstraint() {
         concat!("Alignment of ", stringify!(spTransformConstraint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraint>())).data as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraint, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7235,9 +7099,7 @@ fn bindgen_test_layout_spTransformConstraint() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraint>())).bonesCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraint, bonesCount),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7247,7 +7109,7 @@ fn bindgen_test_layout_spTransformConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraint>())).bones as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraint, bones),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7257,7 +7119,7 @@ fn bindgen_test_layout_spTransformConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraint>())).target as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraint, target),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7267,7 +7129,7 @@ fn bindgen_test_layout_spTransformConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraint>())).rotateMix as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraint, rotateMix),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7277,9 +7139,7 @@ fn bindgen_test_layout_spTransformConstraint() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTransformConstraint>())).translateMix as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTransformConstraint, translateMix),
         36usize,
         concat!(
             "Offset of field: ",
@@ -7289,7 +7149,7 @@ fn bindgen_test_layout_spTransformConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraint>())).scaleMix as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraint, scaleMix),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7299,7 +7159,7 @@ fn bindgen_test_layout_spTransformConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraint>())).shearMix as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraint, shearMix),
         44usize,
         concat!(
             "Offset of field: ",
@@ -7309,7 +7169,7 @@ fn bindgen_test_layout_spTransformConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTransformConstraint>())).active as *const _ as usize },
+        ::std::mem::offset_of!(spTransformConstraint, active),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7353,7 +7213,7 @@ fn bindgen_test_layout_spPathAttachment() {
         concat!("Alignment of ", stringify!(spPathAttachment))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathAttachment>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spPathAttachment, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7363,7 +7223,7 @@ fn bindgen_test_layout_spPathAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathAttachment>())).lengthsLength as *const _ as usize },
+        ::std::mem::offset_of!(spPathAttachment, lengthsLength),
         96usize,
         concat!(
             "Offset of field: ",
@@ -7373,7 +7233,7 @@ fn bindgen_test_layout_spPathAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathAttachment>())).lengths as *const _ as usize },
+        ::std::mem::offset_of!(spPathAttachment, lengths),
         104usize,
         concat!(
             "Offset of field: ",
@@ -7383,7 +7243,7 @@ fn bindgen_test_layout_spPathAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathAttachment>())).closed as *const _ as usize },
+        ::std::mem::offset_of!(spPathAttachment, closed),
         112usize,
         concat!(
             "Offset of field: ",
@@ -7393,7 +7253,7 @@ fn bindgen_test_layout_spPathAttachment() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathAttachment>())).constantSpeed as *const _ as usize },
+        ::std::mem::offset_of!(spPathAttachment, constantSpeed),
         116usize,
         concat!(
             "Offset of field: ",
@@ -7443,7 +7303,7 @@ fn bindgen_test_layout_spPathConstraint() {
         concat!("Alignment of ", stringify!(spPathConstraint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).data as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7453,7 +7313,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).bonesCount as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, bonesCount),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7463,7 +7323,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).bones as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, bones),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7473,7 +7333,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).target as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, target),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7483,7 +7343,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).position as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, position),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7493,7 +7353,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).spacing as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, spacing),
         36usize,
         concat!(
             "Offset of field: ",
@@ -7503,7 +7363,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).rotateMix as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, rotateMix),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7513,7 +7373,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).translateMix as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, translateMix),
         44usize,
         concat!(
             "Offset of field: ",
@@ -7523,7 +7383,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).spacesCount as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, spacesCount),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7533,7 +7393,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).spaces as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, spaces),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7543,7 +7403,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).positionsCount as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, positionsCount),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7553,7 +7413,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).positions as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, positions),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7563,7 +7423,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).worldCount as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, worldCount),
         80usize,
         concat!(
             "Offset of field: ",
@@ -7573,7 +7433,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).world as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, world),
         88usize,
         concat!(
             "Offset of field: ",
@@ -7583,7 +7443,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).curvesCount as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, curvesCount),
         96usize,
         concat!(
             "Offset of field: ",
@@ -7593,7 +7453,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).curves as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, curves),
         104usize,
         concat!(
             "Offset of field: ",
@@ -7603,7 +7463,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).lengthsCount as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, lengthsCount),
         112usize,
         concat!(
             "Offset of field: ",
@@ -7613,7 +7473,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).lengths as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, lengths),
         120usize,
         concat!(
             "Offset of field: ",
@@ -7623,7 +7483,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).segments as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, segments),
         128usize,
         concat!(
             "Offset of field: ",
@@ -7633,7 +7493,7 @@ fn bindgen_test_layout_spPathConstraint() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPathConstraint>())).active as *const _ as usize },
+        ::std::mem::offset_of!(spPathConstraint, active),
         168usize,
         concat!(
             "Offset of field: ",
@@ -7702,7 +7562,7 @@ fn bindgen_test_layout_spSkeleton() {
         concat!("Alignment of ", stringify!(spSkeleton))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).data as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7712,7 +7572,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).bonesCount as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, bonesCount),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7722,7 +7582,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).bones as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, bones),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7732,7 +7592,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).root as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, root),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7742,7 +7602,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).slotsCount as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, slotsCount),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7752,7 +7612,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).slots as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, slots),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7762,7 +7622,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).drawOrder as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, drawOrder),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7772,7 +7632,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).ikConstraintsCount as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, ikConstraintsCount),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7782,7 +7642,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).ikConstraints as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, ikConstraints),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7792,9 +7652,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeleton>())).transformConstraintsCount as *const _ as usize
-        },
+        ::std::mem::offset_of!(spSkeleton, transformConstraintsCount),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7804,7 +7662,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).transformConstraints as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, transformConstraints),
         80usize,
         concat!(
             "Offset of field: ",
@@ -7814,7 +7672,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).pathConstraintsCount as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, pathConstraintsCount),
         88usize,
         concat!(
             "Offset of field: ",
@@ -7824,7 +7682,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).pathConstraints as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, pathConstraints),
         96usize,
         concat!(
             "Offset of field: ",
@@ -7834,7 +7692,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).skin as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, skin),
         104usize,
         concat!(
             "Offset of field: ",
@@ -7844,7 +7702,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).color as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, color),
         112usize,
         concat!(
             "Offset of field: ",
@@ -7854,7 +7712,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).time as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, time),
         128usize,
         concat!(
             "Offset of field: ",
@@ -7864,7 +7722,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).scaleX as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, scaleX),
         132usize,
         concat!(
             "Offset of field: ",
@@ -7874,7 +7732,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).scaleY as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, scaleY),
         136usize,
         concat!(
             "Offset of field: ",
@@ -7884,7 +7742,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).x as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, x),
         140usize,
         concat!(
             "Offset of field: ",
@@ -7894,7 +7752,7 @@ fn bindgen_test_layout_spSkeleton() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeleton>())).y as *const _ as usize },
+        ::std::mem::offset_of!(spSkeleton, y),
         144usize,
         concat!(
             "Offset of field: ",
@@ -8020,7 +7878,7 @@ fn bindgen_test_layout_spPolygon() {
         concat!("Alignment of ", stringify!(spPolygon))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPolygon>())).vertices as *const _ as usize },
+        ::std::mem::offset_of!(spPolygon, vertices),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8030,7 +7888,7 @@ fn bindgen_test_layout_spPolygon() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPolygon>())).count as *const _ as usize },
+        ::std::mem::offset_of!(spPolygon, count),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8040,7 +7898,7 @@ fn bindgen_test_layout_spPolygon() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spPolygon>())).capacity as *const _ as usize },
+        ::std::mem::offset_of!(spPolygon, capacity),
         12usize,
         concat!(
             "Offset of field: ",
@@ -8096,7 +7954,7 @@ fn bindgen_test_layout_spSkeletonBounds() {
         concat!("Alignment of ", stringify!(spSkeletonBounds))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonBounds>())).count as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonBounds, count),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8106,7 +7964,7 @@ fn bindgen_test_layout_spSkeletonBounds() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonBounds>())).boundingBoxes as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonBounds, boundingBoxes),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8116,7 +7974,7 @@ fn bindgen_test_layout_spSkeletonBounds() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonBounds>())).polygons as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonBounds, polygons),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8126,7 +7984,7 @@ fn bindgen_test_layout_spSkeletonBounds() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonBounds>())).minX as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonBounds, minX),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8136,7 +7994,7 @@ fn bindgen_test_layout_spSkeletonBounds() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonBounds>())).minY as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonBounds, minY),
         28usize,
         concat!(
             "Offset of field: ",
@@ -8146,7 +8004,7 @@ fn bindgen_test_layout_spSkeletonBounds() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonBounds>())).maxX as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonBounds, maxX),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8156,7 +8014,7 @@ fn bindgen_test_layout_spSkeletonBounds() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonBounds>())).maxY as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonBounds, maxY),
         36usize,
         concat!(
             "Offset of field: ",
@@ -8251,7 +8109,7 @@ fn bindgen_test_layout_spSkeletonBinary() {
         concat!("Alignment of ", stringify!(spSkeletonBinary))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonBinary>())).scale as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonBinary, scale),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8261,9 +8119,7 @@ fn bindgen_test_layout_spSkeletonBinary() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeletonBinary>())).attachmentLoader as *const _ as usize
-        },
+        ::std::mem::offset_of!(spSkeletonBinary, attachmentLoader),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8273,7 +8129,7 @@ fn bindgen_test_layout_spSkeletonBinary() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonBinary>())).error as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonBinary, error),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8327,7 +8183,7 @@ fn bindgen_test_layout_spSkeletonJson() {
         concat!("Alignment of ", stringify!(spSkeletonJson))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonJson>())).scale as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonJson, scale),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8337,7 +8193,7 @@ fn bindgen_test_layout_spSkeletonJson() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonJson>())).attachmentLoader as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonJson, attachmentLoader),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8347,7 +8203,7 @@ fn bindgen_test_layout_spSkeletonJson() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonJson>())).error as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonJson, error),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8404,7 +8260,7 @@ fn bindgen_test_layout_spTriangulator() {
         concat!("Alignment of ", stringify!(spTriangulator))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTriangulator>())).convexPolygons as *const _ as usize },
+        ::std::mem::offset_of!(spTriangulator, convexPolygons),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8414,9 +8270,7 @@ fn bindgen_test_layout_spTriangulator() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTriangulator>())).convexPolygonsIndices as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTriangulator, convexPolygonsIndices),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8426,7 +8280,7 @@ fn bindgen_test_layout_spTriangulator() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTriangulator>())).indicesArray as *const _ as usize },
+        ::std::mem::offset_of!(spTriangulator, indicesArray),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8436,7 +8290,7 @@ fn bindgen_test_layout_spTriangulator() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTriangulator>())).isConcaveArray as *const _ as usize },
+        ::std::mem::offset_of!(spTriangulator, isConcaveArray),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8446,7 +8300,7 @@ fn bindgen_test_layout_spTriangulator() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTriangulator>())).triangles as *const _ as usize },
+        ::std::mem::offset_of!(spTriangulator, triangles),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8456,7 +8310,7 @@ fn bindgen_test_layout_spTriangulator() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spTriangulator>())).polygonPool as *const _ as usize },
+        ::std::mem::offset_of!(spTriangulator, polygonPool),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8466,9 +8320,7 @@ fn bindgen_test_layout_spTriangulator() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spTriangulator>())).polygonIndicesPool as *const _ as usize
-        },
+        ::std::mem::offset_of!(spTriangulator, polygonIndicesPool),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8523,7 +8375,7 @@ fn bindgen_test_layout_spSkeletonClipping() {
         concat!("Alignment of ", stringify!(spSkeletonClipping))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonClipping>())).triangulator as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonClipping, triangulator),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8533,9 +8385,7 @@ fn bindgen_test_layout_spSkeletonClipping() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeletonClipping>())).clippingPolygon as *const _ as usize
-        },
+        ::std::mem::offset_of!(spSkeletonClipping, clippingPolygon),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8545,7 +8395,7 @@ fn bindgen_test_layout_spSkeletonClipping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonClipping>())).clipOutput as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonClipping, clipOutput),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8555,9 +8405,7 @@ fn bindgen_test_layout_spSkeletonClipping() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeletonClipping>())).clippedVertices as *const _ as usize
-        },
+        ::std::mem::offset_of!(spSkeletonClipping, clippedVertices),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8567,7 +8415,7 @@ fn bindgen_test_layout_spSkeletonClipping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonClipping>())).clippedUVs as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonClipping, clippedUVs),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8577,9 +8425,7 @@ fn bindgen_test_layout_spSkeletonClipping() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeletonClipping>())).clippedTriangles as *const _ as usize
-        },
+        ::std::mem::offset_of!(spSkeletonClipping, clippedTriangles),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8589,7 +8435,7 @@ fn bindgen_test_layout_spSkeletonClipping() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSkeletonClipping>())).scratch as *const _ as usize },
+        ::std::mem::offset_of!(spSkeletonClipping, scratch),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8599,9 +8445,7 @@ fn bindgen_test_layout_spSkeletonClipping() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeletonClipping>())).clipAttachment as *const _ as usize
-        },
+        ::std::mem::offset_of!(spSkeletonClipping, clipAttachment),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8611,9 +8455,7 @@ fn bindgen_test_layout_spSkeletonClipping() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<spSkeletonClipping>())).clippingPolygons as *const _ as usize
-        },
+        ::std::mem::offset_of!(spSkeletonClipping, clippingPolygons),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8692,7 +8534,7 @@ fn bindgen_test_layout_spVertexEffect() {
         concat!("Alignment of ", stringify!(spVertexEffect))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spVertexEffect>())).begin as *const _ as usize },
+        ::std::mem::offset_of!(spVertexEffect, begin),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8702,7 +8544,7 @@ fn bindgen_test_layout_spVertexEffect() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spVertexEffect>())).transform as *const _ as usize },
+        ::std::mem::offset_of!(spVertexEffect, transform),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8712,7 +8554,7 @@ fn bindgen_test_layout_spVertexEffect() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spVertexEffect>())).end as *const _ as usize },
+        ::std::mem::offset_of!(spVertexEffect, end),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8742,7 +8584,7 @@ fn bindgen_test_layout_spJitterVertexEffect() {
         concat!("Alignment of ", stringify!(spJitterVertexEffect))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spJitterVertexEffect>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spJitterVertexEffect, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8752,7 +8594,7 @@ fn bindgen_test_layout_spJitterVertexEffect() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spJitterVertexEffect>())).jitterX as *const _ as usize },
+        ::std::mem::offset_of!(spJitterVertexEffect, jitterX),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8762,7 +8604,7 @@ fn bindgen_test_layout_spJitterVertexEffect() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spJitterVertexEffect>())).jitterY as *const _ as usize },
+        ::std::mem::offset_of!(spJitterVertexEffect, jitterY),
         28usize,
         concat!(
             "Offset of field: ",
@@ -8796,7 +8638,7 @@ fn bindgen_test_layout_spSwirlVertexEffect() {
         concat!("Alignment of ", stringify!(spSwirlVertexEffect))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSwirlVertexEffect>())).super_ as *const _ as usize },
+        ::std::mem::offset_of!(spSwirlVertexEffect, super_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8806,7 +8648,7 @@ fn bindgen_test_layout_spSwirlVertexEffect() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSwirlVertexEffect>())).centerX as *const _ as usize },
+        ::std::mem::offset_of!(spSwirlVertexEffect, centerX),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8816,7 +8658,7 @@ fn bindgen_test_layout_spSwirlVertexEffect() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSwirlVertexEffect>())).centerY as *const _ as usize },
+        ::std::mem::offset_of!(spSwirlVertexEffect, centerY),
         28usize,
         concat!(
             "Offset of field: ",
@@ -8826,7 +8668,7 @@ fn bindgen_test_layout_spSwirlVertexEffect() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSwirlVertexEffect>())).radius as *const _ as usize },
+        ::std::mem::offset_of!(spSwirlVertexEffect, radius),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8836,7 +8678,7 @@ fn bindgen_test_layout_spSwirlVertexEffect() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSwirlVertexEffect>())).angle as *const _ as usize },
+        ::std::mem::offset_of!(spSwirlVertexEffect, angle),
         36usize,
         concat!(
             "Offset of field: ",
@@ -8846,7 +8688,7 @@ fn bindgen_test_layout_spSwirlVertexEffect() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSwirlVertexEffect>())).worldX as *const _ as usize },
+        ::std::mem::offset_of!(spSwirlVertexEffect, worldX),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8856,7 +8698,7 @@ fn bindgen_test_layout_spSwirlVertexEffect() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<spSwirlVertexEffect>())).worldY as *const _ as usize },
+        ::std::mem::offset_of!(spSwirlVertexEffect, worldY),
         44usize,
         concat!(
             "Offset of field: ",
@@ -1,44 +1,59 @@
 use std::{
+    marker::PhantomData,
     ops::{Deref, DerefMut},
     sync::Arc,
 };
 
+/// The shared allocation behind a `SpineMutPtr`, disposing the spine-c object once the last clone
+/// is dropped.
+struct Owned<T> {
+    ptr: *mut T,
+    drop_fn: Option<unsafe extern "C" fn(*mut T)>,
+}
+impl<T> Drop for Owned<T> {
+    fn drop(&mut self) {
+        if let Some(f) = self.drop_fn.take() {
+            unsafe { f(self.ptr) }
+        }
+    }
+}
+
+// SAFETY: `Owned` only hands out the raw pointer and disposes it exactly once, on whichever thread
+// drops the last clone. `SpineMutPtr` itself stays `!Send + !Sync`, so whether the pointee may be
+// used from several threads is decided by each public wrapper type individually.
+unsafe impl<T> Send for Owned<T> {}
+unsafe impl<T> Sync for Owned<T> {}
+
 #[derive(Clone)]
 pub(crate) struct SpineMutPtr<T> {
-    ptr: Arc<*mut T>,
-    drop_fn: Option<unsafe extern "C" fn(*mut T)>,
+    ptr: Arc<Owned<T>>,
+    _not_send: PhantomData<*mut T>,
 }
 impl<T> SpineMutPtr<T> {
     pub(crate) fn new(ptr: *mut T, drop_fn: Option<unsafe extern "C" fn(*mut T)>) -> Self {
         Self {
-            ptr: Arc::new(ptr),
-            drop_fn,
+            ptr: Arc::new(Owned { ptr, drop_fn }),
+            _not_send: PhantomData,
         }
     }
 
     pub(crate) fn as_ref(&self) -> &T {
-        unsafe { (*self.ptr).as_ref().unwrap() }
+        unsafe { self.ptr.ptr.as_ref().unwrap() }
     }
     pub(crate) fn as_mut(&mut self) -> &mut T {
-        unsafe { (*self.ptr).as_mut().unwrap() }
+        unsafe { self.ptr.ptr.as_mut().unwrap() }
+    }
+
+    /// The pointee, if no other clone of this pointer exists, like `Arc::get_mut`.
+    pub(crate) fn get_mut(&mut self) -> Option<&mut T> {
+        Arc::get_mut(&mut self.ptr).map(|owned| unsafe { &mut *owned.ptr })
     }
 
     pub(crate) fn as_ptr(&self) -> *const T {
-        *self.ptr as *const _
+        self.ptr.ptr.cast_const()
     }
     pub(crate) fn as_mut_ptr(&self) -> *mut T {
-        *self.ptr
-    }
-}
-impl<T> Drop for SpineMutPtr<T> {
-    fn drop(&mut self) {
-        if let Some(ptr) = Arc::get_mut(&mut self.ptr) {
-            unsafe {
-                if let Some(f) = self.drop_fn.take() {
-                    f(*ptr)
-                }
-            }
-        }
+        self.ptr.ptr
     }
 }
 impl<T> Deref for SpineMutPtr<T> {
@@ -55,31 +70,45 @@ impl<T> DerefMut for SpineMutPtr<T> {
 }
 impl<T> PartialEq for SpineMutPtr<T> {
     fn eq(&self, rhv: &Self) -> bool {
-        self.ptr == rhv.ptr
+        self.ptr.ptr == rhv.ptr.ptr
     }
 }
 
-#[derive(Clone, PartialEq)]
-pub(crate) struct SpinePtr<T, P> {
-    ptr: *const T,
-    parent: SpineMutPtr<P>,
-}
-impl<T, P> SpinePtr<T, P> {
-    pub(crate) fn new(ptr: *mut T, parent: SpineMutPtr<P>) -> Self {
-        Self { ptr, parent }
-    }
+#[cfg(test)]
+mod tests {
+    use super::SpineMutPtr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
-    pub(crate) fn as_ref(&self) -> &T {
-        unsafe { self.ptr.as_ref().unwrap() }
-    }
-    pub(crate) fn as_ptr(&self) -> *const T {
-        self.ptr as *const _
+    // These tests only use Rust allocations so they can run under Miri.
+    static DISPOSED: AtomicUsize = AtomicUsize::new(0);
+
+    unsafe extern "C" fn dispose(ptr: *mut u32) {
+        drop(Box::from_raw(ptr));
+        DISPOSED.fetch_add(1, Ordering::SeqCst);
     }
-}
-impl<T, P> Deref for SpinePtr<T, P> {
-    type Target = T;
 
-    fn deref(&self) -> &Self::Target {
-        self.as_ref()
+    struct SendPtr(SpineMutPtr<u32>);
+    unsafe impl Send for SendPtr {}
+
+    #[test]
+    fn disposed_once_across_threads() {
+        let ptr = SpineMutPtr::new(Box::into_raw(Box::new(7)), Some(dispose));
+
+        let handles = (0..4)
+            .map(|_| {
+                let clone = SendPtr(ptr.clone());
+                std::thread::spawn(move || {
+                    let clone = clone;
+                    assert_eq!(7, *clone.0);
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(0, DISPOSED.load(Ordering::SeqCst));
+        drop(ptr);
+        assert_eq!(1, DISPOSED.load(Ordering::SeqCst));
     }
 }
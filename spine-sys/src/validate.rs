@@ -0,0 +1,625 @@
+use crate::{
+    atlas::Atlas,
+    enums::AttachmentType,
+    ffi,
+    skeleton::{SkeletonData, Skin},
+    SpineError, SpineMutPtr,
+};
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    convert::TryInto,
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    path::Path,
+};
+
+/// How serious a `Diagnostic` is.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The asset is likely to be wasteful or not what the artist intended.
+    Warning,
+    /// The asset does not load, or renders incorrectly.
+    Error,
+}
+
+/// A problem found by `Validator::validate`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Diagnostic {
+    /// A region or mesh attachment refers to a region that is not in the atlas.
+    MissingRegion {
+        skin: String,
+        attachment: String,
+        path: String,
+    },
+    /// An atlas region that no attachment refers to.
+    UnusedRegion { name: String },
+    /// A slot without an attachment in any skin.
+    UnusedSlot { name: String },
+    /// A bone that no slot, constraint or mesh weight depends on, directly or through its children.
+    UnusedBone { name: String },
+    /// An animation without timelines, or with a duration of 0.
+    EmptyAnimation { name: String },
+    /// An event that no animation keys.
+    UnkeyedEvent { name: String },
+    /// The skeleton was exported from a Spine editor version this runtime does not target.
+    VersionMismatch {
+        found: Option<String>,
+        expected: String,
+    },
+    /// An atlas page larger than the configured maximum texture size.
+    OversizedPage {
+        name: String,
+        width: i32,
+        height: i32,
+        max: i32,
+    },
+}
+impl Diagnostic {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::MissingRegion { .. } | Self::VersionMismatch { .. } => Severity::Error,
+            _ => Severity::Warning,
+        }
+    }
+
+    /// A stable, kebab-case identifier for the kind of problem.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::MissingRegion { .. } => "missing-region",
+            Self::UnusedRegion { .. } => "unused-region",
+            Self::UnusedSlot { .. } => "unused-slot",
+            Self::UnusedBone { .. } => "unused-bone",
+            Self::EmptyAnimation { .. } => "empty-animation",
+            Self::UnkeyedEvent { .. } => "unkeyed-event",
+            Self::VersionMismatch { .. } => "version-mismatch",
+            Self::OversizedPage { .. } => "oversized-page",
+        }
+    }
+}
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::MissingRegion {
+                skin,
+                attachment,
+                path,
+            } => write!(
+                f,
+                "attachment {attachment:?} of skin {skin:?} refers to missing region {path:?}"
+            ),
+            Self::UnusedRegion { name } => write!(f, "region {name:?} is not used"),
+            Self::UnusedSlot { name } => write!(f, "slot {name:?} has no attachments"),
+            Self::UnusedBone { name } => write!(f, "bone {name:?} is not used"),
+            Self::EmptyAnimation { name } => write!(f, "animation {name:?} is empty"),
+            Self::UnkeyedEvent { name } => write!(f, "event {name:?} is never keyed"),
+            Self::VersionMismatch { found, expected } => write!(
+                f,
+                "exported from Spine {}, expected {expected}",
+                found.as_deref().unwrap_or("unknown")
+            ),
+            Self::OversizedPage {
+                name,
+                width,
+                height,
+                max,
+            } => write!(
+                f,
+                "page {name:?} is {width}x{height}, larger than the maximum of {max}"
+            ),
+        }
+    }
+}
+
+type CreateAttachment = unsafe extern "C" fn(
+    *mut ffi::spAttachmentLoader,
+    *mut ffi::spSkin,
+    ffi::spAttachmentType,
+    *const c_char,
+    *const c_char,
+) -> *mut ffi::spAttachment;
+
+/// Mirrors spine-c's private `_spAttachmentLoaderVtable`.
+#[repr(C)]
+struct AttachmentLoaderVtable {
+    create_attachment: CreateAttachment,
+}
+
+/// What the attachment loader saw while loading a skeleton on this thread.
+struct Loading {
+    create_attachment: CreateAttachment,
+    atlas: *mut ffi::spAtlas,
+    used_regions: HashSet<String>,
+    missing: Vec<Diagnostic>,
+}
+
+thread_local! {
+    static LOADING: RefCell<Option<Loading>> = const { RefCell::new(None) };
+}
+
+fn to_string(s: *const c_char) -> String {
+    if s.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned()
+    }
+}
+
+/// Wraps the atlas attachment loader, recording used regions and creating attachments without a
+/// region instead of failing the load when a region is missing.
+unsafe extern "C" fn create_attachment(
+    loader: *mut ffi::spAttachmentLoader,
+    skin: *mut ffi::spSkin,
+    kind: ffi::spAttachmentType,
+    name: *const c_char,
+    path: *const c_char,
+) -> *mut ffi::spAttachment {
+    let needs_region = matches!(
+        AttachmentType::from(kind),
+        AttachmentType::Region | AttachmentType::Mesh | AttachmentType::LinkedMesh
+    );
+
+    let create = LOADING.with(|loading| {
+        let mut loading = loading.borrow_mut();
+        let loading = loading.as_mut()?;
+        if needs_region {
+            if ffi::spAtlas_findRegion(loading.atlas, path).is_null() {
+                loading.missing.push(Diagnostic::MissingRegion {
+                    skin: to_string((*skin).name),
+                    attachment: to_string(name),
+                    path: to_string(path),
+                });
+                return None;
+            }
+            loading.used_regions.insert(to_string(path));
+        }
+        Some(loading.create_attachment)
+    });
+
+    match create {
+        Some(create) => create(loader, skin, kind, name, path),
+        None if AttachmentType::from(kind) == AttachmentType::Region => {
+            ffi::spRegionAttachment_create(name).cast()
+        }
+        None => ffi::spMeshAttachment_create(name).cast(),
+    }
+}
+
+/// Loads `path` with `atlas`, returning the skeleton data, the regions its attachments use and
+/// its missing regions.
+fn load(
+    atlas: &Atlas,
+    path: &Path,
+) -> Result<(SkeletonData, HashSet<String>, Vec<Diagnostic>), SpineError> {
+    let path_c = path
+        .to_str()
+        .and_then(|path| CString::new(path).ok())
+        .ok_or_else(|| {
+            SpineError::FailLoadSkeleton("Failed to convert path to string".to_owned())
+        })?;
+    let is_json = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+
+    let _guard = crate::lock_global_state();
+    unsafe {
+        let loader = ffi::spAtlasAttachmentLoader_create(atlas.inner.as_mut_ptr());
+        let vtable = (*loader)
+            .super_
+            .vtable
+            .cast_mut()
+            .cast::<AttachmentLoaderVtable>();
+        LOADING.with(|loading| {
+            *loading.borrow_mut() = Some(Loading {
+                create_attachment: (*vtable).create_attachment,
+                atlas: atlas.inner.as_mut_ptr(),
+                used_regions: HashSet::new(),
+                missing: Vec::new(),
+            });
+        });
+        (*vtable).create_attachment = create_attachment;
+
+        let (data, error) = if is_json {
+            let json = ffi::spSkeletonJson_createWithLoader(loader.cast());
+            let data = ffi::spSkeletonJson_readSkeletonDataFile(json, path_c.as_ptr());
+            let error = to_string((*json).error);
+            ffi::spSkeletonJson_dispose(json);
+            (data, error)
+        } else {
+            let binary = ffi::spSkeletonBinary_createWithLoader(loader.cast());
+            let data = ffi::spSkeletonBinary_readSkeletonDataFile(binary, path_c.as_ptr());
+            let error = to_string((*binary).error);
+            ffi::spSkeletonBinary_dispose(binary);
+            (data, error)
+        };
+        ffi::spAttachmentLoader_dispose(loader.cast());
+        let loading = LOADING.with(|loading| loading.borrow_mut().take()).unwrap();
+
+        if data.is_null() {
+            return Err(SpineError::FailLoadSkeleton(error));
+        }
+        let skeleton_data = SkeletonData {
+            inner: SpineMutPtr::new(data, Some(ffi::spSkeletonData_dispose)),
+            atlas: atlas.inner.clone(),
+        };
+        Ok((skeleton_data, loading.used_regions, loading.missing))
+    }
+}
+
+/// Cross-checks a skeleton against its atlas, collecting every problem instead of stopping at the
+/// first one.
+///
+/// ```no_run
+/// # use spine_sys::{atlas::Atlas, validate::Validator};
+/// let atlas = Atlas::from_file("dragon.atlas", |_, _| 0).unwrap();
+/// for diagnostic in Validator::new().validate(&atlas, "dragon-ess.skel").unwrap() {
+///     println!("{:?}: {}", diagnostic.severity(), diagnostic);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Validator {
+    max_page_size: i32,
+    expected_version: String,
+}
+impl Default for Validator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Validator {
+    /// Creates a validator expecting Spine 3.8 exports and pages of at most 4096x4096.
+    pub fn new() -> Self {
+        Self {
+            max_page_size: 4096,
+            expected_version: "3.8".to_owned(),
+        }
+    }
+
+    pub fn max_page_size(&self) -> i32 {
+        self.max_page_size
+    }
+
+    /// Sets the largest width or height an atlas page may have, usually the maximum texture size
+    /// of the target hardware.
+    pub fn set_max_page_size(&mut self, max_page_size: i32) {
+        self.max_page_size = max_page_size;
+    }
+
+    pub fn expected_version(&self) -> &str {
+        &self.expected_version
+    }
+
+    /// Sets the version prefix, like `3.8`, that the skeleton's editor version must start with.
+    pub fn set_expected_version(&mut self, expected_version: &str) {
+        expected_version.clone_into(&mut self.expected_version);
+    }
+
+    /// Loads the skeleton at `path`, a `.json` or binary export, with `atlas` and reports every
+    /// problem found. Attachments referring to missing regions are reported rather than failing
+    /// the load.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadSkeleton` if the skeleton cannot be loaded for any other
+    /// reason, such as a malformed file.
+    pub fn validate<P>(&self, atlas: &Atlas, path: P) -> Result<Vec<Diagnostic>, SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let (skeleton_data, used_regions, mut diagnostics) = load(atlas, path.as_ref())?;
+
+        let version = skeleton_data.version();
+        if !version.is_some_and(|version| version.starts_with(&self.expected_version)) {
+            diagnostics.push(Diagnostic::VersionMismatch {
+                found: version.map(str::to_owned),
+                expected: self.expected_version.clone(),
+            });
+        }
+
+        for page in atlas.pages() {
+            let (width, height) = page.dimensions();
+            if width > self.max_page_size || height > self.max_page_size {
+                diagnostics.push(Diagnostic::OversizedPage {
+                    name: page.name().to_owned(),
+                    width,
+                    height,
+                    max: self.max_page_size,
+                });
+            }
+        }
+        for region in atlas.regions() {
+            if !used_regions.contains(region.name()) {
+                diagnostics.push(Diagnostic::UnusedRegion {
+                    name: region.name().to_owned(),
+                });
+            }
+        }
+
+        diagnostics.extend(unused_slots_and_bones(&skeleton_data));
+        diagnostics.extend(animation_diagnostics(&skeleton_data));
+        Ok(diagnostics)
+    }
+}
+
+// Bone and slot indices from spine-c are never negative.
+#[allow(clippy::cast_sign_loss)]
+fn unused_slots_and_bones(skeleton_data: &SkeletonData) -> Vec<Diagnostic> {
+    let bones = skeleton_data.bones();
+    let slots = skeleton_data.slots();
+    let mut used_bones = vec![false; bones.len()];
+    let mut used_slots = vec![false; slots.len()];
+
+    let mut use_bone = |index: i32| used_bones[index as usize] = true;
+    for slot in &slots {
+        use_bone(slot.bone().index().0);
+    }
+    for ik in skeleton_data.ik_constraints() {
+        ik.bones().iter().for_each(|bone| use_bone(bone.index().0));
+        use_bone(ik.target().index().0);
+    }
+    for transform in skeleton_data.transform_constraints() {
+        transform
+            .bones()
+            .iter()
+            .for_each(|bone| use_bone(bone.index().0));
+        use_bone(transform.target().index().0);
+    }
+    for path in skeleton_data.path_constraints() {
+        path.bones()
+            .iter()
+            .for_each(|bone| use_bone(bone.index().0));
+        use_bone(path.target().bone().index().0);
+    }
+
+    for skin in skeleton_data.skins().iter().map(Skin::attachments) {
+        for entry in skin {
+            used_slots[entry.slot.index().0 as usize] = true;
+
+            let vertex_attachment = match entry.attachment.kind() {
+                AttachmentType::Region | AttachmentType::Point => continue,
+                _ => unsafe { &*entry.attachment.inner.cast::<ffi::spVertexAttachment>() },
+            };
+            // Weighted vertices store, per vertex, a bone count followed by that many bone indices.
+            let weights: &[i32] = if vertex_attachment.bones.is_null() {
+                &[]
+            } else {
+                unsafe {
+                    std::slice::from_raw_parts(
+                        vertex_attachment.bones,
+                        vertex_attachment.bonesCount.try_into().unwrap(),
+                    )
+                }
+            };
+            let mut n = 0;
+            while n < weights.len() {
+                let count = weights[n] as usize;
+                weights[n + 1..=n + count]
+                    .iter()
+                    .for_each(|&bone| use_bone(bone));
+                n += count + 1;
+            }
+        }
+    }
+
+    // A bone is needed by the bones and slots below it, and the root is always needed.
+    for (index, bone) in bones.iter().enumerate().rev() {
+        if used_bones[index] || index == 0 {
+            used_bones[index] = true;
+            if let Some(parent) = bone.parent() {
+                used_bones[parent.index().0 as usize] = true;
+            }
+        }
+    }
+
+    let mut diagnostics = Vec::new();
+    for (slot, used) in slots.iter().zip(used_slots) {
+        if !used {
+            diagnostics.push(Diagnostic::UnusedSlot {
+                name: slot.name().to_owned(),
+            });
+        }
+    }
+    for (bone, used) in bones.iter().zip(used_bones) {
+        if !used {
+            diagnostics.push(Diagnostic::UnusedBone {
+                name: bone.name().to_owned(),
+            });
+        }
+    }
+    diagnostics
+}
+
+fn animation_diagnostics(skeleton_data: &SkeletonData) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut keyed_events = HashSet::new();
+
+    for animation in skeleton_data.animations() {
+        let inner = unsafe { &*animation.inner };
+        if inner.timelinesCount == 0 || inner.duration <= 0.0 {
+            diagnostics.push(Diagnostic::EmptyAnimation {
+                name: animation.name().to_owned(),
+            });
+        }
+
+        for n in 0..inner.timelinesCount.try_into().unwrap() {
+            let timeline = unsafe { *inner.timelines.add(n) };
+            if unsafe { (*timeline).type_ } != ffi::spTimelineType_SP_TIMELINE_EVENT {
+                continue;
+            }
+            let timeline = unsafe { &*timeline.cast::<ffi::spEventTimeline>() };
+            for frame in 0..timeline.framesCount.try_into().unwrap() {
+                keyed_events.insert(unsafe { (**timeline.events.add(frame)).data });
+            }
+        }
+    }
+
+    for event in skeleton_data.events() {
+        let inner: *const ffi::spEventData = event.inner;
+        if !keyed_events.contains(&inner.cast_mut()) {
+            diagnostics.push(Diagnostic::UnkeyedEvent {
+                name: event.name().to_owned(),
+            });
+        }
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TEST_CASES;
+    use std::path::PathBuf;
+
+    const UNUSED_JSON: &str = r#"{
+        "skeleton": { "hash": "unused", "spine": "3.8.55" },
+        "bones": [
+            { "name": "root" },
+            { "name": "body", "parent": "root" },
+            { "name": "weight", "parent": "body" },
+            { "name": "tail", "parent": "root" },
+            { "name": "tail-tip", "parent": "tail" }
+        ],
+        "slots": [
+            { "name": "body", "bone": "body", "attachment": "chin" },
+            { "name": "empty", "bone": "root" }
+        ],
+        "skins": [
+            {
+                "name": "default",
+                "attachments": {
+                    "body": {
+                        "chin": {
+                            "type": "mesh", "uvs": [ 0, 0, 1, 0, 0, 1 ], "triangles": [ 0, 1, 2 ],
+                            "vertices": [ 1, 1, 0, 0, 1, 1, 2, 10, 0, 1, 1, 2, 0, 10, 1 ],
+                            "hull": 3
+                        }
+                    }
+                }
+            }
+        ],
+        "events": { "footstep": {}, "unused": {} },
+        "animations": {
+            "walk": {
+                "bones": { "body": { "rotate": [ { "angle": 10 }, { "time": 1, "angle": 20 } ] } },
+                "events": [ { "time": 0.5, "name": "footstep" } ]
+            },
+            "idle": {}
+        }
+    }"#;
+
+    /// Writes `contents` to a file unique to this test run and returns its path.
+    fn temp_file(name: &str, contents: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("spine-validate-{}-{name}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn clean_assets() {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        let validator = Validator::new();
+
+        assert_eq!(
+            Vec::<Diagnostic>::new(),
+            validator.validate(&atlas, test_case.binary()).unwrap()
+        );
+        assert_eq!(
+            Vec::<Diagnostic>::new(),
+            validator.validate(&atlas, test_case.json()).unwrap()
+        );
+    }
+
+    #[test]
+    fn renamed_region() {
+        let test_case = &TEST_CASES[0];
+        let contents = std::fs::read_to_string(test_case.atlas()).unwrap();
+        let path = temp_file(
+            "renamed.atlas",
+            &contents.replace("\nchin\n", "\nchin-renamed\n"),
+        );
+        let atlas = Atlas::from_file(&path, |_, _| 0).unwrap();
+        // spine-c on its own fails the whole load.
+        let renamed = Atlas::from_file(&path, |_, _| 0).unwrap();
+        assert!(SkeletonData::from_json_file(test_case.json(), renamed).is_err());
+        std::fs::remove_file(path).unwrap();
+
+        let mut validator = Validator::new();
+        validator.set_max_page_size(1000);
+        validator.set_expected_version("4.0");
+        let diagnostics = validator.validate(&atlas, test_case.json()).unwrap();
+
+        assert_eq!(
+            vec![
+                Diagnostic::MissingRegion {
+                    skin: "default".to_owned(),
+                    attachment: "chin".to_owned(),
+                    path: "chin".to_owned(),
+                },
+                Diagnostic::VersionMismatch {
+                    found: Some("3.8.55".to_owned()),
+                    expected: "4.0".to_owned(),
+                },
+                Diagnostic::OversizedPage {
+                    name: "dragon.png".to_owned(),
+                    width: 1024,
+                    height: 1024,
+                    max: 1000,
+                },
+                Diagnostic::OversizedPage {
+                    name: "dragon2.png".to_owned(),
+                    width: 1024,
+                    height: 512,
+                    max: 1000,
+                },
+                Diagnostic::UnusedRegion {
+                    name: "chin-renamed".to_owned(),
+                },
+            ],
+            diagnostics
+        );
+        assert_eq!(Severity::Error, diagnostics[0].severity());
+        assert_eq!("missing-region", diagnostics[0].code());
+        assert_eq!(
+            r#"attachment "chin" of skin "default" refers to missing region "chin""#,
+            diagnostics[0].to_string()
+        );
+    }
+
+    #[test]
+    fn unused_setup_data() {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        let path = temp_file("unused.json", UNUSED_JSON);
+        let diagnostics = Validator::new().validate(&atlas, &path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        let diagnostics = diagnostics
+            .into_iter()
+            .filter(|diagnostic| diagnostic.code() != "unused-region")
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Diagnostic::UnusedSlot {
+                    name: "empty".to_owned(),
+                },
+                Diagnostic::UnusedBone {
+                    name: "tail".to_owned(),
+                },
+                Diagnostic::UnusedBone {
+                    name: "tail-tip".to_owned(),
+                },
+                Diagnostic::EmptyAnimation {
+                    name: "idle".to_owned(),
+                },
+                Diagnostic::UnkeyedEvent {
+                    name: "unused".to_owned(),
+                },
+            ],
+            diagnostics
+        );
+        assert!(diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.severity() == Severity::Warning));
+    }
+}
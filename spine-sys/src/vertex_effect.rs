@@ -0,0 +1,285 @@
+use crate::{
+    ffi,
+    skeleton::{Skeleton, Slot, WorldVertex},
+    SpineMutPtr,
+};
+
+/// A per-vertex deformation applied to the world vertices of a skeleton before rendering.
+///
+/// Implement this trait for custom effects (wind sway, shockwaves, ...), or use the spine-c
+/// built-ins `JitterVertexEffect` and `SwirlVertexEffect`.
+pub trait VertexEffect {
+    /// Called once before any vertices of `skeleton` are transformed.
+    fn begin(&mut self, _skeleton: &Skeleton) {}
+
+    /// Transforms a single world space vertex in place.
+    fn transform(&mut self, vertex: &mut WorldVertex);
+
+    /// Called once after all vertices of the skeleton have been transformed.
+    fn end(&mut self) {}
+}
+
+/// Runs a spine-c `spVertexEffect` transform callback against a `WorldVertex`.
+unsafe fn transform_ffi(effect: *mut ffi::spVertexEffect, vertex: &mut WorldVertex) {
+    let mut light = ffi::spColor::from(vertex.light);
+    let mut dark = ffi::spColor::from(vertex.dark);
+
+    if let Some(transform) = (*effect).transform {
+        let [x, y] = &mut vertex.position;
+        let [u, v] = &mut vertex.uv;
+        transform(
+            effect,
+            x,
+            y,
+            u,
+            v,
+            std::ptr::addr_of_mut!(light),
+            std::ptr::addr_of_mut!(dark),
+        );
+    }
+
+    vertex.light = light.into();
+    vertex.dark = dark.into();
+}
+
+unsafe fn begin_ffi(effect: *mut ffi::spVertexEffect, skeleton: &Skeleton) {
+    if let Some(begin) = (*effect).begin {
+        begin(effect, skeleton.inner.as_mut_ptr());
+    }
+}
+
+unsafe fn end_ffi(effect: *mut ffi::spVertexEffect) {
+    if let Some(end) = (*effect).end {
+        end(effect);
+    }
+}
+
+/// Randomly offsets every vertex by up to `jitter` units.
+///
+/// # spine-c
+/// Wraps `spJitterVertexEffect`
+pub struct JitterVertexEffect {
+    pub(crate) inner: SpineMutPtr<ffi::spJitterVertexEffect>,
+}
+impl JitterVertexEffect {
+    pub fn new(jitter_x: f32, jitter_y: f32) -> Self {
+        Self {
+            inner: SpineMutPtr::new(
+                unsafe { ffi::spJitterVertexEffect_create(jitter_x, jitter_y) },
+                Some(ffi::spJitterVertexEffect_dispose),
+            ),
+        }
+    }
+
+    pub fn jitter(&self) -> [f32; 2] {
+        let r = self.inner.as_ref();
+        [r.jitterX, r.jitterY]
+    }
+
+    pub fn set_jitter(&mut self, jitter: [f32; 2]) {
+        let r = self.inner.as_mut();
+        r.jitterX = jitter[0];
+        r.jitterY = jitter[1];
+    }
+}
+// SAFETY: the effect exclusively owns its spine-c state.
+unsafe impl Send for JitterVertexEffect {}
+
+impl VertexEffect for JitterVertexEffect {
+    fn begin(&mut self, skeleton: &Skeleton) {
+        unsafe { begin_ffi(self.inner.as_mut_ptr().cast(), skeleton) }
+    }
+
+    fn transform(&mut self, vertex: &mut WorldVertex) {
+        unsafe { transform_ffi(self.inner.as_mut_ptr().cast(), vertex) }
+    }
+
+    fn end(&mut self) {
+        unsafe { end_ffi(self.inner.as_mut_ptr().cast()) }
+    }
+}
+
+/// Rotates vertices around a center point, with the rotation falling off towards `radius`.
+///
+/// # spine-c
+/// Wraps `spSwirlVertexEffect`
+pub struct SwirlVertexEffect {
+    pub(crate) inner: SpineMutPtr<ffi::spSwirlVertexEffect>,
+}
+impl SwirlVertexEffect {
+    pub fn new(radius: f32) -> Self {
+        Self {
+            inner: SpineMutPtr::new(
+                unsafe { ffi::spSwirlVertexEffect_create(radius) },
+                Some(ffi::spSwirlVertexEffect_dispose),
+            ),
+        }
+    }
+
+    /// The swirl center, relative to the skeleton position.
+    pub fn center(&self) -> [f32; 2] {
+        let r = self.inner.as_ref();
+        [r.centerX, r.centerY]
+    }
+
+    pub fn set_center(&mut self, center: [f32; 2]) {
+        let r = self.inner.as_mut();
+        r.centerX = center[0];
+        r.centerY = center[1];
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.inner.as_ref().radius
+    }
+
+    pub fn set_radius(&mut self, radius: f32) {
+        self.inner.as_mut().radius = radius;
+    }
+
+    /// The rotation at the swirl center, in degrees.
+    pub fn angle(&self) -> f32 {
+        self.inner.as_ref().angle
+    }
+
+    pub fn set_angle(&mut self, angle: f32) {
+        self.inner.as_mut().angle = angle;
+    }
+}
+// SAFETY: the effect exclusively owns its spine-c state.
+unsafe impl Send for SwirlVertexEffect {}
+
+impl VertexEffect for SwirlVertexEffect {
+    fn begin(&mut self, skeleton: &Skeleton) {
+        unsafe { begin_ffi(self.inner.as_mut_ptr().cast(), skeleton) }
+    }
+
+    fn transform(&mut self, vertex: &mut WorldVertex) {
+        unsafe { transform_ffi(self.inner.as_mut_ptr().cast(), vertex) }
+    }
+
+    fn end(&mut self) {
+        unsafe { end_ffi(self.inner.as_mut_ptr().cast()) }
+    }
+}
+
+impl Slot<'_> {
+    /// Computes the world vertices of this slot's attachment, passing each through `effect`.
+    ///
+    /// `VertexEffect::begin` and `VertexEffect::end` are not called; use
+    /// `Skeleton::world_vertices_with_effect` to process a whole skeleton.
+    pub fn world_vertices_with_effect<E>(&self, effect: &mut E) -> Vec<WorldVertex>
+    where
+        E: VertexEffect + ?Sized,
+    {
+        let mut vertices = self.world_vertices();
+        for vertex in &mut vertices {
+            effect.transform(vertex);
+        }
+        vertices
+    }
+}
+
+impl Skeleton {
+    /// Computes the world vertices of every slot in draw order, passing each through `effect`.
+    /// The returned list is indexed the same as `Skeleton::draw_slots`.
+    pub fn world_vertices_with_effect<E>(&self, effect: &mut E) -> Vec<Vec<WorldVertex>>
+    where
+        E: VertexEffect + ?Sized,
+    {
+        effect.begin(self);
+        let vertices = self
+            .draw_slots()
+            .iter()
+            .map(|slot| slot.world_vertices_with_effect(effect))
+            .collect();
+        effect.end();
+
+        vertices
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::{atlas::Atlas, skeleton::SkeletonData, tests::TEST_CASES};
+
+    struct Offset([f32; 2]);
+    impl VertexEffect for Offset {
+        fn transform(&mut self, vertex: &mut WorldVertex) {
+            vertex.position[0] += self.0[0];
+            vertex.position[1] += self.0[1];
+        }
+    }
+
+    fn posed_skeleton() -> (SkeletonData, Skeleton) {
+        let test_case = &TEST_CASES[0];
+        let atlas = Atlas::from_file(test_case.atlas(), |_, _| 0).unwrap();
+        let skeleton_data =
+            unsafe { SkeletonData::from_binary_file(test_case.binary(), atlas) }.unwrap();
+        let mut skeleton = Skeleton::new(&skeleton_data);
+        skeleton.update_world_transforms();
+
+        (skeleton_data, skeleton)
+    }
+
+    fn untransformed(skeleton: &Skeleton) -> Vec<Vec<WorldVertex>> {
+        skeleton
+            .draw_slots()
+            .iter()
+            .map(Slot::world_vertices)
+            .collect()
+    }
+
+    #[test]
+    fn custom_effect() {
+        let (_data, skeleton) = posed_skeleton();
+
+        let base = untransformed(&skeleton);
+        let moved = skeleton.world_vertices_with_effect(&mut Offset([10.0, -5.0]));
+
+        assert!(base.iter().any(|v| !v.is_empty()));
+        for (base, moved) in base.iter().flatten().zip(moved.iter().flatten()) {
+            assert!((base.position[0] + 10.0 - moved.position[0]).abs() < 0.001);
+            assert!((base.position[1] - 5.0 - moved.position[1]).abs() < 0.001);
+            assert_eq!(base.uv, moved.uv);
+            assert_eq!(base.light, moved.light);
+        }
+    }
+
+    #[test]
+    fn zero_jitter() {
+        let (_data, skeleton) = posed_skeleton();
+
+        let mut jitter = JitterVertexEffect::new(0.0, 0.0);
+        assert_eq!([0.0, 0.0], jitter.jitter());
+
+        assert_eq!(
+            untransformed(&skeleton),
+            skeleton.world_vertices_with_effect(&mut jitter)
+        );
+    }
+
+    #[test]
+    fn swirl_preserves_distance() {
+        let (_data, skeleton) = posed_skeleton();
+
+        let mut swirl = SwirlVertexEffect::new(500.0);
+        swirl.set_angle(90.0);
+        assert!((swirl.radius() - 500.0).abs() < f32::EPSILON);
+
+        let center = skeleton.position();
+        let base = untransformed(&skeleton);
+        let swirled = skeleton.world_vertices_with_effect(&mut swirl);
+
+        let distance =
+            |v: &WorldVertex| (v.position[0] - center[0]).hypot(v.position[1] - center[1]);
+
+        let mut moved = false;
+        for (base, swirled) in base.iter().flatten().zip(swirled.iter().flatten()) {
+            assert!((distance(base) - distance(swirled)).abs() < 0.01);
+            moved |= base.position != swirled.position;
+        }
+        assert!(moved);
+    }
+}
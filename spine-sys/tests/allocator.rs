@@ -0,0 +1,37 @@
+//! The allocation hooks are process wide, so this runs in its own test binary, where no other
+//! test uses spine-c at the same time.
+
+use spine_sys::{
+    animation::AnimationStateData,
+    atlas::Atlas,
+    extension::{allocation_stats, use_rust_allocator},
+    skeleton::SkeletonData,
+};
+
+const DRAGON: &str = "../spine-example/examples/dragon/export";
+
+#[test]
+fn rust_allocator() {
+    assert!(allocation_stats().is_none());
+    // SAFETY: this is the only test in this binary, and nothing was created yet.
+    unsafe {
+        use_rust_allocator();
+        use_rust_allocator();
+    }
+
+    let before = allocation_stats().unwrap();
+    let atlas = Atlas::from_file(format!("{DRAGON}/dragon.atlas"), |_, _| 0).unwrap();
+    let skeleton_data =
+        unsafe { SkeletonData::from_binary_file(format!("{DRAGON}/dragon-ess.skel"), atlas) }
+            .unwrap();
+    let state_data = AnimationStateData::new(&skeleton_data);
+    let loaded = allocation_stats().unwrap();
+    assert!(loaded.allocations > before.allocations);
+    assert!(loaded.peak_bytes >= loaded.live_bytes);
+
+    drop(state_data);
+    drop(skeleton_data);
+    let dropped = allocation_stats().unwrap();
+    assert!(dropped.frees > loaded.frees);
+    assert!(dropped.live_bytes < loaded.live_bytes);
+}
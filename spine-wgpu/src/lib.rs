@@ -0,0 +1,34 @@
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+//! Draws Spine skeletons with wgpu.
+//!
+//! `Renderer` uploads atlas pages as textures, turns posed skeletons into vertex and index buffers
+//! once per frame and records their draw calls into a render pass, with a pipeline per blend mode
+//! for straight and premultiplied alpha and two color tinting. `Renderer::headless` and
+//! `Renderer::render` draw offscreen into a `raster::Image`, so tests run on a software adapter
+//! such as lavapipe or llvmpipe.
+
+pub mod renderer;
+
+pub use crate::renderer::{Draw, Renderer};
+pub use spine::raster::{Camera, Image};
+
+use spine::SpineError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RendererError {
+    #[error("{0}")]
+    Spine(#[from] SpineError),
+    #[error("no graphics adapter available: {0}")]
+    Adapter(#[from] wgpu::RequestAdapterError),
+    #[error("failed to create a device: {0}")]
+    Device(#[from] wgpu::RequestDeviceError),
+    #[error("failed to read back the rendered image: {0}")]
+    Readback(#[from] wgpu::BufferAsyncError),
+    #[error("failed to wait for the device: {0}")]
+    Poll(#[from] wgpu::PollError),
+    #[error("offscreen rendering needs an Rgba8Unorm renderer, not {0:?}")]
+    Format(wgpu::TextureFormat),
+}
@@ -0,0 +1,821 @@
+#![allow(clippy::cast_precision_loss)]
+
+use crate::RendererError;
+use bytemuck::{Pod, Zeroable};
+use spine::{
+    atlas::{Atlas, AtlasPage},
+    batch::SkeletonBatcher,
+    color::Color,
+    enums::{AtlasFilter, AtlasWrap, BlendFactor, BlendMode},
+    raster::{Camera, Image},
+    skeleton::{is_y_down, Skeleton, WorldVertex},
+    SpineError,
+};
+use std::{collections::HashMap, ops::Range, path::Path};
+use wgpu::util::DeviceExt;
+
+const BLEND_MODES: [BlendMode; 4] = [
+    BlendMode::Normal,
+    BlendMode::Additive,
+    BlendMode::Multiply,
+    BlendMode::Screen,
+];
+
+/// A vertex of a prepared frame, in world space.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+struct Vertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+    light: [f32; 4],
+    dark: [f32; 4],
+}
+impl Vertex {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 4] = wgpu::vertex_attr_array![
+        0 => Float32x2,
+        1 => Float32x2,
+        2 => Float32x4,
+        3 => Float32x4,
+    ];
+
+    /// The vertex with its `WorldVertex::tint_colors`.
+    fn new(vertex: &WorldVertex, premultiplied_alpha: bool) -> Self {
+        let (light, dark) = vertex.tint_colors(premultiplied_alpha);
+        Self {
+            position: vertex.position,
+            uv: vertex.uv,
+            light,
+            dark,
+        }
+    }
+}
+
+/// Maps world space to clip space: `clip = position * scale + offset`.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Pod, Zeroable)]
+struct View {
+    scale: [f32; 2],
+    offset: [f32; 2],
+}
+impl View {
+    /// The view of `camera` over a `width` x `height` target, matching `Rasterizer`.
+    fn new(camera: Camera, width: u32, height: u32) -> Self {
+        let y = if is_y_down() { -1.0 } else { 1.0 };
+        let scale = [
+            2.0 * camera.zoom / width as f32,
+            y * 2.0 * camera.zoom / height as f32,
+        ];
+        Self {
+            scale,
+            offset: [-camera.center[0] * scale[0], -camera.center[1] * scale[1]],
+        }
+    }
+}
+
+/// A range of prepared indices drawn with one texture and blend mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Draw {
+    pub texture_id: u32,
+    pub blend_mode: BlendMode,
+    pub indices: Range<u32>,
+}
+
+struct Texture {
+    bind_group: wgpu::BindGroup,
+    premultiplied_alpha: bool,
+}
+
+/// A growable buffer holding the vertices or indices of the prepared frame.
+struct DynamicBuffer {
+    buffer: wgpu::Buffer,
+    usage: wgpu::BufferUsages,
+    label: &'static str,
+}
+impl DynamicBuffer {
+    fn new(device: &wgpu::Device, label: &'static str, usage: wgpu::BufferUsages) -> Self {
+        let usage = usage | wgpu::BufferUsages::COPY_DST;
+        Self {
+            buffer: Self::create(device, label, usage, 1024),
+            usage,
+            label,
+        }
+    }
+
+    fn create(
+        device: &wgpu::Device,
+        label: &str,
+        usage: wgpu::BufferUsages,
+        size: u64,
+    ) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size,
+            usage,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Uploads `data`, reallocating to the next power of two if it does not fit.
+    fn write(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, data: &[u8]) {
+        let size = data.len() as u64;
+        if size > self.buffer.size() {
+            self.buffer = Self::create(device, self.label, self.usage, size.next_power_of_two());
+        }
+        if size > 0 {
+            queue.write_buffer(&self.buffer, 0, data);
+        }
+    }
+}
+
+/// Draws posed skeletons with wgpu.
+///
+/// Every frame, `Renderer::prepare` batches the skeletons to draw into one vertex and one index
+/// buffer, and `Renderer::draw` records their draw calls into a render pass. Consecutive slots
+/// sharing a texture and blend mode are drawn with a single call, also across skeletons, so
+/// skeletons sharing an atlas page draw in few calls when they are prepared together.
+///
+/// Textures are looked up by the texture id assigned while loading the `Atlas`, see
+/// `Renderer::load_atlas` and `Renderer::insert_texture`. Whether a texture stores premultiplied
+/// alpha picks the blend functions and vertex colors of the slots using it.
+pub struct Renderer {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    format: wgpu::TextureFormat,
+    texture_layout: wgpu::BindGroupLayout,
+    view_buffer: wgpu::Buffer,
+    view_bind_group: wgpu::BindGroup,
+    pipelines: HashMap<(BlendMode, bool), wgpu::RenderPipeline>,
+    textures: HashMap<u32, Texture>,
+    vertex_buffer: DynamicBuffer,
+    index_buffer: DynamicBuffer,
+    vertices: Vec<Vertex>,
+    indices: Vec<u32>,
+    draws: Vec<Draw>,
+    batcher: SkeletonBatcher,
+}
+
+impl Renderer {
+    /// Creates a renderer drawing into render passes with a single `format` color attachment.
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, format: wgpu::TextureFormat) -> Self {
+        let shader = device.create_shader_module(wgpu::include_wgsl!("spine.wgsl"));
+
+        let view_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("spine view"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let texture_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("spine texture"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let view_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("spine view"),
+            contents: bytemuck::bytes_of(&View::new(Camera::new([0.0, 0.0], 1.0), 1, 1)),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let view_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("spine view"),
+            layout: &view_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: view_buffer.as_entire_binding(),
+            }],
+        });
+
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("spine"),
+            bind_group_layouts: &[&view_layout, &texture_layout],
+            push_constant_ranges: &[],
+        });
+        let mut pipelines = HashMap::new();
+        for blend_mode in BLEND_MODES {
+            for premultiplied_alpha in [false, true] {
+                let pipeline = create_pipeline(
+                    device,
+                    &layout,
+                    &shader,
+                    format,
+                    blend_state(blend_mode, premultiplied_alpha),
+                );
+                pipelines.insert((blend_mode, premultiplied_alpha), pipeline);
+            }
+        }
+
+        Self {
+            device: device.clone(),
+            queue: queue.clone(),
+            format,
+            texture_layout,
+            view_buffer,
+            view_bind_group,
+            pipelines,
+            textures: HashMap::new(),
+            vertex_buffer: DynamicBuffer::new(device, "spine vertices", wgpu::BufferUsages::VERTEX),
+            index_buffer: DynamicBuffer::new(device, "spine indices", wgpu::BufferUsages::INDEX),
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            draws: Vec::new(),
+            batcher: SkeletonBatcher::new(),
+        }
+    }
+
+    /// Creates a renderer for offscreen rendering with `Renderer::render`, on the first adapter
+    /// wgpu finds. `WGPU_BACKEND` and the other wgpu environment variables pick the adapter.
+    ///
+    /// # Errors
+    /// Returns a `RendererError::Adapter` or `RendererError::Device` without a usable adapter.
+    pub fn headless() -> Result<Self, RendererError> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::from_env_or_default());
+        let adapter = futures_lite::future::block_on(instance.request_adapter(
+            &wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::from_env().unwrap_or_default(),
+                force_fallback_adapter: false,
+                compatible_surface: None,
+            },
+        ))?;
+        let (device, queue) =
+            futures_lite::future::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+                label: Some("spine"),
+                required_limits:
+                    wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits()),
+                ..wgpu::DeviceDescriptor::default()
+            }))?;
+        Ok(Self::new(&device, &queue, wgpu::TextureFormat::Rgba8Unorm))
+    }
+
+    pub fn device(&self) -> &wgpu::Device {
+        &self.device
+    }
+
+    pub fn queue(&self) -> &wgpu::Queue {
+        &self.queue
+    }
+
+    /// The format of the color attachment the pipelines draw into.
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.format
+    }
+
+    /// Uploads the texture for atlas pages that were given `id` as their texture id.
+    pub fn insert_texture(
+        &mut self,
+        id: u32,
+        image: &Image,
+        sampler: &wgpu::SamplerDescriptor<'_>,
+        premultiplied_alpha: bool,
+    ) {
+        let texture = self.device.create_texture_with_data(
+            &self.queue,
+            &wgpu::TextureDescriptor {
+                label: Some("spine page"),
+                size: wgpu::Extent3d {
+                    width: image.width,
+                    height: image.height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8Unorm,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            &image.pixels,
+        );
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = self.device.create_sampler(sampler);
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("spine page"),
+            layout: &self.texture_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+            ],
+        });
+        self.textures.insert(
+            id,
+            Texture {
+                bind_group,
+                premultiplied_alpha,
+            },
+        );
+    }
+
+    /// Loads an atlas, decoding every page as a PNG file and uploading it under a new texture id,
+    /// sampled with the filters and wrapping of the page and with the premultiplied alpha of the
+    /// atlas.
+    ///
+    /// # Errors
+    /// Returns a `SpineError::FailLoadAtlas` if the atlas cannot be loaded, or a
+    /// `SpineError::FailImage` if one of its pages cannot be decoded.
+    pub fn load_atlas<P>(&mut self, path: P) -> Result<Atlas, SpineError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut next_id = self.textures.keys().max().map_or(1, |id| id + 1);
+        let mut pages = Vec::new();
+        let mut error = None;
+
+        let atlas = Atlas::from_file(path, |page, page_path| match Image::from_png(page_path) {
+            Ok(image) => {
+                let id = next_id;
+                next_id += 1;
+                pages.push((id, image, sampler(page)));
+                id
+            }
+            Err(e) => {
+                error.get_or_insert(e);
+                0
+            }
+        })?;
+        if let Some(e) = error {
+            return Err(e);
+        }
+
+        for (page, (id, image, sampler)) in atlas.pages().iter().zip(pages) {
+            self.insert_texture(id, &image, &sampler, page.premultiplied_alpha());
+        }
+        Ok(atlas)
+    }
+
+    /// Batches `skeletons`, drawn in order, and uploads their geometry for a `width` x `height`
+    /// target seen through `camera`. World transforms must be up to date.
+    ///
+    /// Replaces what was prepared before, so only one frame can be prepared at a time.
+    ///
+    /// # Panics
+    /// Panics if the frame has more than `u32::MAX` vertices or indices.
+    pub fn prepare(&mut self, skeletons: &[&Skeleton], camera: Camera, width: u32, height: u32) {
+        self.vertices.clear();
+        self.indices.clear();
+        self.draws.clear();
+
+        for skeleton in skeletons {
+            for batch in self.batcher.batch(skeleton) {
+                let Some(texture) = self.textures.get(&batch.texture_id) else {
+                    continue;
+                };
+
+                let offset = u32::try_from(self.vertices.len()).unwrap();
+                let start = u32::try_from(self.indices.len()).unwrap();
+                self.vertices.extend(
+                    batch
+                        .vertices
+                        .iter()
+                        .map(|vertex| Vertex::new(vertex, texture.premultiplied_alpha)),
+                );
+                self.indices
+                    .extend(batch.indices.iter().map(|&index| offset + index));
+                let end = u32::try_from(self.indices.len()).unwrap();
+
+                match self.draws.last_mut() {
+                    Some(draw)
+                        if draw.texture_id == batch.texture_id
+                            && draw.blend_mode == batch.blend_mode =>
+                    {
+                        draw.indices.end = end;
+                    }
+                    _ => self.draws.push(Draw {
+                        texture_id: batch.texture_id,
+                        blend_mode: batch.blend_mode,
+                        indices: start..end,
+                    }),
+                }
+            }
+        }
+
+        self.vertex_buffer.write(
+            &self.device,
+            &self.queue,
+            bytemuck::cast_slice(&self.vertices),
+        );
+        self.index_buffer.write(
+            &self.device,
+            &self.queue,
+            bytemuck::cast_slice(&self.indices),
+        );
+        self.queue.write_buffer(
+            &self.view_buffer,
+            0,
+            bytemuck::bytes_of(&View::new(camera, width, height)),
+        );
+    }
+
+    /// The draw calls of the prepared frame.
+    pub fn draws(&self) -> &[Draw] {
+        &self.draws
+    }
+
+    /// Records the draw calls of the prepared frame into `pass`, which must draw into a single
+    /// color attachment of `Renderer::format`.
+    pub fn draw(&self, pass: &mut wgpu::RenderPass<'_>) {
+        if self.draws.is_empty() {
+            return;
+        }
+
+        pass.set_bind_group(0, &self.view_bind_group, &[]);
+        pass.set_vertex_buffer(0, self.vertex_buffer.buffer.slice(..));
+        pass.set_index_buffer(
+            self.index_buffer.buffer.slice(..),
+            wgpu::IndexFormat::Uint32,
+        );
+        for draw in &self.draws {
+            let texture = &self.textures[&draw.texture_id];
+            pass.set_pipeline(&self.pipelines[&(draw.blend_mode, texture.premultiplied_alpha)]);
+            pass.set_bind_group(1, &texture.bind_group, &[]);
+            pass.draw_indexed(draw.indices.clone(), 0, 0..1);
+        }
+    }
+
+    /// Draws `skeletons` over a transparent `width` x `height` image and reads it back.
+    ///
+    /// # Errors
+    /// Returns a `RendererError::Format` unless the renderer draws into
+    /// `TextureFormat::Rgba8Unorm`, as the renderer of `Renderer::headless` does, or an error if
+    /// the image cannot be read back.
+    ///
+    /// # Panics
+    /// Panics if the device is lost while the image is read back.
+    pub fn render(
+        &mut self,
+        skeletons: &[&Skeleton],
+        camera: Camera,
+        width: u32,
+        height: u32,
+    ) -> Result<Image, RendererError> {
+        if self.format != wgpu::TextureFormat::Rgba8Unorm {
+            return Err(RendererError::Format(self.format));
+        }
+
+        let size = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        let target = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("spine offscreen"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let bytes_per_row = (width * 4).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("spine readback"),
+            size: u64::from(bytes_per_row) * u64::from(height),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        self.prepare(skeletons, camera, width, height);
+        let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("spine offscreen"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                ..wgpu::RenderPassDescriptor::default()
+            });
+            self.draw(&mut pass);
+        }
+        encoder.copy_texture_to_buffer(
+            target.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &readback,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            size,
+        );
+        self.queue.submit([encoder.finish()]);
+
+        let slice = readback.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            sender.send(result).ok();
+        });
+        self.device.poll(wgpu::PollType::wait_indefinitely())?;
+        receiver
+            .recv()
+            .expect("map_async callback dropped without running")?;
+
+        // The target holds premultiplied colors, as blending over transparent black leaves them
+        // whatever the blend mode.
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+        for row in slice
+            .get_mapped_range()
+            .chunks_exact(bytes_per_row as usize)
+        {
+            for pixel in row[..width as usize * 4].chunks_exact(4) {
+                let a = f32::from(pixel[3]) / 255.0;
+                let color = if a > 0.0 {
+                    let channel = |c: u8| f32::from(c) / 255.0 / a;
+                    Color::new(channel(pixel[0]), channel(pixel[1]), channel(pixel[2]), a)
+                } else {
+                    Color::TRANSPARENT
+                };
+                pixels.extend(color.to_rgba8().to_be_bytes());
+            }
+        }
+        readback.unmap();
+
+        Ok(Image::from_rgba8(width, height, pixels))
+    }
+}
+
+fn create_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    shader: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    blend: wgpu::BlendState,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("spine"),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module: shader,
+            entry_point: Some("vertex"),
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+            buffers: &[wgpu::VertexBufferLayout {
+                array_stride: std::mem::size_of::<Vertex>() as u64,
+                step_mode: wgpu::VertexStepMode::Vertex,
+                attributes: &Vertex::ATTRIBUTES,
+            }],
+        },
+        primitive: wgpu::PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState::default(),
+        fragment: Some(wgpu::FragmentState {
+            module: shader,
+            entry_point: Some("fragment"),
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: Some(blend),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        multiview: None,
+        cache: None,
+    })
+}
+
+/// The blend state spine-runtimes uses for `blend_mode`, see `BlendMode::blend_func`.
+pub fn blend_state(blend_mode: BlendMode, premultiplied_alpha: bool) -> wgpu::BlendState {
+    let factor = |factor| match factor {
+        BlendFactor::One => wgpu::BlendFactor::One,
+        BlendFactor::SrcAlpha => wgpu::BlendFactor::SrcAlpha,
+        BlendFactor::OneMinusSrcAlpha => wgpu::BlendFactor::OneMinusSrcAlpha,
+        BlendFactor::DstColor => wgpu::BlendFactor::Dst,
+        BlendFactor::OneMinusSrcColor => wgpu::BlendFactor::OneMinusSrc,
+    };
+    let func = blend_mode.blend_func(premultiplied_alpha);
+    wgpu::BlendState {
+        color: wgpu::BlendComponent {
+            src_factor: factor(func.src_color),
+            dst_factor: factor(func.dst_color),
+            operation: wgpu::BlendOperation::Add,
+        },
+        alpha: wgpu::BlendComponent {
+            src_factor: factor(func.src_alpha),
+            dst_factor: factor(func.dst_alpha),
+            operation: wgpu::BlendOperation::Add,
+        },
+    }
+}
+
+/// The sampler for the filters and wrapping of an atlas page. Pages have no mipmaps.
+fn sampler(page: &AtlasPage<'_>) -> wgpu::SamplerDescriptor<'static> {
+    let address_mode = |wrap| match wrap {
+        AtlasWrap::MirroedRepeat => wgpu::AddressMode::MirrorRepeat,
+        AtlasWrap::ClampToEdge => wgpu::AddressMode::ClampToEdge,
+        AtlasWrap::Repeat => wgpu::AddressMode::Repeat,
+    };
+    let filter = |filter| match filter {
+        AtlasFilter::Nearest
+        | AtlasFilter::MipmapNereastNearest
+        | AtlasFilter::MipmapNearestLinear => wgpu::FilterMode::Nearest,
+        _ => wgpu::FilterMode::Linear,
+    };
+    let (u_wrap, v_wrap) = page.wrap();
+    wgpu::SamplerDescriptor {
+        label: Some("spine page"),
+        address_mode_u: address_mode(u_wrap),
+        address_mode_v: address_mode(v_wrap),
+        mag_filter: filter(page.mag_filter()),
+        min_filter: filter(page.min_filter()),
+        ..wgpu::SamplerDescriptor::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spine::{
+        animation::{AnimationState, AnimationStateData, TrackIndex},
+        raster::Rasterizer,
+        skeleton::SkeletonData,
+    };
+
+    const DRAGON: &str = "../spine-example/examples/dragon/export";
+
+    /// The headless renderer, or `None` where no adapter is available, as on CI machines without
+    /// lavapipe or llvmpipe.
+    fn renderer() -> Option<Renderer> {
+        match Renderer::headless() {
+            Ok(renderer) => Some(renderer),
+            Err(e) => {
+                eprintln!("skipping: {e}");
+                None
+            }
+        }
+    }
+
+    /// Poses the dragon `time` seconds into its "flying" animation.
+    fn dragon(atlas: Atlas, time: f32) -> (SkeletonData, Skeleton) {
+        let skeleton_data = unsafe {
+            SkeletonData::from_binary_file(Path::new(DRAGON).join("dragon-ess.skel"), atlas)
+        }
+        .unwrap();
+        let animation_data = AnimationStateData::new(&skeleton_data);
+        let mut animation = AnimationState::new(&animation_data);
+        let mut skeleton = Skeleton::new(&skeleton_data);
+
+        animation.set_by_name("flying", TrackIndex::zero(), true);
+        animation.update(time);
+        animation.apply(&mut skeleton);
+        skeleton.update_world_transforms();
+
+        (skeleton_data, skeleton)
+    }
+
+    #[test]
+    fn view_matches_rasterizer() {
+        let view = View::new(Camera::new([10.0, -20.0], 2.0), 200, 100);
+        let y = if is_y_down() { -1.0 } else { 1.0 };
+        let expected = [0.02, y * 0.04, -0.2, y * 0.8];
+        let actual = [view.scale, view.offset].concat();
+        assert!(expected
+            .iter()
+            .zip(&actual)
+            .all(|(e, a)| (e - a).abs() < 1e-6));
+    }
+
+    #[test]
+    fn blend_states() {
+        let normal = blend_state(BlendMode::Normal, false);
+        assert_eq!(wgpu::BlendFactor::SrcAlpha, normal.color.src_factor);
+        assert_eq!(wgpu::BlendFactor::OneMinusSrcAlpha, normal.color.dst_factor);
+        assert_eq!(wgpu::BlendFactor::One, normal.alpha.src_factor);
+        assert_eq!(
+            wgpu::BlendFactor::One,
+            blend_state(BlendMode::Normal, true).color.src_factor
+        );
+        let multiply = blend_state(BlendMode::Multiply, true);
+        assert_eq!(wgpu::BlendFactor::Dst, multiply.color.src_factor);
+        let screen = blend_state(BlendMode::Screen, false);
+        assert_eq!(wgpu::BlendFactor::OneMinusSrc, screen.color.dst_factor);
+    }
+
+    #[test]
+    fn matches_rasterizer() {
+        // GPUs differ from the rasterizer in filtering precision and in coverage along edges, so
+        // compare the average difference of the premultiplied channels.
+        let premultiplied = |image: &Image| {
+            image
+                .pixels
+                .chunks_exact(4)
+                .flat_map(|p| {
+                    let a = f32::from(p[3]) / 255.0;
+                    [p[0], p[1], p[2]]
+                        .map(|c| f32::from(c) * a)
+                        .into_iter()
+                        .chain([f32::from(p[3])])
+                })
+                .collect::<Vec<_>>()
+        };
+
+        for (atlas, premultiplied_alpha) in [("dragon.atlas", false), ("dragon-pma.atlas", true)] {
+            let Some(mut renderer) = renderer() else {
+                return;
+            };
+            let atlas = Path::new(DRAGON).join(atlas);
+            let loaded = renderer.load_atlas(&atlas).unwrap();
+            assert!(loaded
+                .pages()
+                .iter()
+                .all(|page| page.premultiplied_alpha() == premultiplied_alpha));
+            let (_data, skeleton) = dragon(loaded, 0.3);
+            let camera = Camera::fit_skeleton(&skeleton, 128, 128);
+            let image = renderer.render(&[&skeleton], camera, 128, 128).unwrap();
+
+            let mut rasterizer = Rasterizer::new();
+            let (_data, skeleton) = dragon(rasterizer.load_atlas(&atlas).unwrap(), 0.3);
+            let expected = rasterizer.render(&skeleton, camera, 128, 128);
+
+            let (a, b) = (premultiplied(&image), premultiplied(&expected));
+            let difference =
+                a.iter().zip(&b).map(|(a, b)| (a - b).abs()).sum::<f32>() / a.len() as f32;
+            assert!(difference < 1.0, "average difference {difference}");
+            assert!(image.pixels.chunks_exact(4).any(|p| p[3] == 255));
+        }
+    }
+
+    #[test]
+    fn draws_merge_across_skeletons() {
+        let Some(mut renderer) = renderer() else {
+            return;
+        };
+        let atlas = renderer
+            .load_atlas(Path::new(DRAGON).join("dragon.atlas"))
+            .unwrap();
+        let skeleton_data = SkeletonData::from_json_str(
+            r#"{
+                "skeleton": { "hash": "renderer", "spine": "3.8.55" },
+                "bones": [ { "name": "root" } ],
+                "slots": [ { "name": "image", "bone": "root", "attachment": "chin" } ],
+                "skins": [ { "name": "default", "attachments": {
+                    "image": { "chin": { "width": 214, "height": 146 } }
+                } } ]
+            }"#,
+            atlas,
+        )
+        .unwrap();
+        let mut skeletons = (0..3)
+            .map(|_| Skeleton::new(&skeleton_data))
+            .collect::<Vec<_>>();
+        for (i, skeleton) in skeletons.iter_mut().enumerate() {
+            skeleton.set_position([i as f32 * 300.0, 0.0]);
+            skeleton.update_world_transforms();
+        }
+
+        let skeletons = skeletons.iter().collect::<Vec<_>>();
+        let camera = Camera::new([300.0, 0.0], 0.25);
+        let image = renderer.render(&skeletons, camera, 256, 64).unwrap();
+        assert_eq!(
+            [Draw {
+                texture_id: 1,
+                blend_mode: BlendMode::Normal,
+                indices: 0..18,
+            }],
+            renderer.draws()
+        );
+        // Every skeleton was drawn: the columns at their centers are covered.
+        for x in [32, 128, 224] {
+            assert!((0..64).any(|y| image.pixel(x, y)[3] > 0), "column {x}");
+        }
+
+        renderer.prepare(&[], camera, 256, 64);
+        assert!(renderer.draws().is_empty());
+    }
+}
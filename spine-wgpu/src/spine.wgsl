@@ -0,0 +1,41 @@
+struct View {
+    scale: vec2<f32>,
+    offset: vec2<f32>,
+};
+
+@group(0) @binding(0) var<uniform> view: View;
+@group(1) @binding(0) var texture: texture_2d<f32>;
+@group(1) @binding(1) var texture_sampler: sampler;
+
+struct Vertex {
+    @location(0) position: vec2<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) light: vec4<f32>,
+    @location(3) dark: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) light: vec4<f32>,
+    @location(2) dark: vec4<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(vertex.position * view.scale + view.offset, 0.0, 1.0);
+    out.uv = vertex.uv;
+    out.light = vertex.light;
+    out.dark = vertex.dark;
+    return out;
+}
+
+// Two color tinting as in the spine-runtimes shaders: the light color tints the texture and the
+// dark color replaces its black.
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let texel = textureSample(texture, texture_sampler, in.uv);
+    let rgb = ((texel.a - 1.0) * in.dark.a + 1.0 - texel.rgb) * in.dark.rgb + texel.rgb * in.light.rgb;
+    return vec4<f32>(rgb, texel.a * in.light.a);
+}